@@ -0,0 +1,158 @@
+"""Benchmark parse-to-score latency and retained memory over tests/logs.
+
+Run against an installed build, e.g. before and after a change:
+
+    python benchmarks/bench_parse.py --rounds 20
+
+Reports the median time to parse every log and read its score, the time to
+parse and walk every attribute of every result, and the resident memory
+retained by holding all results of several rounds at once.
+"""
+
+import argparse
+import gc
+import os
+import statistics
+import sys
+import time
+from collections.abc import Callable
+from datetime import timedelta
+from pathlib import Path
+
+import cambia
+
+LOGS_DIR = Path(__file__).parent.parent / "tests" / "logs"
+
+
+def load_logs() -> list[bytes]:
+    """Read every log that cambia can parse.
+
+    Returns:
+        The raw content of each parseable log under tests/logs.
+    """
+    logs = []
+    for path in sorted(LOGS_DIR.rglob("*.log")):
+        raw = path.read_bytes()
+        try:
+            cambia.parse_log_content(raw)
+        except ValueError:
+            continue
+        logs.append(raw)
+    return logs
+
+
+def walk(obj: object) -> int:
+    """Touch every public attribute of a result object, recursively.
+
+    Args:
+        obj: The object to walk.
+
+    Returns:
+        The number of attributes read.
+    """
+    if obj is None or isinstance(obj, (str, bytes, int, float, timedelta)):
+        return 0
+    if isinstance(obj, list):
+        return sum(walk(item) for item in obj)
+    if hasattr(obj, "name") and hasattr(obj, "value"):
+        # Enum members expose their siblings as attributes; treat as leaves
+        return 0
+    count = 0
+    for name in dir(obj):
+        if name.startswith("_"):
+            continue
+        value = getattr(obj, name)
+        if callable(value):
+            continue
+        count += 1 + walk(value)
+    return count
+
+
+def score_only(logs: list[bytes]) -> None:
+    """Parse every log and read only the combined score."""
+    for raw in logs:
+        _ = cambia.parse_log_content(raw).evaluation_combined[0].combined_score
+
+
+def full_walk(logs: list[bytes]) -> None:
+    """Parse every log and read every attribute of the result."""
+    for raw in logs:
+        walk(cambia.parse_log_content(raw))
+
+
+def median_seconds(
+    func: Callable[[list[bytes]], None], logs: list[bytes], rounds: int
+) -> float:
+    """Time a benchmark function.
+
+    Args:
+        func: The function to time.
+        logs: Logs passed to the function.
+        rounds: Number of timed runs.
+
+    Returns:
+        The median wall time of one run, in seconds.
+    """
+    func(logs)
+    timings = []
+    for _ in range(rounds):
+        start = time.perf_counter()
+        func(logs)
+        timings.append(time.perf_counter() - start)
+    return statistics.median(timings)
+
+
+def rss_bytes() -> int:
+    """Return the current resident set size, or the peak where unavailable."""
+    statm = Path("/proc/self/statm")
+    if statm.exists():
+        return int(statm.read_text().split()[1]) * os.sysconf("SC_PAGE_SIZE")
+    import resource
+
+    peak = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+    return peak if sys.platform == "darwin" else peak * 1024
+
+
+def retained_bytes(logs: list[bytes], copies: int) -> int:
+    """Measure the memory held by parse results kept alive at once.
+
+    Args:
+        logs: Logs to parse.
+        copies: How many times to parse each log.
+
+    Returns:
+        The growth in resident memory while holding every result.
+    """
+    gc.collect()
+    before = rss_bytes()
+    results = [cambia.parse_log_content(raw) for _ in range(copies) for raw in logs]
+    gc.collect()
+    after = rss_bytes()
+    del results
+    return after - before
+
+
+def main() -> None:
+    """Run the benchmarks and print a summary."""
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--rounds", type=int, default=10, help="timed runs")
+    parser.add_argument("--copies", type=int, default=50, help="results held")
+    args = parser.parse_args()
+
+    logs = load_logs()
+    size = sum(len(raw) for raw in logs)
+    print(f"cambia {cambia.__version__}: {len(logs)} logs, {size / 1024:.0f} KiB")
+
+    for label, func in (("parse + score", score_only), ("parse + walk", full_walk)):
+        seconds = median_seconds(func, logs, args.rounds)
+        per_log = seconds / len(logs) * 1e6
+        print(f"{label:<16} {seconds * 1000:8.2f} ms  ({per_log:.0f} us/log)")
+
+    retained = retained_bytes(logs, args.copies)
+    per_result = retained / (len(logs) * args.copies) / 1024
+    mib = retained / 2**20
+    print(f"{'retained memory':<16} {mib:8.2f} MiB  ({per_result:.1f} KiB/result)")
+
+
+if __name__ == "__main__":
+    main()
@@ -6,10 +6,32 @@ Use this module to parse and score CD rip logs from various rippers.
 
 from ._cambia import (
     AccurateRipConfidence,
+    AccurateRipConsistency,
+    AccurateRipGroup,
+    AccurateRipOffsetKind,
+    AccurateRipOutlier,
     AccurateRipStatus,
+    AccurateRipSummary,
+    AccurateRipOutcome,
+    AccurateRipTotalKind,
     AccurateRipUnit,
+    ArchiveEntry,
     CambiaResponse,
     Checksum,
+    Coverage,
+    CoverageIssue,
+    CtdbResult,
+    CtdbStatus,
+    CtdbTrack,
+    DiscErrorRange,
+    DiscInfo,
+    DiscLayout,
+    EncodingCandidate,
+    EncodingDetection,
+    ErrorKind,
+    ErrorMap,
+    HtoaExtraction,
+    ErrorSpan,
     Evaluation,
     EvaluationCombined,
     EvaluationUnit,
@@ -19,26 +41,51 @@ from ._cambia import (
     EvaluationUnitScope,
     EvaluatorType,
     Gap,
+    HtoaInfo,
+    InternalError,
     Integrity,
+    Isrc,
+    MSF,
+    Mcn,
     MediaType,
     ParsedLog,
     ParsedLogCombined,
     Quartet,
+    RangeSplit,
+    RangeTrack,
     ReadMode,
     ReleaseInfo,
+    Remediation,
     Ripper,
+    ScoreComparison,
+    SniffResult,
     TestAndCopy,
     Toc,
     TocEntry,
     TocHash,
     TocRaw,
     TrackEntry,
+    TrackEvaluation,
+    TrackInfo,
     TrackError,
     TrackErrorData,
     TrackErrorRange,
+    TrackType,
+    TranslatedLine,
+    TranslatedLog,
+    compare,
+    detect_encoding,
+    evaluate,
     get_supported_rippers,
+    parse_archive,
     parse_log_content,
     parse_log_file,
+    parse_only,
+    read_ctdb,
+    read_disc_info,
+    sniff,
+    split_range,
+    translate_log,
 )
 
 # Version is automatically set by maturin from Cargo.toml
@@ -52,7 +99,19 @@ __all__ = [
     "__version__",
     "parse_log_file",
     "parse_log_content",
+    "parse_only",
+    "evaluate",
+    "compare",
+    "split_range",
+    "parse_archive",
+    "detect_encoding",
+    "sniff",
+    "translate_log",
+    "read_disc_info",
+    "read_ctdb",
     "get_supported_rippers",
+    # Exceptions
+    "InternalError",
     # Enums
     "Ripper",
     "MediaType",
@@ -61,9 +120,19 @@ __all__ = [
     "Gap",
     "Integrity",
     "AccurateRipStatus",
+    "AccurateRipOffsetKind",
+    "AccurateRipOutcome",
+    "AccurateRipTotalKind",
+    "CtdbStatus",
+    "ErrorKind",
+    "HtoaExtraction",
     "EvaluatorType",
+    "TrackType",
+    "DiscLayout",
     # Data classes
+    "MSF",
     "CambiaResponse",
+    "ScoreComparison",
     "ParsedLogCombined",
     "ParsedLog",
     "ReleaseInfo",
@@ -76,14 +145,40 @@ __all__ = [
     "TrackError",
     "TrackErrorData",
     "TrackErrorRange",
+    "DiscErrorRange",
+    "ErrorMap",
+    "ErrorSpan",
+    "RangeSplit",
+    "RangeTrack",
+    "Coverage",
+    "CoverageIssue",
+    "HtoaInfo",
+    "DiscInfo",
+    "TrackInfo",
+    "Isrc",
+    "Mcn",
     "TestAndCopy",
     "AccurateRipUnit",
     "AccurateRipConfidence",
+    "AccurateRipSummary",
+    "AccurateRipConsistency",
+    "AccurateRipGroup",
+    "AccurateRipOutlier",
+    "CtdbResult",
+    "CtdbTrack",
     "EvaluationCombined",
     "Evaluation",
+    "TrackEvaluation",
     "EvaluationUnit",
     "EvaluationUnitData",
     "EvaluationUnitField",
     "EvaluationUnitScope",
     "EvaluationUnitClass",
+    "Remediation",
+    "ArchiveEntry",
+    "EncodingDetection",
+    "EncodingCandidate",
+    "SniffResult",
+    "TranslatedLog",
+    "TranslatedLine",
 ]
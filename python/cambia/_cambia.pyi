@@ -1,8 +1,24 @@
 """Type stubs for the Rust extension module."""
 
+import mmap
 import os
 from datetime import timedelta
 from enum import Enum
+from typing import Any, Literal, Protocol
+
+class _SupportsRead(Protocol):
+    def read(self) -> bytes | str: ...
+
+_LogContent = str | bytes | bytearray | memoryview | mmap.mmap | _SupportsRead
+
+# ============= Exceptions =============
+
+class InternalError(Exception):
+    """Raised when cambia panics on an input instead of returning an error.
+
+    The message includes the panic message. This indicates a bug in cambia
+    rather than in the caller, and the process stays usable.
+    """
 
 # ============= Enums =============
 
@@ -148,8 +164,145 @@ class EvaluationUnitClass(Enum):
     Good = ...
     Perfect = ...
 
+class TrackType(Enum):
+    """Whether a TOC track holds audio or data."""
+
+    _value_: str
+
+    Audio = ...
+    Data = ...
+
+class DiscLayout(Enum):
+    """How audio and data tracks are laid out on a disc.
+
+    Enhanced CDs have a data track in a second session after the audio,
+    11400 sectors after its end. Mixed-mode CDs start with a data track,
+    which the TOC ends at least 150 sectors before the first audio track.
+    Logs do not mark data tracks, so the layout is inferred from these gaps.
+    """
+
+    _value_: str
+
+    Plain = ...
+    Enhanced = ...
+    MixedMode = ...
+
+class AccurateRipOffsetKind(Enum):
+    """Whether an AccurateRip match used the offset of the database."""
+
+    _value_: str
+
+    Same = ...
+    Different = ...
+
+class AccurateRipTotalKind(Enum):
+    """Whether an AccurateRip total counts all versions or one."""
+
+    _value_: str
+
+    All = ...
+    Version = ...
+
+class AccurateRipOutcome(Enum):
+    """What AccurateRip said about a track, taking its best result.
+
+    ``Offset`` is a match at another pressing's offset.
+    """
+
+    _value_: str
+
+    Match = ...
+    Offset = ...
+    Mismatch = ...
+    NotFound = ...
+
+class CtdbStatus(Enum):
+    """CUETools DB result of a disc or track."""
+
+    _value_: str
+
+    Verified = ...
+    Differs = ...
+    NoMatch = ...
+    NotFound = ...
+    Unknown = ...
+
+class ErrorKind(Enum):
+    """A kind of track error.
+
+    The value is the name of its TrackError attribute, e.g. ``"read"``.
+    """
+
+    _value_: str
+
+    Read = ...
+    Skip = ...
+    JitterGeneric = ...
+    JitterEdge = ...
+    JitterAtom = ...
+    Drift = ...
+    Dropped = ...
+    Duplicated = ...
+    DamagedSectors = ...
+    InconsistentErrorSectors = ...
+    MissingSamples = ...
+
+class HtoaExtraction(Enum):
+    """How the hidden track one audio of a disc was extracted."""
+
+    _value_: str
+
+    Track = ...
+    Range = ...
+
 # ============= Classes =============
 
+class MSF:
+    """A CD position or length in minutes, seconds and frames.
+
+    Stored as a whole number of sectors (75 per second), so comparison,
+    hashing and arithmetic are exact. Adding or subtracting an MSF or an
+    int number of sectors gives a new MSF. Positions count from the start
+    of the program area, without the 2-second lead-in that Red Book
+    absolute addresses add.
+    """
+
+    minutes: int
+    seconds: int
+    frames: int
+    sectors: int
+    samples: int
+    duration: timedelta
+
+    def __init__(self, minutes: int = 0, seconds: int = 0, frames: int = 0) -> None:
+        """Create an MSF.
+
+        Raises:
+            ValueError: If seconds is not below 60 or frames not below 75.
+        """
+        ...
+    @staticmethod
+    def from_sectors(sectors: int) -> MSF: ...
+    @staticmethod
+    def parse(text: str) -> MSF:
+        """Parse "mm:ss:ff", or EAC's "mm:ss.ff".
+
+        Raises:
+            ValueError: If the text is not in that form.
+        """
+        ...
+    def __add__(self, other: MSF | int) -> MSF: ...
+    def __radd__(self, other: MSF | int) -> MSF: ...
+    def __sub__(self, other: MSF | int) -> MSF:
+        """Raises ValueError if the result would be negative."""
+        ...
+    def __int__(self) -> int: ...
+    def __lt__(self, other: MSF) -> bool: ...
+    def __le__(self, other: MSF) -> bool: ...
+    def __gt__(self, other: MSF) -> bool: ...
+    def __ge__(self, other: MSF) -> bool: ...
+    def __hash__(self) -> int: ...
+
 class TocEntry:
     """Represents a single TOC (Table of Contents) entry."""
 
@@ -158,6 +311,34 @@ class TocEntry:
     length: timedelta
     start_sector: int
     end_sector: int
+    length_sectors: int
+    start_msf: MSF
+    length_msf: MSF
+    start_sample: int
+    length_samples: int
+    track_type: TrackType
+    session: int
+
+    def __init__(
+        self,
+        track: int,
+        start_sector: int,
+        end_sector: int,
+        *,
+        start: timedelta | None = None,
+        length: timedelta | None = None,
+    ) -> None:
+        """Create a TOC entry.
+
+        Start and length default to the times the sectors span, at 75
+        sectors per second.
+
+        Raises:
+            ValueError: If the track is above 99, the end sector is above
+                449849 (the last sector of a 99:59:74 disc) or before the
+                start sector.
+        """
+        ...
 
 class TocHash:
     """Hash information for various disc ID services."""
@@ -165,15 +346,30 @@ class TocHash:
     hash: str
     url: str
 
+    def __init__(self, hash: str = "", url: str = "") -> None: ...
+
 class TocRaw:
-    """Raw TOC information."""
+    """Raw TOC information.
+
+    Entries have their track type and session from the layout of the disc.
+    """
 
     entries: list[TocEntry]
+    disc_layout: DiscLayout
+
+    def __init__(self, entries: list[TocEntry] = ...) -> None:
+        """Create a raw TOC.
+
+        Raises:
+            ValueError: If the entries are not in track order or overlap.
+        """
+        ...
 
 class Toc:
     """Table of contents data with various disc IDs."""
 
     raw: TocRaw
+    disc_layout: DiscLayout
     freedb: TocHash
     accurip_tocid: TocHash
     ctdb_tocid: TocHash
@@ -181,6 +377,28 @@ class Toc:
     gn: TocHash
     mcdi: TocHash
 
+    def __init__(
+        self,
+        raw: TocRaw | None = None,
+        *,
+        freedb: TocHash | None = None,
+        accurip_tocid: TocHash | None = None,
+        ctdb_tocid: TocHash | None = None,
+        mbz: TocHash | None = None,
+        gn: TocHash | None = None,
+        mcdi: TocHash | None = None,
+    ) -> None:
+        """Create a TOC.
+
+        The freedb, AccurateRip, CTDB and MusicBrainz IDs that are not given
+        are computed from the entries. AccurateRip and CTDB leave data
+        tracks out, AccurateRip keeping the lead-out of the whole disc, and
+        MusicBrainz covers the first session only. Gracenote and MCDI IDs
+        are taken as given. The computed IDs are empty if the entries run
+        past the sector addresses a disc can have.
+        """
+        ...
+
 class Checksum:
     """Checksum information."""
 
@@ -188,18 +406,65 @@ class Checksum:
     log: str
     integrity: Integrity
 
+    def __init__(
+        self,
+        calculated: str = "",
+        log: str = "",
+        integrity: Integrity | None = None,
+    ) -> None:
+        """Create a checksum. Integrity defaults to comparing the two."""
+        ...
+
 class ReleaseInfo:
     """Album release information."""
 
     artist: str
     title: str
 
+    def __init__(self, artist: str = "", title: str = "") -> None: ...
+
 class AccurateRipConfidence:
-    """AccurateRip confidence information."""
+    """AccurateRip confidence information.
+
+    ``offset`` is the offset as text, "Same", "Different" or
+    "Different (n)"; ``offset_kind`` and ``offset_value`` give it typed,
+    with ``offset_value`` None for the same offset or an unlogged one.
+    """
 
     matching: int | None
     total: int | None
+    total_kind: AccurateRipTotalKind | None
     offset: str
+    offset_kind: AccurateRipOffsetKind
+    offset_value: int | None
+
+    def __init__(
+        self,
+        matching: int | None = None,
+        total: int | None = None,
+        offset: int | None = None,
+        *,
+        total_kind: AccurateRipTotalKind = ...,
+        offset_kind: AccurateRipOffsetKind | None = None,
+    ) -> None:
+        """Create an AccurateRip confidence.
+
+        Args:
+            matching: Number of matching submissions.
+            total: Total number of submissions.
+            offset: The differing offset the match was found with, or None
+                if it was the same.
+            total_kind: Whether total counts all AccurateRip versions
+                (default) or the result's version only.
+            offset_kind: Different with no offset for a match at an
+                unlogged different offset. Defaults to Different when an
+                offset is given and Same otherwise.
+
+        Raises:
+            ValueError: If matching is above total, or an offset is given
+                with offset_kind Same.
+        """
+        ...
 
 class AccurateRipUnit:
     """AccurateRip information for a track."""
@@ -209,6 +474,21 @@ class AccurateRipUnit:
     sign: str
     version: int | None
 
+    def __init__(
+        self,
+        status: AccurateRipStatus,
+        *,
+        confidence: AccurateRipConfidence | None = None,
+        sign: str = "",
+        version: int | None = None,
+    ) -> None:
+        """Create an AccurateRip result.
+
+        Raises:
+            ValueError: If the version is not 1 or 2.
+        """
+        ...
+
 class TestAndCopy:
     """Test and copy hash verification."""
 
@@ -216,11 +496,47 @@ class TestAndCopy:
     copy_hash: str
     integrity: Integrity
 
+    def __init__(
+        self,
+        test_hash: str = "",
+        copy_hash: str = "",
+        integrity: Integrity | None = None,
+    ) -> None:
+        """Create a test and copy result.
+
+        Integrity defaults to comparing the two hashes.
+        """
+        ...
+
 class TrackErrorRange:
-    """Range of track errors."""
+    """Range of track errors, relative to the start of the track.
+
+    The sector and MSF attributes round to the nearest sector; the sample
+    attributes round to the nearest 44.1 kHz sample.
+    """
 
     start: timedelta
     length: timedelta
+    start_sector: int
+    length_sectors: int
+    start_msf: MSF
+    length_msf: MSF
+    start_sample: int
+    length_samples: int
+
+    def __init__(self, start: timedelta, length: timedelta) -> None: ...
+
+class DiscErrorRange:
+    """A track error range placed on the disc by ParsedLog.error_ranges()."""
+
+    track: int
+    kind: ErrorKind
+    start_sector: int
+    end_sector: int
+    length_sectors: int
+    start_msf: MSF
+    end_msf: MSF
+    range: TrackErrorRange
 
 class TrackErrorData:
     """Track error data."""
@@ -228,6 +544,12 @@ class TrackErrorData:
     count: int
     ranges: list[TrackErrorRange]
 
+    def __init__(
+        self, count: int | None = None, ranges: list[TrackErrorRange] = ...
+    ) -> None:
+        """Create track error data. Count defaults to the number of ranges."""
+        ...
+
 class TrackError:
     """Track error information."""
 
@@ -243,6 +565,24 @@ class TrackError:
     inconsistent_err_sectors: TrackErrorData
     missing_samples: TrackErrorData
 
+    def __init__(
+        self,
+        *,
+        read: TrackErrorData | None = None,
+        skip: TrackErrorData | None = None,
+        jitter_generic: TrackErrorData | None = None,
+        jitter_edge: TrackErrorData | None = None,
+        jitter_atom: TrackErrorData | None = None,
+        drift: TrackErrorData | None = None,
+        dropped: TrackErrorData | None = None,
+        duplicated: TrackErrorData | None = None,
+        damaged_sectors: TrackErrorData | None = None,
+        inconsistent_err_sectors: TrackErrorData | None = None,
+        missing_samples: TrackErrorData | None = None,
+    ) -> None:
+        """Create track errors. Kinds that are not given have no errors."""
+        ...
+
 class TrackEntry:
     """Individual track information."""
 
@@ -259,6 +599,30 @@ class TrackEntry:
     errors: TrackError
     ar_info: list[AccurateRipUnit]
 
+    def __init__(
+        self,
+        num: int,
+        *,
+        is_range: bool = False,
+        aborted: bool = False,
+        filenames: list[str] = ...,
+        peak_level: float | None = None,
+        pregap_length: timedelta | None = None,
+        extraction_speed: float | None = None,
+        gain: float | None = None,
+        preemphasis: bool | None = None,
+        test_and_copy: TestAndCopy | None = None,
+        errors: TrackError | None = None,
+        ar_info: list[AccurateRipUnit] = ...,
+    ) -> None:
+        """Create a track.
+
+        Raises:
+            ValueError: If the track number is above 99, or the peak level
+                or extraction speed is negative.
+        """
+        ...
+
 class ParsedLog:
     """Parsed log data from a single ripper log."""
 
@@ -283,9 +647,450 @@ class ParsedLog:
     gap_handling: Gap
     checksum: Checksum
     toc: Toc
+    disc_info: DiscInfo | None
+    ctdb: CtdbResult | None
     tracks: list[TrackEntry]
     id3_enabled: Quartet
     audio_encoder: list[str]
+    accuraterip: AccurateRipSummary
+    htoa: HtoaInfo
+
+    def __init__(
+        self,
+        ripper: Ripper,
+        *,
+        tracks: list[TrackEntry] = ...,
+        toc: Toc | None = None,
+        release_info: ReleaseInfo | None = None,
+        checksum: Checksum | None = None,
+        encoding: str = "UTF-8",
+        **settings: Any,
+    ) -> None:
+        """Build a log by hand, e.g. to test code that takes parse results.
+
+        The log can be passed to evaluate() like a parsed one.
+
+        Args:
+            ripper: The ripper the log is from.
+            tracks: The ripped tracks.
+            toc: The disc's TOC; empty if not given.
+            release_info: Artist and title; empty if not given.
+            checksum: The log checksum; Unknown if not given.
+            encoding: The encoding the log file is evaluated as having.
+            **settings: Other attributes, with the same names and types as
+                replace() takes, e.g. ``drive`` or ``read_offset``. Quartet
+                settings that are not given are ``Quartet.UNKNOWN``.
+
+        Raises:
+            TypeError: If a setting is unknown or has the wrong type.
+            ValueError: If a track number is listed twice.
+        """
+        ...
+    @staticmethod
+    def secure(
+        ripper: Ripper,
+        *,
+        tracks: list[TrackEntry] = ...,
+        toc: Toc | None = None,
+        release_info: ReleaseInfo | None = None,
+        checksum: Checksum | None = None,
+        encoding: str = "UTF-8",
+        **settings: Any,
+    ) -> ParsedLog:
+        """Build a log like the constructor, with recommended defaults.
+
+        Settings that are not given take their recommended values: cache
+        defeating, Accurate Stream, test and copy, null samples and silence
+        filling on; C2, overread, silent block deletion, normalization and
+        ID3 off; secure read mode, appended gaps and a pressed disc.
+        """
+        ...
+
+    def to_log_text(
+        self, ripper: Ripper | None = None, language: Literal["en"] = "en"
+    ) -> str:
+        """Write this log back out as English ripper log text.
+
+        The text has the settings block, TOC, per-track sections with CRCs
+        and AccurateRip results, and the summary. Parsing it again gives an
+        equivalent ParsedLog. It has no checksum and ends with a line
+        marking it as regenerated. Localized EAC logs are not written.
+
+        Args:
+            ripper: Ripper.EAC, Ripper.XLD or Ripper.Whipper; defaults to
+                the log's own ripper.
+            language: Language of the text. Only "en" is accepted.
+
+        Raises:
+            ValueError: If the ripper is not supported or the language is
+                not "en".
+        """
+        ...
+    def replace(self, **changes: Any) -> ParsedLog:
+        """Return a copy of this log with the given attributes changed.
+
+        Used to score a log as if it had been ripped with other settings,
+        via evaluate() or compare(). Settings take the same types as the
+        matching attributes; Quartet settings also accept a bool.
+
+        Args:
+            **changes: New attribute values, e.g. ``test_and_copy=True`` or
+                ``gap_handling=Gap.Append``. ``tracks`` maps track numbers
+                to per-track changes: TrackEntry attributes such as
+                ``aborted``, ``ar_status`` to set the AccurateRip status,
+                and ``errors`` mapping error kinds (ErrorKind or its value,
+                e.g. ``"read"``) to a new count.
+
+        Returns:
+            A modified copy, independent of the original response.
+
+        Raises:
+            TypeError: If an attribute is unknown or a value has the wrong
+                type.
+            ValueError: If a track number or error kind does not exist.
+        """
+        ...
+    def error_ranges(self) -> list[DiscErrorRange]:
+        """Place every track error range on the disc.
+
+        Each range is added to the start sector of its track in the TOC, or
+        of the first TOC entry for range rips. Ranges with no length cover
+        the one sector they are in. Tracks missing from the TOC are left
+        out.
+
+        Returns:
+            One DiscErrorRange per range, in track order, with ``kind``
+            the ErrorKind it came from and inclusive absolute sectors.
+        """
+        ...
+
+    def error_map(self) -> ErrorMap:
+        """Merge the error ranges of every track into a disc-wide timeline.
+
+        Ranges are placed on the disc as by error_ranges().
+        """
+        ...
+    def render_error_map_svg(self, width: int = 800) -> str:
+        """Draw the error map as a standalone SVG document.
+
+        The disc is a bar with track boundaries from the TOC and a coloured
+        band per error interval, with per-track peak level bars and
+        extraction speeds below it and a legend of the error kinds. Exact
+        positions and values are in tooltips.
+
+        Args:
+            width: Width of the image in pixels, at least 200.
+
+        Returns:
+            The SVG markup.
+        """
+        ...
+    def accuraterip_consistency(self) -> AccurateRipConsistency:
+        """Check the AccurateRip results of the tracks against each other.
+
+        A range rip is checked per TOC track.
+        """
+        ...
+    def coverage(self) -> Coverage:
+        """Line the tracks of the log up against its TOC.
+
+        A range covers every TOC track without an entry of its own. The
+        last TOC track is a data track when a session gap of 11400 sectors
+        comes before it, as on Enhanced CDs; data tracks are never missing.
+        Issues are TOC lengths and starts that do not match their sectors,
+        gaps or overlaps between tracks, pre-gaps that do not fit the TOC
+        and error ranges that run past the end of their track.
+        """
+        ...
+
+class ErrorSpan:
+    """A stretch of the disc where the same error kinds apply."""
+
+    start_sector: int
+    end_sector: int
+    length_sectors: int
+    start_msf: MSF
+    end_msf: MSF
+    kinds: list[ErrorKind]
+
+class ErrorMap:
+    """Disc-wide error timeline from ParsedLog.error_map().
+
+    Intervals are inclusive and merged where they overlap or touch, keyed by
+    error kind in TrackError order. error_sectors counts every sector with
+    an error once, whatever its kinds.
+    """
+
+    disc_start: int | None
+    disc_end: int | None
+    intervals: dict[ErrorKind, list[tuple[int, int]]]
+    timeline: list[ErrorSpan]
+    error_sectors: int
+
+class RangeTrack:
+    """A TOC track of a split range rip, with its sector span."""
+
+    track: int
+    start_sector: int
+    end_sector: int
+    length_sectors: int
+    derived: bool
+    entry: TrackEntry
+
+class RangeSplit:
+    """A log with its range pseudo-track split up by split_range().
+
+    ``log`` has one entry per TOC track instead of the pseudo-track, which
+    is kept as ``range`` (None if the log is not a range rip). ``derived``
+    on each RangeTrack tells whether its entry was made from the range.
+    ``unplaced_errors`` counts, per error kind, errors that no track could
+    be given: those logged without a position and those outside the TOC.
+    """
+
+    log: ParsedLog
+    tracks: list[RangeTrack]
+    range: TrackEntry | None
+    unplaced_errors: dict[ErrorKind, int]
+
+class CoverageIssue:
+    """Something in a log that does not add up with its TOC."""
+
+    track: int
+    message: str
+
+class Coverage:
+    """Which tracks a log ripped, from ParsedLog.coverage().
+
+    Every TOC audio track is in exactly one of ``ripped``, ``aborted`` and
+    ``missing``; logged tracks that are not in the TOC are in
+    ``not_in_toc`` as well. ``htoa_sectors`` is where track one starts,
+    and ``htoa_present`` is set from a second on. ``htoa_extracted`` is
+    None for range rips, since the parsed log does not keep the sectors of
+    the range.
+    """
+
+    ripped: list[int]
+    aborted: list[int]
+    missing: list[int]
+    duplicated: list[int]
+    not_in_toc: list[int]
+    data_tracks: list[int]
+    range_rip: bool
+    complete: bool
+    htoa_sectors: int
+    htoa_present: bool
+    htoa_extracted: bool | None
+    issues: list[CoverageIssue]
+    consistent: bool
+
+class AccurateRipSummary:
+    """The AccurateRip results of a disc, from ParsedLog.accuraterip.
+
+    ``tracks`` are the tracks with a result other than a disabled lookup,
+    ``matched`` those that matched in any version. ``min_confidence`` is
+    the lowest confidence of any track, counting an unmatched track as 0,
+    and None without results. ``version_conflicts`` are tracks whose v1
+    and v2 results disagree on a match, and ``different_offset`` tracks
+    that only matched at another pressing's offset, with the distinct
+    logged offsets in ``offsets``.
+    """
+
+    tracks: list[int]
+    matched: list[int]
+    unmatched: list[int]
+    all_tracks_matched: bool
+    min_confidence: int | None
+    version_conflicts: list[int]
+    versions_agree: bool
+    different_offset: list[int]
+    offsets: list[int]
+
+class AccurateRipGroup:
+    """Tracks with the same AccurateRip outcome.
+
+    ``offset`` is the pressing's offset for ``AccurateRipOutcome.Offset``
+    groups, where logged.
+    """
+
+    outcome: AccurateRipOutcome
+    offset: int | None
+    tracks: list[int]
+
+class AccurateRipOutlier:
+    """A track whose AccurateRip result contradicts the majority."""
+
+    track: int
+    outcome: AccurateRipOutcome
+    offset: int | None
+    message: str
+
+class AccurateRipConsistency:
+    """AccurateRip results of the tracks of a disc checked against each other.
+
+    From ParsedLog.accuraterip_consistency(). ``groups`` holds the tracks
+    per outcome, the largest first, with tracks that matched at the same
+    pressing's offset in one group, and ``totals`` the tracks per number
+    of submissions. ``majority`` is the group of more tracks than any
+    other, None on a tie, and ``outliers`` the tracks outside it.
+    ``unchecked`` tracks have no result. ``verified`` is True when every
+    track matched, at one pressing's offset. ``totals_agree`` is False
+    when tracks have different numbers of submissions in ``totals``.
+    """
+
+    groups: list[AccurateRipGroup]
+    totals: dict[int, list[int]]
+    majority: AccurateRipGroup | None
+    outliers: list[AccurateRipOutlier]
+    unchecked: list[int]
+    verified: bool
+    totals_agree: bool
+
+class HtoaInfo:
+    """Hidden track one audio (HTOA) of a disc, from ParsedLog.htoa.
+
+    The hidden track is the audio before track one, ``length_sectors``
+    long according to the TOC; it is ``present`` from a second on.
+    ``extraction`` is ``HtoaExtraction.Track`` for a track 0 entry and
+    ``HtoaExtraction.Range`` for a range rip of a disc with a hidden track, with ``entry`` holding it.
+    The parsed log does not keep the sectors of a range, so a range is not
+    known to start at sector 0. ``guidance`` says how to rip the hidden
+    track with the log's ripper, and is None when it was extracted with
+    matching test and copy CRCs or there is none.
+    """
+
+    length_sectors: int
+    length_msf: MSF
+    present: bool
+    extraction: HtoaExtraction | None
+    entry: TrackEntry | None
+    tested: bool
+    crcs_match: bool
+    verified: bool
+    guidance: str | None
+
+class Isrc:
+    """An International Standard Recording Code.
+
+    ``code`` is normalized to 12 uppercase characters without hyphens.
+    ISRCs have no check digit, so ``valid`` checks the format: a 2-letter
+    country, a 3-character registrant, a 2-digit year and a 5-digit
+    designation, which are None when the code is not valid. ``str()``
+    gives the hyphenated form. Compares equal by code.
+    """
+
+    code: str
+    valid: bool
+    country: str | None
+    registrant: str | None
+    year: str | None
+    designation: str | None
+
+    def __init__(self, code: str) -> None: ...
+
+class Mcn:
+    """A media catalog number, the EAN-13 or UPC-A barcode of a disc.
+
+    ``valid`` checks the length and the check digit; the all-zero code
+    drives return for discs without a catalog number is not valid.
+    ``ean`` is the 13-digit form and ``upc`` the 12-digit one for codes
+    with a leading zero. Compares and hashes by EAN, so a UPC-A equals its
+    EAN-13.
+    """
+
+    code: str
+    valid: bool
+    ean: str | None
+    upc: str | None
+
+    def __init__(self, code: str) -> None: ...
+
+class TrackInfo:
+    """ISRC and CD-Text of one track, from read_disc_info()."""
+
+    track: int
+    isrc: Isrc | None
+    performer: str | None
+    title: str | None
+
+class DiscInfo:
+    """Identifiers and CD-Text of one log, from read_disc_info() or
+    ParsedLog.disc_info.
+
+    ``mcn`` is None for logs without a catalog number or with only the
+    all-zero one. ``performer`` and ``title`` are the disc's CD-Text, not
+    the release metadata the ripper looked up. ``isrcs`` maps track
+    numbers to the ISRCs that are logged, and ``valid`` is True when the
+    catalog number and every ISRC are valid.
+    """
+
+    ripper: Ripper
+    mcn: Mcn | None
+    performer: str | None
+    title: str | None
+    tracks: list[TrackInfo]
+    isrcs: dict[int, Isrc]
+    valid: bool
+
+class CtdbTrack:
+    """CUETools DB result of one track."""
+
+    track: int
+    status: CtdbStatus
+    confidence: int | None
+    total: int | None
+    differing_samples: int | None
+
+    def __init__(
+        self,
+        track: int,
+        status: CtdbStatus,
+        *,
+        confidence: int | None = None,
+        total: int | None = None,
+        differing_samples: int | None = None,
+    ) -> None:
+        """Build a track result.
+
+        Raises:
+            ValueError: If confidence is greater than total.
+        """
+        ...
+
+class CtdbResult:
+    """CUETools DB result of one log, from read_ctdb() or ParsedLog.ctdb.
+
+    ``confidence`` is the number of submissions that match the rip and
+    ``total`` the number of submissions for the disc. Where a log has only
+    track results, the disc result is made up from them: Verified if every
+    track is, with the lowest confidence, and ``differing_samples`` is the
+    sum of theirs. ``verified`` is True for a Verified disc. Evaluations
+    report it without changing the score.
+    """
+
+    tocid: str | None
+    status: CtdbStatus
+    confidence: int | None
+    total: int | None
+    differing_samples: int | None
+    tracks: list[CtdbTrack]
+    verified: bool
+
+    def __init__(
+        self,
+        status: CtdbStatus,
+        *,
+        confidence: int | None = None,
+        total: int | None = None,
+        differing_samples: int | None = None,
+        tocid: str | None = None,
+        tracks: list[CtdbTrack] = ...,
+    ) -> None:
+        """Build a result, e.g. to pass to evaluate().
+
+        Raises:
+            ValueError: If confidence is greater than total.
+        """
+        ...
 
 class ParsedLogCombined:
     """Main parsed data container."""
@@ -293,11 +1098,27 @@ class ParsedLogCombined:
     encoding: str
     parsed_logs: list[ParsedLog]
 
+    def __init__(
+        self, parsed_logs: list[ParsedLog], *, encoding: str = "UTF-8"
+    ) -> None:
+        """Combine logs, e.g. hand-built ones, for evaluate().
+
+        Raises:
+            ValueError: If parsed_logs is empty.
+        """
+        ...
+
 class EvaluationUnitScope:
-    """Scope of evaluation unit."""
+    """Scope of evaluation unit. Hashable and comparable by value."""
 
     name: str
     value: str
+    track: int | None
+
+    @staticmethod
+    def Release() -> EvaluationUnitScope: ...
+    @staticmethod
+    def Track(track: int | None) -> EvaluationUnitScope: ...
 
 class EvaluationUnitData:
     """Evaluation unit data."""
@@ -311,20 +1132,98 @@ class EvaluationUnit:
     """Single evaluation unit."""
 
     unit_score: str
+    points: int | None
+    impact: int | None
     data: EvaluationUnitData
+    remediation: Remediation | None
+
+class Remediation:
+    """How to avoid a deduction with the ripper that produced the log."""
+
+    setting: str | None
+    value: str | None
+    advice: str
+    rerip: bool
+
+_Deduction = tuple[
+    EvaluationUnitField, str, int | None, EvaluationUnitClass, EvaluationUnitScope
+]
 
 class Evaluation:
     """Individual evaluation with units."""
 
     score: str
+    numeric_score: int | None
+    max_score: int
+    deducted: int | None
     evaluation_units: list[EvaluationUnit]
+    ctdb: CtdbResult | None
+    ctdb_verified: bool
+
+    def deductions(self) -> list[_Deduction]:
+        """Return the units of this log, largest deduction first.
+
+        Returns:
+            (field, message, points, class, scope) tuples, one per unit.
+            Units whose score is not numeric come last.
+        """
+        ...
+
+    def by_track(self) -> dict[int | None, TrackEvaluation]:
+        """Group the track-scoped units by track number.
+
+        Returns:
+            A mapping in track order from track number to that track's
+            units and score. Units for a track the evaluator did not
+            number are keyed by None.
+        """
+        ...
+
+    def release_units(self) -> list[EvaluationUnit]:
+        """Return the units that apply to the whole release."""
+        ...
+
+class TrackEvaluation:
+    """Evaluation units of one track, with a score computed from them alone.
+
+    ``score`` is 100 minus ``deducted``, clamped at zero: what the track
+    would score if its units were the only ones. cambia-core itself only
+    scores the log as a whole.
+    """
+
+    track: int | None
+    evaluation_units: list[EvaluationUnit]
+    deducted: int
+    score: int
+
+    def deductions(self) -> list[_Deduction]:
+        """Return this track's units, largest deduction first.
+
+        Returns:
+            (field, message, points, class, scope) tuples, one per unit.
+        """
+        ...
 
 class EvaluationCombined:
     """Combined evaluation results."""
 
     evaluator: EvaluatorType
     combined_score: str
+    numeric_score: int | None
+    max_score: int
+    deducted: int | None
     evaluations: list[Evaluation]
+    ctdb_verified: bool
+
+    def deductions(self) -> list[list[_Deduction]]:
+        """Return the units of each log, largest deduction first.
+
+        Returns:
+            One list per log, in the order of `evaluations`, of
+            (field, message, points, class, scope) tuples. Units whose score
+            is not numeric come last.
+        """
+        ...
 
 class CambiaResponse:
     """Main response data from Cambia."""
@@ -333,36 +1232,371 @@ class CambiaResponse:
     parsed: ParsedLogCombined
     evaluation_combined: list[EvaluationCombined]
 
+class ScoreComparison:
+    """Scores of a log before and after a change, from compare()."""
+
+    original: CambiaResponse
+    modified: CambiaResponse
+    delta: int | None
+
+class ArchiveEntry:
+    """A log found inside an archive, with its parse result."""
+
+    path: str
+    response: CambiaResponse | None
+    error: str | None
+
+class EncodingCandidate:
+    """A possible encoding of a log, with a heuristic score.
+
+    ``score`` is the encoding's share of chardetng's guesses under a range
+    of regional hints, scored down if it cannot decode the log without
+    errors. It ranks candidates; it is not a probability.
+    """
+
+    encoding: str
+    score: float
+
+class EncodingDetection:
+    """Result of detect_encoding()."""
+
+    bom: bool
+    candidates: list[EncodingCandidate]
+
+class SniffResult:
+    """Header-level summary of a log produced by sniff().
+
+    ``language`` is None for EAC logs in languages other than English,
+    German, Russian, Spanish and Swedish, which are still identified and
+    split.
+    """
+
+    ripper: Ripper | None
+    ripper_version: str | None
+    language: str | None
+    encoding: str
+    combined: bool
+    log_count: int
+    has_checksum: bool
+
+class TranslatedLine:
+    """One line of a translated log, mapped back to the original."""
+
+    number: int
+    original: str
+    text: str
+    recognized: bool
+
+class TranslatedLog:
+    """A log translated into another language by translate_log()."""
+
+    text: str
+    source_language: str | None
+    target: str
+    lines: list[TranslatedLine]
+    untranslated: list[TranslatedLine]
+    complete: bool
+
 # ============= Functions =============
 
-def parse_log_file(path: str | os.PathLike[str]) -> CambiaResponse:
+def parse_log_file(
+    path: str | os.PathLike[str],
+    *,
+    encoding: str | None = None,
+    ripper: Ripper | None = None,
+) -> CambiaResponse:
     """Parse a CD ripping log file and return the parsed data.
 
+    Gzip-compressed logs (e.g. ``.log.gz``) are decompressed transparently.
+
     Args:
         path: Path to the log file. Accepts a string or any os.PathLike
             object (e.g. pathlib.Path).
+        encoding: Decode the log with this encoding (e.g. ``"cp1251"``)
+            instead of auto-detecting it.
+        ripper: Run this ripper's parser directly instead of detecting
+            the ripper, skipping the evaluators.
 
     Returns:
         Parsed log data.
 
     Raises:
-        ValueError: If parsing fails.
+        ValueError: If parsing fails, or the forced ripper has no parser
+            or cannot read the log.
+        LookupError: If the encoding is unknown.
     """
     ...
 
-def parse_log_content(content: str | bytes) -> CambiaResponse:
-    """Parse log content from a string or bytes.
+def parse_log_content(
+    content: _LogContent,
+    *,
+    encoding: str | None = None,
+    ripper: Ripper | None = None,
+) -> CambiaResponse:
+    """Parse log content from a string, bytes-like object or binary file.
 
     Args:
-        content: Log file content as string or bytes.
-            When bytes, the encoding is auto-detected by cambia-core.
+        content: Log file content as a string, any bytes-like or
+            buffer-protocol object (bytes, bytearray, memoryview, mmap),
+            or a readable binary file object. Objects other than bytes are
+            copied once, and the encoding is auto-detected by cambia-core.
+            File objects are read to the end.
+        encoding: Decode byte content with this encoding (e.g.
+            ``"shift_jis"``) instead of auto-detecting it.
+        ripper: Run this ripper's parser directly instead of detecting
+            the ripper from the log header.
 
     Returns:
         Parsed log data.
 
     Raises:
-        ValueError: If parsing fails.
-        TypeError: If content is not str or bytes.
+        ValueError: If parsing fails, or the forced ripper has no parser
+            or cannot read the log.
+        LookupError: If the encoding is unknown.
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def parse_only(
+    content: _LogContent,
+    *,
+    encoding: str | None = None,
+    ripper: Ripper | None = None,
+) -> ParsedLogCombined:
+    """Parse log content without running any evaluator.
+
+    Returns the same parsed data as parse_log_content(). Without
+    ``ripper``, the parser is picked from the first log header in the
+    text, as sniff() reads it. The result can be scored later with
+    evaluate().
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+        encoding: Decode byte content with this encoding instead of
+            auto-detecting it.
+        ripper: Run this ripper's parser directly instead of detecting
+            the ripper from the log header.
+
+    Returns:
+        Parsed log data.
+
+    Raises:
+        ValueError: If no log header is recognized or parsing fails.
+        LookupError: If the encoding is unknown.
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def evaluate(
+    parsed: ParsedLogCombined | ParsedLog,
+    *,
+    ctdb: list[CtdbResult | None] | None = None,
+) -> CambiaResponse:
+    """Run the evaluators over a log returned by parse_only().
+
+    Args:
+        parsed: The parsed log, or a single ParsedLog such as one returned
+            by ParsedLog.replace().
+        ctdb: The CUETools DB result of each log, in order, to use in
+            place of ParsedLog.ctdb.
+
+    Returns:
+        A response holding the parsed log and its evaluations, the same as
+        parse_log_content() would have returned.
+
+    Raises:
+        TypeError: If parsed is neither a ParsedLogCombined nor a ParsedLog.
+        ValueError: If ctdb has more results than there are logs.
+    """
+    ...
+
+def compare(
+    original: ParsedLogCombined | ParsedLog, modified: ParsedLogCombined | ParsedLog
+) -> ScoreComparison:
+    """Evaluate two versions of a log and report the score difference.
+
+    Args:
+        original: The log as parsed.
+        modified: A changed version, usually from ParsedLog.replace().
+
+    Returns:
+        Both evaluations, and the modified minus the original combined
+        score of the first evaluator.
+
+    Raises:
+        TypeError: If either log is neither a ParsedLogCombined nor a
+            ParsedLog.
+    """
+    ...
+
+def split_range(parsed_log: ParsedLog) -> RangeSplit:
+    """Split the pseudo-track of a range rip into one entry per TOC track.
+
+    Error ranges go to the tracks they fall in, split where they cross a
+    track boundary. AccurateRip results go to tracks in TOC order when the
+    range has one per TOC track, as in EAC's range summary. A matching
+    test and copy marks every derived track as matching. Peak level,
+    extraction speed and CRCs describe the whole range and stay on
+    ``RangeSplit.range``. TOC tracks with an entry of their own, as in XLD
+    range rips, keep it, and logs that are not range rips come back with
+    their tracks unchanged.
+
+    Args:
+        parsed_log: The log to split.
+
+    Returns:
+        The split log and one RangeTrack per TOC track it has an entry for.
+    """
+    ...
+
+def detect_encoding(content: _LogContent) -> EncodingDetection:
+    """Rank the encodings a log could be in.
+
+    Useful when auto-detection picked the wrong encoding: pass the name of
+    a better candidate back through ``encoding=``.
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+
+    Returns:
+        Whether the log starts with a byte order mark, and the candidate
+        encodings sorted by descending score. Scores sum to 1.
+
+    Raises:
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def sniff(content: _LogContent) -> SniffResult:
+    """Identify a log from its headers, without parsing or evaluating it.
+
+    Much cheaper than parse_log_content(), for routing or triaging large
+    numbers of files.
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+
+    Returns:
+        The ripper, version string and language of the first log (None if
+        no log header is recognized), the detected encoding, how many logs
+        a combined file contains, and whether any of them has a checksum
+        or signature block.
+
+    Raises:
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def read_disc_info(content: _LogContent) -> list[DiscInfo]:
+    """Read the ISRCs, catalog number and CD-Text of every log in a file.
+
+    Only "ISRC", "MCN" or "Media catalog number", and "CD-Text Title" or
+    "CD-Text Performer" lines are read; bare "Title" or "Artist" lines are
+    release metadata. Parsed logs carry the same as ParsedLog.disc_info.
+    Codes are kept as logged, with validity on the Isrc and Mcn objects, so
+    that bad codes can be spotted rather than dropped.
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+
+    Returns:
+        One DiscInfo per log, in the order of a combined file, with a
+        TrackInfo per track section. Empty if no log header is recognized.
+
+    Raises:
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def read_ctdb(content: _LogContent) -> list[CtdbResult | None]:
+    """Read the CUETools DB results of every log in a file.
+
+    Reads the section EAC's CTDB plugin or CUERipper writes: database
+    entries for the disc and a result per track. XLD's "CTDB Summary" is
+    read in the layout of its AccurateRip summary, which has not been
+    checked against a real XLD log.
+    Parsed logs carry the result of their part of the file as
+    ParsedLog.ctdb; this reads them without parsing.
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+
+    Returns:
+        One entry per log header, in the order of a combined file, None for
+        logs without a CTDB section. Empty if no log header is recognized.
+
+    Raises:
+        TypeError: If content is not one of the accepted types.
+    """
+    ...
+
+def translate_log(
+    content: _LogContent, target: str = "en", *, strict: bool = False
+) -> TranslatedLog:
+    """Translate a localized EAC log into English, line by line.
+
+    Recognized lines are rewritten with EAC's English wording so the text
+    reads like an English log. Russian, Spanish and Swedish EAC logs are
+    supported, but coverage is partial: the tables hold the wording found
+    in the bundled test logs, not EAC's full language files. Lines no
+    table knows are kept with an "[untranslated] " marker in front and
+    listed in ``untranslated``; ``complete`` is False when there are any.
+    English logs, and logs of other rippers, come back unchanged.
+
+    Args:
+        content: Log content, accepted in the same forms as
+            parse_log_content().
+        target: Language code to translate into. Only "en" is supported.
+        strict: Raise instead of returning a log with untranslated lines.
+
+    Returns:
+        The translated text, the language of the original and one
+        TranslatedLine per original line, with its 1-based line number in
+        the original.
+
+    Raises:
+        TypeError: If content is not one of the accepted types.
+        ValueError: If target is not supported, no log header is
+            recognized, an EAC log is in an unsupported language, or
+            ``strict`` is set and a line could not be translated.
+    """
+    ...
+
+def parse_archive(
+    source: str | os.PathLike[str] | _LogContent,
+    *,
+    max_entries: int | None = None,
+    max_entry_size: int | None = None,
+    max_total_size: int | None = None,
+) -> list[ArchiveEntry]:
+    """Parse every log found inside a zip, tar, tar.gz or gzip archive.
+
+    Log candidates are found by their ``.log``/``.log.gz`` extension and by
+    sniffing the content of every other file.
+
+    Args:
+        source: Path to the archive, the archive content as a bytes-like
+            object, or a readable binary file object.
+        max_entries: Maximum number of archive entries (default 10000).
+        max_entry_size: Maximum decompressed size of one log in bytes
+            (default 16 MiB).
+        max_total_size: Maximum total decompressed size in bytes
+            (default 256 MiB).
+
+    Returns:
+        One entry per log candidate, in archive order. Candidates that fail
+        to parse have ``response`` set to None and ``error`` set.
+
+    Raises:
+        OSError: If the archive file cannot be read.
+        ValueError: If the archive is malformed, of an unsupported format,
+            or exceeds one of the limits.
+        TypeError: If source is not a path, bytes-like or file object.
     """
     ...
 
@@ -0,0 +1,299 @@
+// AccurateRip results of a whole disc, from the results of its tracks
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+
+use cambia_core::parser::ParsedLog;
+use cambia_core::track::{
+    AccurateRipConfidenceTotal, AccurateRipOffset, AccurateRipStatus, AccurateRipUnit, TrackEntry,
+};
+
+use pyo3::PyResult;
+
+use crate::range;
+
+fn matched(unit: &AccurateRipUnit) -> bool {
+    matches!(
+        unit.status,
+        AccurateRipStatus::Match | AccurateRipStatus::Offsetted
+    )
+}
+
+/// Whether a matching unit matched at another pressing's offset.
+fn offsetted(unit: &AccurateRipUnit) -> bool {
+    matches!(unit.status, AccurateRipStatus::Offsetted)
+        || unit
+            .confidence
+            .as_ref()
+            .is_some_and(|confidence| matches!(confidence.offset, AccurateRipOffset::Different(_)))
+}
+
+/// The tracks of a log, with the results of a range rip on the TOC tracks
+/// they belong to.
+fn tracks(log: &ParsedLog) -> PyResult<Cow<'_, [TrackEntry]>> {
+    Ok(if log.tracks.iter().any(|track| track.is_range) {
+        Cow::Owned(range::split(log)?.log.tracks)
+    } else {
+        Cow::Borrowed(&log.tracks)
+    })
+}
+
+/// The results of every track, other than those of disabled lookups.
+fn results(tracks: &[TrackEntry]) -> Vec<(u32, Vec<&AccurateRipUnit>)> {
+    tracks
+        .iter()
+        .map(|track| {
+            let units = track
+                .ar_info
+                .iter()
+                .filter(|unit| !matches!(unit.status, AccurateRipStatus::Disabled))
+                .collect();
+            (u32::from(track.num), units)
+        })
+        .collect()
+}
+
+pub struct Summary {
+    /// Tracks with an AccurateRip result, in log order.
+    pub tracks: Vec<u32>,
+    /// Tracks with a match in any AccurateRip version.
+    pub matched: Vec<u32>,
+    /// The lowest confidence of any track, counting an unmatched track as
+    /// 0, or None if no track has a result.
+    pub min_confidence: Option<u32>,
+    /// Tracks with both a v1 and a v2 result where only one of them
+    /// matched.
+    pub version_conflicts: Vec<u32>,
+    /// Tracks that matched only at another pressing's offset.
+    pub different_offset: Vec<u32>,
+    /// The distinct offsets those matches were found at, where logged.
+    pub offsets: Vec<i16>,
+}
+
+impl Summary {
+    pub fn all_matched(&self) -> bool {
+        !self.tracks.is_empty() && self.matched.len() == self.tracks.len()
+    }
+}
+
+/// Summarize the AccurateRip results of the tracks of a log. Units of
+/// disabled lookups are left out, so tracks with no other unit have no
+/// result. A range rip is summed up per TOC track.
+pub fn summary(log: &ParsedLog) -> PyResult<Summary> {
+    let mut summary = Summary {
+        tracks: Vec::new(),
+        matched: Vec::new(),
+        min_confidence: None,
+        version_conflicts: Vec::new(),
+        different_offset: Vec::new(),
+        offsets: Vec::new(),
+    };
+    let mut offsets = BTreeSet::new();
+    let tracks = tracks(log)?;
+    for (num, units) in results(&tracks) {
+        if units.is_empty() {
+            continue;
+        }
+        summary.tracks.push(num);
+
+        let matching: Vec<&&AccurateRipUnit> = units.iter().filter(|unit| matched(unit)).collect();
+        let confidence = matching
+            .iter()
+            .filter_map(|unit| unit.confidence.as_ref()?.matching)
+            .max()
+            .unwrap_or(0);
+        summary.min_confidence = Some(
+            summary
+                .min_confidence
+                .map_or(confidence, |min| min.min(confidence)),
+        );
+        if !matching.is_empty() {
+            summary.matched.push(num);
+            if matching.iter().all(|unit| offsetted(unit)) {
+                summary.different_offset.push(num);
+            }
+        }
+        for unit in &matching {
+            if let Some(AccurateRipOffset::Different(Some(offset))) =
+                unit.confidence.as_ref().map(|confidence| confidence.offset)
+            {
+                offsets.insert(offset);
+            }
+        }
+
+        let version = |version: u8| {
+            let units: Vec<_> = units
+                .iter()
+                .filter(|unit| unit.version == Some(version))
+                .collect();
+            (!units.is_empty()).then(|| units.iter().any(|unit| matched(unit)))
+        };
+        if let (Some(v1), Some(v2)) = (version(1), version(2)) {
+            if v1 != v2 {
+                summary.version_conflicts.push(num);
+            }
+        }
+    }
+    summary.offsets = offsets.into_iter().collect();
+    Ok(summary)
+}
+
+/// What AccurateRip said about a track, taking its best result.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    /// Matched at the offset of the rip.
+    Matched,
+    /// Matched at another pressing's offset, if logged.
+    Offset(Option<i16>),
+    Mismatch,
+    NotFound,
+}
+
+impl Outcome {
+    fn of(units: &[&AccurateRipUnit]) -> Outcome {
+        let matching: Vec<_> = units.iter().filter(|unit| matched(unit)).collect();
+        if matching.iter().any(|unit| !offsetted(unit)) {
+            return Outcome::Matched;
+        }
+        if !matching.is_empty() {
+            return Outcome::Offset(matching.iter().find_map(|unit| {
+                match unit.confidence.as_ref()?.offset {
+                    AccurateRipOffset::Different(offset) => offset,
+                    AccurateRipOffset::Same => None,
+                }
+            }));
+        }
+        if units
+            .iter()
+            .any(|unit| matches!(unit.status, AccurateRipStatus::Mismatch))
+        {
+            Outcome::Mismatch
+        } else {
+            Outcome::NotFound
+        }
+    }
+
+    pub fn offset(&self) -> Option<i16> {
+        match self {
+            Outcome::Offset(offset) => *offset,
+            _ => None,
+        }
+    }
+
+    pub fn matched(&self) -> bool {
+        matches!(self, Outcome::Matched | Outcome::Offset(_))
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Outcome::Matched => "matched".to_string(),
+            Outcome::Offset(Some(offset)) => format!("matched at offset {}", offset),
+            Outcome::Offset(None) => "matched at a different offset".to_string(),
+            Outcome::Mismatch => "did not match".to_string(),
+            Outcome::NotFound => "not in the database".to_string(),
+        }
+    }
+}
+
+/// A track whose result contradicts the majority of the disc.
+pub struct Outlier {
+    pub track: u32,
+    pub outcome: Outcome,
+    pub message: String,
+}
+
+pub struct Consistency {
+    /// Tracks per outcome, the largest group first. Tracks that matched at
+    /// one pressing's offset share a group.
+    pub groups: Vec<(Outcome, Vec<u32>)>,
+    /// Tracks per number of submissions: the highest total of any result,
+    /// or the matching count where no total is logged.
+    pub totals: Vec<(u32, Vec<u32>)>,
+    /// The outcome of more tracks than any other, or None on a tie.
+    pub majority: Option<Outcome>,
+    pub outliers: Vec<Outlier>,
+    /// Tracks without an AccurateRip result.
+    pub unchecked: Vec<u32>,
+}
+
+impl Consistency {
+    /// Whether every track matched, at one pressing's offset.
+    pub fn verified(&self) -> bool {
+        self.unchecked.is_empty()
+            && matches!(self.groups.as_slice(), [(outcome, _)] if outcome.matched())
+    }
+
+    /// Whether every track with a result has the same number of
+    /// submissions, as in `totals`.
+    pub fn totals_agree(&self) -> bool {
+        self.totals.len() <= 1
+    }
+}
+
+/// Check the AccurateRip results of the tracks of a log against each other.
+pub fn consistency(log: &ParsedLog) -> PyResult<Consistency> {
+    let tracks = tracks(log)?;
+    let mut groups: BTreeMap<Outcome, Vec<u32>> = BTreeMap::new();
+    let mut totals: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    let mut outcomes = Vec::new();
+    let mut unchecked = Vec::new();
+    for (num, units) in results(&tracks) {
+        if units.is_empty() {
+            unchecked.push(num);
+            continue;
+        }
+        let outcome = Outcome::of(&units);
+        groups.entry(outcome).or_default().push(num);
+        outcomes.push((num, outcome));
+        let total = units
+            .iter()
+            .filter_map(|unit| {
+                let confidence = unit.confidence.as_ref()?;
+                confidence.total.as_ref().map(total).or(confidence.matching)
+            })
+            .max();
+        if let Some(total) = total {
+            totals.entry(total).or_default().push(num);
+        }
+    }
+
+    let mut groups: Vec<(Outcome, Vec<u32>)> = groups.into_iter().collect();
+    groups.sort_by_key(|(_, tracks)| std::cmp::Reverse(tracks.len()));
+    let majority = match groups.as_slice() {
+        [(outcome, _)] => Some(*outcome),
+        [(outcome, first), (_, second), ..] if first.len() > second.len() => Some(*outcome),
+        _ => None,
+    };
+    let outliers = match majority {
+        Some(majority) => {
+            let count = groups[0].1.len();
+            outcomes
+                .iter()
+                .filter(|(_, outcome)| *outcome != majority)
+                .map(|(track, outcome)| Outlier {
+                    track: *track,
+                    outcome: *outcome,
+                    message: format!(
+                        "{} (majority of {} tracks: {})",
+                        outcome.describe(),
+                        count,
+                        majority.describe()
+                    ),
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    };
+    Ok(Consistency {
+        groups,
+        totals: totals.into_iter().collect(),
+        majority,
+        outliers,
+        unchecked,
+    })
+}
+
+fn total(total: &AccurateRipConfidenceTotal) -> u32 {
+    match total {
+        AccurateRipConfidenceTotal::All(n) | AccurateRipConfidenceTotal::Version(n) => *n,
+    }
+}
@@ -0,0 +1,321 @@
+// Archive traversal for parse_archive and gzip-compressed log input
+use std::io::{Cursor, Read};
+
+use flate2::read::GzDecoder;
+
+/// Limits applied while walking an archive, so that archive bombs are
+/// rejected before they can exhaust memory.
+#[derive(Clone, Copy)]
+pub struct ArchiveLimits {
+    /// Maximum number of entries (files and directories) in the archive.
+    pub max_entries: usize,
+    /// Maximum decompressed size of a single log candidate, in bytes.
+    pub max_entry_size: u64,
+    /// Maximum decompressed size of everything read from the archive, in bytes.
+    pub max_total_size: u64,
+}
+
+impl Default for ArchiveLimits {
+    fn default() -> Self {
+        ArchiveLimits {
+            max_entries: 10_000,
+            max_entry_size: 16 * 1024 * 1024,
+            max_total_size: 256 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ArchiveError {
+    Io(std::io::Error),
+    Format(String),
+    Limit(String),
+}
+
+impl std::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArchiveError::Io(e) => write!(f, "{}", e),
+            ArchiveError::Format(msg) | ArchiveError::Limit(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<std::io::Error> for ArchiveError {
+    fn from(e: std::io::Error) -> Self {
+        ArchiveError::Io(e)
+    }
+}
+
+/// A log candidate found inside an archive.
+pub struct ArchiveMember {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// How many leading bytes of a non-`.log` entry are read for content sniffing.
+const SNIFF_LEN: usize = 4096;
+
+/// Header markers that identify a ripper log regardless of its file name.
+const LOG_MARKERS: &[&str] = &[
+    "Exact Audio Copy V",
+    "EAC extraction logfile",
+    "X Lossless Decoder version",
+    "Log created by: whipper",
+    "Log created by: morituri",
+    "CUERipper v",
+];
+
+pub fn is_gzip(raw: &[u8]) -> bool {
+    raw.starts_with(&[0x1f, 0x8b])
+}
+
+fn is_zip(raw: &[u8]) -> bool {
+    raw.starts_with(b"PK\x03\x04") || raw.starts_with(b"PK\x05\x06")
+}
+
+fn is_tar(raw: &[u8]) -> bool {
+    raw.len() >= 262 && &raw[257..262] == b"ustar"
+}
+
+/// Whether the start of a file looks like a ripper log, in either a
+/// byte-oriented encoding or UTF-16.
+fn looks_like_log(head: &[u8]) -> bool {
+    LOG_MARKERS.iter().any(|marker| {
+        let ascii = marker.as_bytes();
+        let utf16le: Vec<u8> = ascii.iter().flat_map(|&b| [b, 0]).collect();
+        let utf16be: Vec<u8> = ascii.iter().flat_map(|&b| [0, b]).collect();
+        [ascii, &utf16le[..], &utf16be[..]]
+            .iter()
+            .any(|needle| head.windows(needle.len()).any(|w| w == *needle))
+    })
+}
+
+fn has_log_extension(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.ends_with(".log") || lower.ends_with(".log.gz")
+}
+
+/// Read at most `limit` bytes from `reader`, failing if there is more.
+fn read_limited<R: Read>(reader: R, limit: u64, what: &str) -> Result<Vec<u8>, ArchiveError> {
+    let mut data = Vec::new();
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut data)?;
+    if data.len() as u64 > limit {
+        return Err(ArchiveError::Limit(format!(
+            "{} exceeds the size limit of {} bytes",
+            what, limit
+        )));
+    }
+    Ok(data)
+}
+
+/// Decompress a gzip stream, refusing to produce more than `limit` bytes.
+pub fn gunzip(raw: &[u8], limit: u64) -> Result<Vec<u8>, ArchiveError> {
+    read_limited(GzDecoder::new(raw), limit, "Decompressed data")
+}
+
+/// A reader that fails once more than `limit` bytes have been read through
+/// it, so that the total size limit covers data a walk skips over as well as
+/// data it keeps.
+struct Capped<R> {
+    inner: R,
+    left: u64,
+    exceeded: bool,
+}
+
+impl<R: Read> Read for Capped<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        match self.left.checked_sub(n as u64) {
+            Some(left) => {
+                self.left = left;
+                Ok(n)
+            }
+            None => {
+                self.exceeded = true;
+                Err(std::io::Error::other("decompressed size limit exceeded"))
+            }
+        }
+    }
+}
+
+/// Tracks the entry count and total bytes read across an archive walk.
+struct Budget {
+    limits: ArchiveLimits,
+    entries: usize,
+    total: u64,
+}
+
+impl Budget {
+    fn new(limits: ArchiveLimits) -> Self {
+        Budget {
+            limits,
+            entries: 0,
+            total: 0,
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), ArchiveError> {
+        self.entries += 1;
+        if self.entries > self.limits.max_entries {
+            return Err(ArchiveError::Limit(format!(
+                "Archive has more than {} entries",
+                self.limits.max_entries
+            )));
+        }
+        Ok(())
+    }
+
+    fn consume(&mut self, len: usize) -> Result<(), ArchiveError> {
+        self.total = self.total.saturating_add(len as u64);
+        if self.total > self.limits.max_total_size {
+            return Err(ArchiveError::Limit(format!(
+                "Archive exceeds the total size limit of {} bytes",
+                self.limits.max_total_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Read an entry if it is a log candidate, by extension or by content.
+    fn read_candidate<R: Read>(
+        &mut self,
+        path: &str,
+        mut reader: R,
+    ) -> Result<Option<ArchiveMember>, ArchiveError> {
+        let mut data = Vec::new();
+        if !has_log_extension(path) {
+            (&mut reader)
+                .take(SNIFF_LEN as u64)
+                .read_to_end(&mut data)?;
+            self.consume(data.len())?;
+            if !looks_like_log(&data) {
+                return Ok(None);
+            }
+        }
+        let limit = self.limits.max_entry_size.saturating_sub(data.len() as u64);
+        let mut rest = Vec::new();
+        reader
+            .take(limit.saturating_add(1))
+            .read_to_end(&mut rest)?;
+        if rest.len() as u64 > limit {
+            return Err(ArchiveError::Limit(format!(
+                "{} exceeds the size limit of {} bytes",
+                if path.is_empty() { "Log" } else { path },
+                self.limits.max_entry_size
+            )));
+        }
+        self.consume(rest.len())?;
+        data.extend_from_slice(&rest);
+
+        if is_gzip(&data) {
+            data = gunzip(&data, self.limits.max_entry_size)?;
+            self.consume(data.len())?;
+        }
+        Ok(Some(ArchiveMember {
+            path: path.to_string(),
+            data,
+        }))
+    }
+}
+
+fn walk_zip(raw: &[u8], budget: &mut Budget) -> Result<Vec<ArchiveMember>, ArchiveError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(raw))
+        .map_err(|e| ArchiveError::Format(format!("Invalid zip archive: {}", e)))?;
+    if archive.len() > budget.limits.max_entries {
+        return Err(ArchiveError::Limit(format!(
+            "Archive has more than {} entries",
+            budget.limits.max_entries
+        )));
+    }
+
+    let mut members = Vec::new();
+    for i in 0..archive.len() {
+        budget.enter()?;
+        let file = archive
+            .by_index(i)
+            .map_err(|e| ArchiveError::Format(format!("Invalid zip entry: {}", e)))?;
+        if file.is_dir() {
+            continue;
+        }
+        let path = file.name().to_string();
+        if let Some(member) = budget.read_candidate(&path, file)? {
+            members.push(member);
+        }
+    }
+    Ok(members)
+}
+
+fn walk_tar<R: Read>(reader: R, budget: &mut Budget) -> Result<Vec<ArchiveMember>, ArchiveError> {
+    let mut archive = tar::Archive::new(reader);
+    let entries = archive
+        .entries()
+        .map_err(|e| ArchiveError::Format(format!("Invalid tar archive: {}", e)))?;
+
+    let mut members = Vec::new();
+    for entry in entries {
+        budget.enter()?;
+        let entry = entry.map_err(|e| ArchiveError::Format(format!("Invalid tar entry: {}", e)))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().into_owned();
+        if let Some(member) = budget.read_candidate(&path, entry)? {
+            members.push(member);
+        }
+    }
+    Ok(members)
+}
+
+/// Find every log candidate in a zip, tar, tar.gz or single gzip archive.
+///
+/// `name` is used as the member path for a bare gzip stream, which carries
+/// no file name of its own.
+pub fn extract_logs(
+    raw: &[u8],
+    name: &str,
+    limits: ArchiveLimits,
+) -> Result<Vec<ArchiveMember>, ArchiveError> {
+    let mut budget = Budget::new(limits);
+
+    if is_zip(raw) {
+        walk_zip(raw, &mut budget)
+    } else if is_tar(raw) {
+        walk_tar(raw, &mut budget)
+    } else if is_gzip(raw) {
+        // Decompress as the walk reads, rather than into memory up front.
+        let mut stream = Capped {
+            inner: GzDecoder::new(raw),
+            left: limits.max_total_size,
+            exceeded: false,
+        };
+        let mut head = Vec::new();
+        let result = (&mut stream)
+            .take(512)
+            .read_to_end(&mut head)
+            .map_err(ArchiveError::from)
+            .and_then(|_| {
+                let reader = Cursor::new(&head[..]).chain(&mut stream);
+                if is_tar(&head) {
+                    walk_tar(reader, &mut budget)
+                } else {
+                    budget.enter()?;
+                    let path = name.strip_suffix(".gz").unwrap_or(name);
+                    Ok(budget.read_candidate(path, reader)?.into_iter().collect())
+                }
+            });
+        if stream.exceeded {
+            return Err(ArchiveError::Limit(format!(
+                "Archive exceeds the total size limit of {} bytes",
+                limits.max_total_size
+            )));
+        }
+        result
+    } else {
+        Err(ArchiveError::Format(
+            "Unsupported archive format, expected zip, tar, tar.gz or gzip".to_string(),
+        ))
+    }
+}
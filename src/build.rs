@@ -0,0 +1,201 @@
+// Defaults and checks for building cambia-core structs from Python
+use cambia_core::extract::{Gap, MediaType, Quartet, ReadMode, ReleaseInfo, Ripper};
+use cambia_core::integrity::{Checksum, Integrity};
+use cambia_core::parser::ParsedLog;
+use cambia_core::toc::{Toc, TocEntry, TocHash, TocRaw};
+use cambia_core::track::{TrackEntry, TrackError, TrackErrorData};
+use cambia_core::util::Time;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// CD frames, or sectors, per second of audio.
+pub const SECTORS_PER_SECOND: u32 = 75;
+
+/// The highest track number a CD can have.
+pub const MAX_TRACK: u32 = 99;
+
+/// The last sector a CD can address: 99:59:74 less the two-second lead-in.
+pub const MAX_SECTOR: u32 =
+    99 * 60 * SECTORS_PER_SECOND + 59 * SECTORS_PER_SECOND + 74 - 2 * SECTORS_PER_SECOND;
+
+/// Settings of a log ripped the recommended way, for `ParsedLog.secure()`.
+pub const SECURE_QUARTETS: [(&str, Quartet); 10] = [
+    ("accurate_stream", Quartet::True),
+    ("defeat_audio_cache", Quartet::True),
+    ("use_c2", Quartet::False),
+    ("overread", Quartet::False),
+    ("fill_silence", Quartet::True),
+    ("delete_silence", Quartet::False),
+    ("use_null_samples", Quartet::True),
+    ("test_and_copy", Quartet::True),
+    ("normalize", Quartet::False),
+    ("id3_enabled", Quartet::False),
+];
+
+pub fn error(class: &str, message: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("{}: {}", class, message))
+}
+
+/// The reverse of `time_to_duration`: Time deserializes from f64 seconds.
+pub fn time(duration: std::time::Duration) -> PyResult<Time> {
+    serde_json::from_value(serde_json::Value::from(duration.as_secs_f64())).map_err(|err| {
+        PyValueError::new_err(format!("Cannot convert {:?} to a time: {}", duration, err))
+    })
+}
+
+pub fn sectors_to_time(sectors: u32) -> PyResult<Time> {
+    time(std::time::Duration::from_secs_f64(
+        f64::from(sectors) / f64::from(SECTORS_PER_SECOND),
+    ))
+}
+
+/// Match when both hashes are known and equal, Unknown when either is
+/// missing.
+pub fn integrity(first: &str, second: &str) -> Integrity {
+    if first.is_empty() || second.is_empty() {
+        Integrity::Unknown
+    } else if first.eq_ignore_ascii_case(second) {
+        Integrity::Match
+    } else {
+        Integrity::Mismatch
+    }
+}
+
+pub fn empty_hash() -> TocHash {
+    TocHash {
+        hash: String::new(),
+        url: String::new(),
+    }
+}
+
+pub fn empty_toc() -> Toc {
+    Toc {
+        raw: TocRaw {
+            entries: Vec::new(),
+        },
+        freedb: empty_hash(),
+        accurip_tocid: empty_hash(),
+        ctdb_tocid: empty_hash(),
+        mbz: empty_hash(),
+        gn: empty_hash(),
+        mcdi: empty_hash(),
+    }
+}
+
+pub fn no_errors() -> TrackError {
+    let none = || TrackErrorData {
+        count: 0,
+        ranges: Vec::new(),
+    };
+    TrackError {
+        read: none(),
+        skip: none(),
+        jitter_generic: none(),
+        jitter_edge: none(),
+        jitter_atom: none(),
+        drift: none(),
+        dropped: none(),
+        duplicated: none(),
+        damaged_sectors: none(),
+        inconsistent_err_sectors: none(),
+        missing_samples: none(),
+    }
+}
+
+pub fn check_track(class: &str, num: u32) -> PyResult<()> {
+    if num > MAX_TRACK {
+        return Err(error(
+            class,
+            format!("track number {} is above {}", num, MAX_TRACK),
+        ));
+    }
+    Ok(())
+}
+
+/// Entries must be in track order without overlapping sectors.
+pub fn check_toc(entries: &[TocEntry]) -> PyResult<()> {
+    for pair in entries.windows(2) {
+        if pair[1].track <= pair[0].track {
+            return Err(error(
+                "TocRaw",
+                format!(
+                    "track {} follows track {}; entries must be in track order",
+                    pair[1].track, pair[0].track
+                ),
+            ));
+        }
+        if pair[1].start_sector <= pair[0].end_sector {
+            return Err(error(
+                "TocRaw",
+                format!(
+                    "track {} starts at sector {}, inside track {}",
+                    pair[1].track, pair[1].start_sector, pair[0].track
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_tracks(tracks: &[TrackEntry]) -> PyResult<()> {
+    for (index, track) in tracks.iter().enumerate() {
+        if tracks[..index].iter().any(|other| other.num == track.num) {
+            return Err(error(
+                "ParsedLog",
+                format!("track {} is listed twice", track.num),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A log with nothing known about it yet: every setting is Unknown, as if
+/// the log did not mention it.
+pub fn log(ripper: Ripper) -> ParsedLog {
+    ParsedLog {
+        ripper,
+        ripper_version: String::new(),
+        release_info: ReleaseInfo {
+            artist: String::new(),
+            title: String::new(),
+        },
+        language: "English".to_string(),
+        read_offset: None,
+        combined_rw_offset: None,
+        drive: String::new(),
+        media_type: MediaType::Unknown,
+        accurate_stream: Quartet::Unknown,
+        defeat_audio_cache: Quartet::Unknown,
+        use_c2: Quartet::Unknown,
+        overread: Quartet::Unknown,
+        fill_silence: Quartet::Unknown,
+        delete_silence: Quartet::Unknown,
+        use_null_samples: Quartet::Unknown,
+        test_and_copy: Quartet::Unknown,
+        normalize: Quartet::Unknown,
+        read_mode: ReadMode::Unknown,
+        gap_handling: Gap::Unknown,
+        checksum: Checksum {
+            calculated: String::new(),
+            log: String::new(),
+            integrity: Integrity::Unknown,
+        },
+        toc: empty_toc(),
+        tracks: Vec::new(),
+        id3_enabled: Quartet::Unknown,
+        audio_encoder: Vec::new(),
+    }
+}
+
+/// Apply the recommended settings from `SECURE_QUARTETS`, secure read mode,
+/// appended gaps and a pressed disc.
+pub fn secure(log: &mut ParsedLog) {
+    for (key, value) in SECURE_QUARTETS {
+        if let Some(field) = crate::replace::quartet_field(log, key) {
+            *field = value;
+        }
+    }
+    log.read_mode = ReadMode::Secure;
+    log.gap_handling = Gap::Append;
+    log.media_type = MediaType::Pressed;
+}
@@ -0,0 +1,252 @@
+// Which TOC tracks a log covers, and whether its track data fits the TOC
+use std::collections::BTreeMap;
+
+use cambia_core::parser::ParsedLog;
+use cambia_core::toc::TocEntry;
+use pyo3::prelude::*;
+
+use crate::build::SECTORS_PER_SECOND;
+use crate::error_kind;
+use crate::layout;
+use crate::position;
+
+/// The pre-gap of track one also counts the two seconds before sector 0.
+pub const LEAD_IN_PREGAP: u32 = 2 * SECTORS_PER_SECOND;
+
+/// Shorter stretches before track one are silence on many pressings (33
+/// sectors is common) rather than hidden audio.
+pub const HTOA_MIN_SECTORS: u32 = SECTORS_PER_SECOND;
+
+/// Something in the log that does not add up with its TOC.
+pub struct Issue {
+    pub track: u32,
+    pub message: String,
+}
+
+pub struct Coverage {
+    /// Tracks with at least one entry that was not aborted, including TOC
+    /// tracks covered by a range.
+    pub ripped: Vec<u32>,
+    /// Tracks whose every entry was aborted.
+    pub aborted: Vec<u32>,
+    /// Audio tracks of the TOC with no entry.
+    pub missing: Vec<u32>,
+    /// Tracks with more than one entry.
+    pub duplicated: Vec<u32>,
+    /// Tracks with an entry but no TOC line, other than a hidden track 0.
+    pub not_in_toc: Vec<u32>,
+    pub data_tracks: Vec<u32>,
+    pub range_rip: bool,
+    /// Sectors before track one, if track one is in the TOC.
+    pub htoa_sectors: u32,
+    /// Whether the hidden track was ripped. Unknown for range rips, since
+    /// the parsed log keeps no range sectors to tell whether the range
+    /// started before track one.
+    pub htoa_extracted: Option<bool>,
+    pub issues: Vec<Issue>,
+}
+
+/// Sectors before track one, or 0 if track one is not in the TOC.
+pub fn htoa_sectors(entries: &[TocEntry]) -> u32 {
+    entries
+        .iter()
+        .find(|entry| entry.track == 1)
+        .map_or(0, |entry| entry.start_sector)
+}
+
+fn length_sectors(entry: &TocEntry) -> u32 {
+    position::span(entry.start_sector, entry.end_sector)
+}
+
+/// Check the TOC against itself and against the pre-gaps and error ranges
+/// of the tracks.
+fn issues(log: &ParsedLog, data: &[u32]) -> PyResult<Vec<Issue>> {
+    let entries = &log.toc.raw.entries;
+    let mut issues = Vec::new();
+    let mut issue = |track: u32, message: String| issues.push(Issue { track, message });
+
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.end_sector < entry.start_sector {
+            issue(
+                entry.track,
+                format!(
+                    "ends at sector {} before it starts at sector {}",
+                    entry.end_sector, entry.start_sector
+                ),
+            );
+            continue;
+        }
+        let length = position::sectors(&entry.length)?;
+        if length != length_sectors(entry) {
+            issue(
+                entry.track,
+                format!(
+                    "TOC length {} does not match sectors {}-{}",
+                    position::format_msf(length),
+                    entry.start_sector,
+                    entry.end_sector
+                ),
+            );
+        }
+        let start = position::sectors(&entry.start)?;
+        if start != entry.start_sector {
+            issue(
+                entry.track,
+                format!(
+                    "TOC start {} does not match start sector {}",
+                    position::format_msf(start),
+                    entry.start_sector
+                ),
+            );
+        }
+        let Some(previous) = index.checked_sub(1).map(|index| &entries[index]) else {
+            continue;
+        };
+        // Session gaps and the post-gaps of data tracks are not in the TOC.
+        if entry.start_sector != previous.end_sector.saturating_add(1)
+            && !data.contains(&entry.track)
+            && !data.contains(&previous.track)
+        {
+            issue(
+                entry.track,
+                format!(
+                    "starts at sector {} but track {} ends at sector {}",
+                    entry.start_sector, previous.track, previous.end_sector
+                ),
+            );
+        }
+    }
+
+    for track in log.tracks.iter().filter(|track| !track.is_range) {
+        let num = u32::from(track.num);
+        let Some(index) = entries.iter().position(|entry| entry.track == num) else {
+            continue;
+        };
+        let entry = &entries[index];
+        if let Some(pregap) = &track.pregap_length {
+            let pregap = position::sectors(pregap)?;
+            if index == 0 && num == 1 {
+                let Some(expected) = entry.start_sector.checked_add(LEAD_IN_PREGAP) else {
+                    issue(
+                        num,
+                        format!(
+                            "starts at sector {}, past the end of a disc",
+                            entry.start_sector
+                        ),
+                    );
+                    continue;
+                };
+                if pregap != expected {
+                    issue(
+                        num,
+                        format!(
+                            "pre-gap {} does not match a start at sector {} (expected {})",
+                            position::format_msf(pregap),
+                            entry.start_sector,
+                            position::format_msf(expected)
+                        ),
+                    );
+                }
+            } else if let Some(previous) = index.checked_sub(1).map(|index| &entries[index]) {
+                // The pre-gap is the end of the previous track in the TOC.
+                if pregap > length_sectors(previous) {
+                    issue(
+                        num,
+                        format!(
+                            "pre-gap {} is longer than track {}",
+                            position::format_msf(pregap),
+                            previous.track
+                        ),
+                    );
+                }
+            }
+        }
+        let length = length_sectors(entry);
+        for (kind, data) in error_kind::each(&track.errors) {
+            for range in &data.ranges {
+                let (start, sectors) = position::range_sectors(range)?;
+                if start.saturating_add(sectors) > length {
+                    issue(
+                        num,
+                        format!(
+                            "{} error at {} runs past the end of the track ({} sectors)",
+                            kind.key(),
+                            position::format_msf(start),
+                            length
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    Ok(issues)
+}
+
+pub fn coverage(log: &ParsedLog) -> PyResult<Coverage> {
+    let entries = &log.toc.raw.entries;
+    let data = layout::data_tracks(entries);
+
+    // Entries per track number, and whether any of them completed.
+    let mut logged: BTreeMap<u32, (usize, bool)> = BTreeMap::new();
+    for track in log.tracks.iter().filter(|track| !track.is_range) {
+        let (count, completed) = logged.entry(u32::from(track.num)).or_default();
+        *count += 1;
+        *completed |= !track.aborted;
+    }
+    let ranges: Vec<_> = log.tracks.iter().filter(|track| track.is_range).collect();
+    let range_completed = ranges.iter().any(|track| !track.aborted);
+
+    let mut ripped = Vec::new();
+    let mut aborted = Vec::new();
+    for (num, (_, completed)) in &logged {
+        if *completed {
+            ripped.push(*num);
+        } else {
+            aborted.push(*num);
+        }
+    }
+    let mut missing = Vec::new();
+    for entry in entries {
+        if logged.contains_key(&entry.track) || data.contains(&entry.track) {
+            continue;
+        }
+        if ranges.is_empty() {
+            missing.push(entry.track);
+        } else if range_completed {
+            ripped.push(entry.track);
+        } else {
+            aborted.push(entry.track);
+        }
+    }
+    ripped.sort_unstable();
+    aborted.sort_unstable();
+
+    let htoa_extracted = if logged.get(&0).is_some_and(|(_, completed)| *completed) {
+        Some(true)
+    } else if range_completed {
+        None
+    } else {
+        Some(false)
+    };
+
+    Ok(Coverage {
+        ripped,
+        aborted,
+        missing,
+        duplicated: logged
+            .iter()
+            .filter(|(_, (count, _))| *count > 1)
+            .map(|(num, _)| *num)
+            .collect(),
+        not_in_toc: logged
+            .keys()
+            .filter(|num| **num != 0 && !entries.iter().any(|entry| entry.track == **num))
+            .copied()
+            .collect(),
+        range_rip: !ranges.is_empty(),
+        htoa_sectors: htoa_sectors(entries),
+        htoa_extracted,
+        issues: issues(log, &data)?,
+        data_tracks: data,
+    })
+}
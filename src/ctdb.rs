@@ -0,0 +1,240 @@
+// CUETools DB results, read from the text of a log
+use encoding_rs::Encoding;
+
+use crate::encoding;
+use crate::sniff::{self, SubLog};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Status {
+    /// "Accurately ripped".
+    Verified,
+    /// "Differs in N samples": the database has the disc and CUETools can
+    /// repair the rip from it.
+    Differs,
+    NoMatch,
+    /// "disk not present in database".
+    NotFound,
+    /// The disc was found but the log has no result for it.
+    Unknown,
+}
+
+#[derive(Clone)]
+pub struct Finding {
+    pub status: Status,
+    /// Submissions that match the rip, and all submissions for the disc.
+    pub confidence: Option<u32>,
+    pub total: Option<u32>,
+    /// Samples that differ, for `Status::Differs`.
+    pub differing_samples: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct Track {
+    pub track: u32,
+    pub result: Finding,
+}
+
+#[derive(Clone)]
+pub struct Ctdb {
+    pub tocid: Option<String>,
+    /// The result for the disc: the logged one, or else one made up from
+    /// the results of the tracks.
+    pub result: Finding,
+    pub tracks: Vec<Track>,
+}
+
+impl Ctdb {
+    pub fn verified(&self) -> bool {
+        matches!(self.result.status, Status::Verified)
+    }
+}
+
+/// A result such as "(16/16) Accurately ripped", "[55e84e4d] (1/1)
+/// Accurately ripped", "(0/3) No match" or "disk not present in database".
+fn result(text: &str) -> Option<Finding> {
+    let mut text = text.trim();
+    if let Some(rest) = text.strip_prefix('[') {
+        text = rest.split_once(']')?.1.trim();
+    }
+    let counts = |text: &str| -> Option<(u32, u32)> {
+        let (confidence, total) = text.split_once('/')?;
+        Some((confidence.trim().parse().ok()?, total.trim().parse().ok()?))
+    };
+    let mut confidence = None;
+    let mut total = None;
+    if let Some(rest) = text.strip_prefix('(') {
+        if let Some((inner, rest)) = rest.split_once(')') {
+            if let Some((n, m)) = counts(inner) {
+                confidence = Some(n);
+                total = Some(m);
+                text = rest.trim();
+            }
+        }
+    }
+    let lower = text.to_lowercase();
+    let mut differing_samples = None;
+    let status = if lower.starts_with("accurately ripped") {
+        Status::Verified
+    } else if let Some(rest) = lower.strip_prefix("differs in ") {
+        differing_samples = rest.split_whitespace().next().and_then(|n| n.parse().ok());
+        Status::Differs
+    } else if lower.starts_with("no match") {
+        Status::NoMatch
+    } else if lower.starts_with("disk not present") {
+        Status::NotFound
+    } else {
+        return None;
+    };
+    Some(Finding {
+        status,
+        confidence,
+        total,
+        differing_samples,
+    })
+}
+
+/// A track row of the "Track | CTDB Status" table, "  1   | (16/16)
+/// Accurately ripped", or of XLD's CTDB summary, "Track 01 : (16/16)
+/// Accurately ripped".
+fn track(line: &str) -> Option<Track> {
+    let (num, text) = match line.strip_prefix("Track ") {
+        Some(rest) => rest.split_once(':')?,
+        None => line.split_once('|')?,
+    };
+    Some(Track {
+        track: num.trim().parse().ok()?,
+        result: result(text)?,
+    })
+}
+
+/// The disc result made up from the results of the tracks: verified if
+/// every track is, with the lowest confidence.
+fn from_tracks(tracks: &[Track]) -> Finding {
+    let status = [Status::NoMatch, Status::Differs, Status::Verified]
+        .into_iter()
+        .find(|status| tracks.iter().any(|track| track.result.status == *status))
+        .unwrap_or(Status::Unknown);
+    Finding {
+        status,
+        confidence: tracks
+            .iter()
+            .filter_map(|track| track.result.confidence)
+            .min(),
+        total: tracks.iter().filter_map(|track| track.result.total).max(),
+        differing_samples: tracks
+            .iter()
+            .filter_map(|track| track.result.differing_samples)
+            .reduce(u32::saturating_add),
+    }
+}
+
+/// Whether a line starts the CTDB section: the header of EAC's CTDB plugin,
+/// the TOCID line that CUERipper starts its CUETools report with, or the
+/// "CTDB Summary (TOCID: ...)" header of XLD.
+fn section_start(line: &str) -> bool {
+    line.starts_with("---- CUETools DB Plugin")
+        || line.starts_with("[CTDB TOCID:")
+        || line.starts_with("CTDB Summary")
+}
+
+/// Whether a line belongs to the CTDB section: a TOCID line, a database
+/// entry, the submission result, a row of the track table or a result of
+/// XLD's summary. CUERipper goes on with "[AccurateRip ID: ...]", which ends
+/// it.
+fn section_line(line: &str) -> bool {
+    (line.starts_with('[') && !line.starts_with("[AccurateRip"))
+        || line.starts_with("Submit result")
+        || line.contains('|')
+        || track(line).is_some()
+        || result(line).is_some()
+}
+
+/// Read the CTDB section of one log, or None if it has none.
+///
+/// XLD's summary is read in the layout of its AccurateRip summary, with a
+/// "Track NN : " row per track and the disc result made up from them. No
+/// log under `tests/logs` has one, so that layout is not checked against a
+/// real XLD log.
+pub fn read_log(text: &str) -> Option<Ctdb> {
+    let mut in_section = false;
+    let mut found = false;
+    let mut tocid = None;
+    let mut disc: Vec<Finding> = Vec::new();
+    let mut tracks = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if section_start(trimmed) {
+            in_section = true;
+            found = true;
+        } else if !in_section {
+            continue;
+        } else if !section_line(trimmed) {
+            // A checksum or the next section of the log.
+            in_section = false;
+            continue;
+        }
+
+        // "[CTDB TOCID: <id>] found" or "... TOCID: <id>)"
+        if let Some(index) = trimmed.find("TOCID:") {
+            let rest = trimmed[index + "TOCID:".len()..].trim_start();
+            let id: String = rest
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+                .collect();
+            if !id.is_empty() {
+                tocid.get_or_insert(id);
+            }
+            let after = rest.split_once(']').map_or("", |(_, after)| after);
+            let status = after.split(", Submit result").next().unwrap_or("");
+            if let Some(result) = result(status) {
+                disc.push(result);
+            }
+            continue;
+        }
+        if let Some(track) = track(trimmed) {
+            tracks.push(track);
+        } else if let Some(result) = result(trimmed) {
+            disc.push(result);
+        }
+    }
+    if !found {
+        return None;
+    }
+    // Of several database entries, the one with the most matches counts.
+    let result = disc
+        .into_iter()
+        .max_by_key(|result| {
+            (
+                matches!(result.status, Status::Verified),
+                result.confidence.unwrap_or(0),
+            )
+        })
+        .unwrap_or_else(|| from_tracks(&tracks));
+    Some(Ctdb {
+        tocid,
+        result,
+        tracks,
+    })
+}
+
+/// Read the CTDB results of every log in a file, in order, decoding with
+/// the given encoding or a detected one.
+pub fn read(raw: &[u8], forced_encoding: Option<&'static Encoding>) -> Vec<Option<Ctdb>> {
+    let (text, _) = encoding::decode_as(raw, forced_encoding);
+    sniff::split(&text)
+        .iter()
+        .map(|log| read_log(log.text))
+        .collect()
+}
+
+/// Read the CTDB results of each parsed log from the sub-log
+/// `sniff::sources` matched it to.
+pub fn for_sources(logs: &[SubLog], sources: &[Option<usize>]) -> Vec<Option<Ctdb>> {
+    sources
+        .iter()
+        .map(|source| source.and_then(|index| read_log(logs[index].text)))
+        .collect()
+}
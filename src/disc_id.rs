@@ -0,0 +1,180 @@
+// Disc IDs computed from a TOC, with data tracks handled as each service does
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use cambia_core::parser::ParsedLogCombined;
+use cambia_core::toc::{Toc, TocEntry, TocHash};
+
+use crate::build::SECTORS_PER_SECOND;
+use crate::layout::{self, Layout, SESSION_GAP};
+
+/// Sector addresses in the TOC of a log start at 0; MSF positions on the
+/// disc start two seconds earlier.
+const MSF_OFFSET: u32 = 2 * SECTORS_PER_SECOND;
+
+pub struct DiscIds {
+    pub freedb: TocHash,
+    pub accurip_tocid: TocHash,
+    pub ctdb_tocid: TocHash,
+    pub mbz: TocHash,
+}
+
+fn hash(hash: String, url: String) -> TocHash {
+    TocHash { hash, url }
+}
+
+/// The MSF position of a sector address, or None past the end of a disc.
+fn msf(sector: u32) -> Option<u32> {
+    sector.checked_add(MSF_OFFSET)
+}
+
+/// SHA-1 in the base64 alphabet MusicBrainz and CTDB use in URLs.
+fn url_safe_sha1(text: &str) -> String {
+    STANDARD
+        .encode(sha1_smol::Sha1::from(text).digest().bytes())
+        .replace('+', ".")
+        .replace('/', "_")
+        .replace('=', "-")
+}
+
+/// The freedb ID covers every track, data tracks included, up to the
+/// lead-out of the disc.
+fn freedb(entries: &[TocEntry], leadout: u32) -> Option<u32> {
+    let digits = |mut seconds: u32| {
+        let mut sum = 0;
+        while seconds > 0 {
+            sum += seconds % 10;
+            seconds /= 10;
+        }
+        sum
+    };
+    let mut checksum: u32 = 0;
+    for entry in entries {
+        checksum += digits(msf(entry.start_sector)? / SECTORS_PER_SECOND);
+    }
+    let seconds = (msf(leadout)? / SECTORS_PER_SECOND)
+        .checked_sub(msf(entries[0].start_sector)? / SECTORS_PER_SECOND)?;
+    Some(((checksum % 255) << 24) | (seconds << 8) | entries.len() as u32)
+}
+
+/// AccurateRip leaves data tracks out but keeps the lead-out of the whole
+/// disc, so the data track of an Enhanced CD still moves the ID.
+fn accurip(audio: &[&TocEntry], leadout: u32, freedb: u32) -> TocHash {
+    let mut id1: u32 = leadout;
+    let mut id2: u32 = leadout.max(1).wrapping_mul(audio.len() as u32 + 1);
+    for entry in audio {
+        id1 = id1.wrapping_add(entry.start_sector);
+        id2 = id2.wrapping_add(entry.start_sector.max(1).wrapping_mul(entry.track));
+    }
+    hash(
+        format!("{:08x}-{:08x}-{:08x}", id1, id2, freedb),
+        format!(
+            "http://www.accuraterip.com/accuraterip/{:x}/{:x}/{:x}/dBAR-{:03}-{:08x}-{:08x}-{:08x}.bin",
+            id1 & 0xF,
+            (id1 >> 4) & 0xF,
+            (id1 >> 8) & 0xF,
+            audio.len(),
+            id1,
+            id2,
+            freedb
+        ),
+    )
+}
+
+/// CTDB hashes the audio tracks only, relative to the first of them.
+fn ctdb(audio: &[&TocEntry]) -> Option<TocHash> {
+    let first = audio[0].start_sector;
+    let mut text = String::new();
+    for entry in &audio[1..] {
+        text.push_str(&format!("{:08X}", entry.start_sector.checked_sub(first)?));
+    }
+    let end = audio[audio.len() - 1].end_sector.checked_add(1)?;
+    text.push_str(&format!("{:08X}", end.checked_sub(first)?));
+    text.push_str(&"0".repeat(100usize.saturating_sub(audio.len()) * 8));
+    Some(hash(url_safe_sha1(&text), String::new()))
+}
+
+/// MusicBrainz covers the first session: the tracks before the data track
+/// of an Enhanced CD, with the session's own lead-out.
+fn mbz(entries: &[TocEntry], leadout: u32) -> Option<TocHash> {
+    let (session, leadout) = match layout::layout(entries) {
+        Layout::Enhanced => {
+            let data = &entries[entries.len() - 1];
+            (
+                &entries[..entries.len() - 1],
+                data.start_sector.checked_sub(SESSION_GAP)?,
+            )
+        }
+        _ => (entries, leadout),
+    };
+    let leadout = msf(leadout)?;
+    let first = session[0].track;
+    let last = session[session.len() - 1].track;
+    let mut text = format!("{:02X}{:02X}{:08X}", first, last, leadout);
+    for track in 1..=99 {
+        let offset = match session.iter().find(|entry| entry.track == track) {
+            Some(entry) => msf(entry.start_sector)?,
+            None => 0,
+        };
+        text.push_str(&format!("{:08X}", offset));
+    }
+    let id = url_safe_sha1(&text);
+    let mut offsets = Vec::new();
+    for entry in session {
+        offsets.push(msf(entry.start_sector)?.to_string());
+    }
+    let url = format!(
+        "https://musicbrainz.org/cdtoc/attach?toc={}+{}+{}+{}&tracks={}&id={}",
+        first,
+        last,
+        leadout,
+        offsets.join("+"),
+        session.len(),
+        id
+    );
+    Some(hash(id, url))
+}
+
+/// Compute the disc IDs of a TOC, or None if it has no audio tracks or its
+/// sectors are out of order or beyond what a disc can hold.
+pub fn compute(entries: &[TocEntry]) -> Option<DiscIds> {
+    let data = layout::data_tracks(entries);
+    let audio: Vec<&TocEntry> = entries
+        .iter()
+        .filter(|entry| !data.contains(&entry.track))
+        .collect();
+    if audio.is_empty() {
+        return None;
+    }
+    let leadout = entries[entries.len() - 1].end_sector.checked_add(1)?;
+    let freedb = freedb(entries, leadout)?;
+    Some(DiscIds {
+        accurip_tocid: accurip(&audio, leadout, freedb),
+        ctdb_tocid: ctdb(&audio)?,
+        mbz: mbz(entries, leadout)?,
+        freedb: hash(format!("{:08x}", freedb), String::new()),
+    })
+}
+
+impl DiscIds {
+    pub fn apply(self, toc: &mut Toc) {
+        toc.freedb = self.freedb;
+        toc.accurip_tocid = self.accurip_tocid;
+        toc.ctdb_tocid = self.ctdb_tocid;
+        toc.mbz = self.mbz;
+    }
+}
+
+/// Replace the disc IDs of parsed logs of Enhanced and mixed-mode CDs with
+/// ones computed around their data track. The IDs rippers log, and that
+/// cambia-core passes on, hash the data track as if it were audio; those
+/// of plain discs are kept.
+pub fn correct(parsed: &mut ParsedLogCombined) {
+    for log in &mut parsed.parsed_logs {
+        if layout::layout(&log.toc.raw.entries) == Layout::Plain {
+            continue;
+        }
+        if let Some(ids) = compute(&log.toc.raw.entries) {
+            ids.apply(&mut log.toc);
+        }
+    }
+}
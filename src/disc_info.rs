@@ -0,0 +1,270 @@
+// ISRCs, the media catalog number and CD-Text, read from the text of a log
+use cambia_core::extract::Ripper;
+
+use crate::encoding;
+use crate::sniff::{self, LogHeader, SubLog};
+
+/// Labels of the disc's media catalog number, lowercased. Release metadata
+/// such as a label's catalog number or a barcode looked up online is not
+/// read from the disc and is left out.
+const MCN_LABELS: &[&str] = &["mcn", "media catalog number", "media catalogue number"];
+
+/// CD-Text labels, lowercased. Bare "Title" and "Artist" lines are release
+/// metadata, as in whipper's "Release:" block, not CD-Text.
+const TITLE_LABELS: &[&str] = &["cd-text title"];
+const PERFORMER_LABELS: &[&str] = &["cd-text performer", "cd-text artist"];
+
+/// An International Standard Recording Code: a country code, a registrant
+/// code, the year of reference and a designation code, "CCXXXYYNNNNN".
+/// ISRCs have no check digit, so validity is a matter of format.
+#[derive(Clone)]
+pub struct Isrc {
+    /// The code with hyphens and spaces removed, uppercased.
+    pub code: String,
+}
+
+impl Isrc {
+    pub fn new(code: &str) -> Self {
+        Isrc {
+            code: code
+                .chars()
+                .filter(|c| *c != '-' && !c.is_whitespace())
+                .collect::<String>()
+                .to_ascii_uppercase(),
+        }
+    }
+
+    pub fn valid(&self) -> bool {
+        let bytes = self.code.as_bytes();
+        bytes.len() == 12
+            && bytes[..2].iter().all(u8::is_ascii_uppercase)
+            && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+            && bytes[5..].iter().all(u8::is_ascii_digit)
+    }
+
+    /// Country, registrant, year and designation codes, if the code is valid.
+    pub fn parts(&self) -> Option<[&str; 4]> {
+        self.valid().then(|| {
+            [
+                &self.code[..2],
+                &self.code[2..5],
+                &self.code[5..7],
+                &self.code[7..],
+            ]
+        })
+    }
+}
+
+/// A media catalog number: the EAN-13 of the disc, of which a UPC-A is the
+/// 12 digits after a leading zero.
+#[derive(Clone)]
+pub struct Mcn {
+    /// The digits of the code as logged, without spaces or hyphens.
+    pub code: String,
+}
+
+impl Mcn {
+    pub fn new(code: &str) -> Self {
+        Mcn {
+            code: code
+                .chars()
+                .filter(|c| *c != '-' && !c.is_whitespace())
+                .collect(),
+        }
+    }
+
+    /// The code as an EAN-13, with a UPC-A padded by a leading zero.
+    pub fn ean(&self) -> Option<String> {
+        if !self.code.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        match self.code.len() {
+            13 => Some(self.code.clone()),
+            12 => Some(format!("0{}", self.code)),
+            _ => None,
+        }
+    }
+
+    pub fn upc(&self) -> Option<String> {
+        self.ean()
+            .and_then(|ean| ean.strip_prefix('0').map(str::to_string))
+    }
+
+    /// Whether the check digit matches. Drives return an all-zero code for
+    /// discs without a catalog number, so that code is not valid.
+    pub fn valid(&self) -> bool {
+        let Some(ean) = self.ean() else {
+            return false;
+        };
+        let digits: Vec<u32> = ean.bytes().map(|b| u32::from(b - b'0')).collect();
+        if digits.iter().all(|digit| *digit == 0) {
+            return false;
+        }
+        // Weights alternate 1 and 3 from the left of the 12 data digits.
+        let sum: u32 = digits[..12]
+            .iter()
+            .enumerate()
+            .map(|(index, digit)| if index % 2 == 0 { *digit } else { digit * 3 })
+            .sum();
+        (10 - sum % 10) % 10 == digits[12]
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct CdText {
+    pub performer: Option<String>,
+    pub title: Option<String>,
+}
+
+impl CdText {
+    fn set(&mut self, label: &str, value: &str) {
+        if TITLE_LABELS.contains(&label) {
+            self.title.get_or_insert_with(|| value.to_string());
+        } else if PERFORMER_LABELS.contains(&label) {
+            self.performer.get_or_insert_with(|| value.to_string());
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TrackInfo {
+    pub track: u8,
+    pub isrc: Option<Isrc>,
+    pub cd_text: CdText,
+}
+
+/// What one log says about the identity of its disc.
+#[derive(Clone)]
+pub struct DiscInfo {
+    pub ripper: Ripper,
+    /// None if the log has no catalog number or only the all-zero one.
+    pub mcn: Option<Mcn>,
+    pub cd_text: CdText,
+    pub tracks: Vec<TrackInfo>,
+}
+
+/// Label and value of a "Label : value" line, or of "ISRC <code>" where a
+/// ripper logs the code without a colon.
+fn field(line: &str) -> Option<(String, &str)> {
+    let (label, value) = line
+        .split_once(':')
+        .or_else(|| line.split_once(char::is_whitespace))?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| (label.trim().to_lowercase(), value))
+}
+
+/// The number of a track section header: "Track  1" in EAC, in any
+/// language, and "Track 01" in XLD, both unindented.
+fn track_header(line: &str) -> Option<u8> {
+    if line.starts_with(char::is_whitespace) || line.contains(':') {
+        return None;
+    }
+    let mut words = line.split_whitespace();
+    let (Some(word), Some(num), None) = (words.next(), words.next(), words.next()) else {
+        return None;
+    };
+    if !word.chars().all(char::is_alphabetic) {
+        return None;
+    }
+    num.parse().ok()
+}
+
+/// The number of a whipper track, "  1:" under "Tracks:".
+fn whipper_track(line: &str) -> Option<u8> {
+    let indent = line.len() - line.trim_start().len();
+    (indent == 2)
+        .then(|| line.trim().strip_suffix(':'))
+        .flatten()
+        .and_then(|num| num.parse().ok())
+}
+
+fn read(header: &LogHeader, text: &str) -> DiscInfo {
+    let mut info = DiscInfo {
+        ripper: header.ripper.clone(),
+        mcn: None,
+        cd_text: CdText::default(),
+        tracks: Vec::new(),
+    };
+    let whipper = matches!(header.ripper, Ripper::Whipper | Ripper::Morituri);
+    // Whether the line is inside whipper's "Tracks:" block, and inside the
+    // section of the last track.
+    let mut in_tracks = false;
+    let mut in_track = false;
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect();
+    for (index, line) in lines.iter().copied().enumerate() {
+        let indented = line.starts_with(char::is_whitespace);
+        let track = if whipper {
+            if !indented {
+                in_tracks = line == "Tracks:";
+                in_track = false;
+            }
+            in_tracks.then(|| whipper_track(line)).flatten()
+        } else {
+            // Track sections are indented; the footer after them is not.
+            if !indented {
+                in_track = false;
+            }
+            // A release line like "Maroon 5" is not followed by an indented
+            // track section.
+            track_header(line).filter(|_| {
+                lines
+                    .get(index + 1)
+                    .is_some_and(|next| next.starts_with(char::is_whitespace))
+            })
+        };
+        if let Some(track) = track {
+            info.tracks.push(TrackInfo {
+                track,
+                isrc: None,
+                cd_text: CdText::default(),
+            });
+            in_track = true;
+            continue;
+        }
+        let Some((label, value)) = field(line.trim_start()) else {
+            continue;
+        };
+        match info.tracks.last_mut().filter(|_| in_track) {
+            Some(track) => {
+                if label == "isrc" {
+                    track.isrc.get_or_insert_with(|| Isrc::new(value));
+                } else {
+                    track.cd_text.set(&label, value);
+                }
+            }
+            None => {
+                if MCN_LABELS.contains(&label.as_str()) {
+                    let mcn = Mcn::new(value);
+                    if info.mcn.is_none() && !mcn.code.bytes().all(|b| b == b'0') {
+                        info.mcn = Some(mcn);
+                    }
+                } else {
+                    info.cd_text.set(&label, value);
+                }
+            }
+        }
+    }
+    info
+}
+
+/// Read the identifiers and CD-Text of every log in a file, in order.
+pub fn disc_info(raw: &[u8]) -> Vec<DiscInfo> {
+    let (text, _) = encoding::decode(raw);
+    sniff::split(&text)
+        .iter()
+        .map(|log| read(&log.header, log.text))
+        .collect()
+}
+
+/// Read the identifiers and CD-Text of each parsed log from the sub-log
+/// `sniff::sources` matched it to.
+pub fn for_sources(logs: &[SubLog], sources: &[Option<usize>]) -> Vec<Option<DiscInfo>> {
+    sources
+        .iter()
+        .map(|source| source.map(|index| read(&logs[index].header, logs[index].text)))
+        .collect()
+}
@@ -0,0 +1,170 @@
+// Encoding override and detection diagnostics
+use std::borrow::Cow;
+
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+
+/// Python codec names that are not WHATWG labels, mapped to one that is.
+const PYTHON_ALIASES: &[(&str, &str)] = &[
+    ("mac-roman", "macintosh"),
+    ("macroman", "macintosh"),
+    ("latin-1", "windows-1252"),
+    ("cp932", "shift_jis"),
+    ("cp936", "gbk"),
+    ("cp949", "euc-kr"),
+    ("cp950", "big5"),
+];
+
+/// TLD hints used to ask chardetng for its best guess under each regional
+/// expectation. `None` is the generic guess cambia-core itself relies on.
+const TLD_HINTS: &[Option<&[u8]>] = &[
+    None,
+    Some(b"ru"),
+    Some(b"jp"),
+    Some(b"cn"),
+    Some(b"tw"),
+    Some(b"kr"),
+    Some(b"gr"),
+    Some(b"tr"),
+    Some(b"il"),
+    Some(b"pl"),
+    Some(b"lt"),
+    Some(b"th"),
+    Some(b"vn"),
+    Some(b"eg"),
+];
+
+/// Look up an encoding by WHATWG label or common Python codec name.
+pub fn lookup(label: &str) -> Option<&'static Encoding> {
+    let normalized = label.trim().to_ascii_lowercase().replace('_', "-");
+    let label = PYTHON_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == normalized)
+        .map_or(normalized.as_str(), |(_, target)| target);
+    Encoding::for_label(label.as_bytes())
+}
+
+/// Decode `raw` with a forced encoding and re-encode it as UTF-8 with a BOM,
+/// so that cambia-core's BOM sniffing picks UTF-8 without running detection.
+/// Malformed sequences are replaced with U+FFFD.
+pub fn transcode(raw: &[u8], encoding: &'static Encoding) -> Vec<u8> {
+    let (text, _) = encoding.decode_with_bom_removal(raw);
+    let mut out = Vec::with_capacity(text.len() + 3);
+    out.extend_from_slice(b"\xEF\xBB\xBF");
+    out.extend_from_slice(text.as_bytes());
+    out
+}
+
+/// Ranked encoding candidates for a log, each with a heuristic score.
+pub struct Detection {
+    pub bom: bool,
+    pub candidates: Vec<(&'static Encoding, f64)>,
+}
+
+/// Guess UTF-16 without a BOM from the share of zero bytes in odd or even
+/// positions, which is high for mostly-ASCII text such as ripper logs.
+fn sniff_utf16(raw: &[u8]) -> Option<&'static Encoding> {
+    let sample = &raw[..raw.len().min(4096)];
+    let pairs = sample.len() / 2;
+    if pairs == 0 {
+        return None;
+    }
+    let zeros_at = |parity: usize| {
+        sample
+            .iter()
+            .skip(parity)
+            .step_by(2)
+            .filter(|&&b| b == 0)
+            .count()
+    };
+    if zeros_at(1) * 2 > pairs {
+        Some(UTF_16LE)
+    } else if zeros_at(0) * 2 > pairs {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+/// Rank the encodings a log could be in.
+///
+/// A BOM is authoritative. Otherwise chardetng is asked for a guess under a
+/// range of regional hints. chardetng reports no confidence of its own, so
+/// each encoding's score is its share of these votes, with the generic guess
+/// weighted double, guesses chardetng does not stand behind halved and
+/// encodings that cannot decode the input without errors penalised.
+pub fn detect(raw: &[u8]) -> Detection {
+    if let Some((encoding, _)) = Encoding::for_bom(raw) {
+        return Detection {
+            bom: true,
+            candidates: vec![(encoding, 1.0)],
+        };
+    }
+    if let Some(encoding) = sniff_utf16(raw) {
+        return Detection {
+            bom: false,
+            candidates: vec![(encoding, 1.0)],
+        };
+    }
+
+    let mut detector = EncodingDetector::new();
+    detector.feed(raw, true);
+
+    let mut votes: Vec<(&'static Encoding, f64)> = Vec::new();
+    for (i, tld) in TLD_HINTS.iter().enumerate() {
+        let (encoding, confident) = detector.guess_assess(*tld, true);
+        let mut weight = if i == 0 { 2.0 } else { 1.0 };
+        if !confident {
+            weight /= 2.0;
+        }
+        match votes.iter_mut().find(|(e, _)| *e == encoding) {
+            Some((_, w)) => *w += weight,
+            None => votes.push((encoding, weight)),
+        }
+    }
+
+    for (encoding, weight) in votes.iter_mut() {
+        if *encoding != UTF_8 && encoding.decode_without_bom_handling(raw).1 {
+            *weight /= 10.0;
+        }
+    }
+
+    let total: f64 = votes.iter().map(|(_, w)| w).sum();
+    let mut candidates: Vec<_> = votes
+        .into_iter()
+        .map(|(encoding, weight)| (encoding, weight / total))
+        .collect();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    Detection {
+        bom: false,
+        candidates,
+    }
+}
+
+/// Decode a log with a forced encoding, or else the way `decode` does.
+pub fn decode_as<'a>(
+    raw: &'a [u8],
+    forced: Option<&'static Encoding>,
+) -> (Cow<'a, str>, &'static Encoding) {
+    match forced {
+        Some(encoding) => (encoding.decode_with_bom_removal(raw).0, encoding),
+        None => decode(raw),
+    }
+}
+
+/// Decode a log the way cambia-core does: a BOM wins, then UTF-16 sniffing,
+/// then chardetng's generic guess. Cheaper than `detect`, which runs every
+/// regional hint.
+pub fn decode(raw: &[u8]) -> (Cow<'_, str>, &'static Encoding) {
+    let encoding = match Encoding::for_bom(raw) {
+        Some((encoding, _)) => encoding,
+        None => sniff_utf16(raw).unwrap_or_else(|| {
+            let mut detector = EncodingDetector::new();
+            detector.feed(raw, true);
+            detector.guess(None, true)
+        }),
+    };
+    let (text, encoding, _) = encoding.decode(raw);
+    (text, encoding)
+}
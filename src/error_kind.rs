@@ -0,0 +1,115 @@
+// The kinds of error a ripper logs per track, and what each maps to
+use cambia_core::evaluate::EvaluationUnitField;
+use cambia_core::track::{TrackError, TrackErrorData};
+
+/// A kind of track error, in the order `TrackError` declares them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ErrorKind {
+    Read,
+    Skip,
+    JitterGeneric,
+    JitterEdge,
+    JitterAtom,
+    Drift,
+    Dropped,
+    Duplicated,
+    DamagedSectors,
+    InconsistentErrorSectors,
+    MissingSamples,
+}
+
+impl ErrorKind {
+    pub const ALL: [ErrorKind; 11] = [
+        ErrorKind::Read,
+        ErrorKind::Skip,
+        ErrorKind::JitterGeneric,
+        ErrorKind::JitterEdge,
+        ErrorKind::JitterAtom,
+        ErrorKind::Drift,
+        ErrorKind::Dropped,
+        ErrorKind::Duplicated,
+        ErrorKind::DamagedSectors,
+        ErrorKind::InconsistentErrorSectors,
+        ErrorKind::MissingSamples,
+    ];
+
+    /// The name of its `TrackError` field, as `ParsedLog.replace()` takes it.
+    pub fn key(self) -> &'static str {
+        match self {
+            ErrorKind::Read => "read",
+            ErrorKind::Skip => "skip",
+            ErrorKind::JitterGeneric => "jitter_generic",
+            ErrorKind::JitterEdge => "jitter_edge",
+            ErrorKind::JitterAtom => "jitter_atom",
+            ErrorKind::Drift => "drift",
+            ErrorKind::Dropped => "dropped",
+            ErrorKind::Duplicated => "duplicated",
+            ErrorKind::DamagedSectors => "damaged_sectors",
+            ErrorKind::InconsistentErrorSectors => "inconsistent_err_sectors",
+            ErrorKind::MissingSamples => "missing_samples",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    /// The kind of error an evaluation unit field deducts for. Missing
+    /// samples are not deducted as a track error.
+    pub fn from_field(field: &EvaluationUnitField) -> Option<Self> {
+        use EvaluationUnitField as Field;
+
+        Some(match field {
+            Field::ReadError => ErrorKind::Read,
+            Field::SkipError => ErrorKind::Skip,
+            Field::JitterGenericError => ErrorKind::JitterGeneric,
+            Field::JitterEdgeError => ErrorKind::JitterEdge,
+            Field::JitterAtomError => ErrorKind::JitterAtom,
+            Field::DriftError => ErrorKind::Drift,
+            Field::DroppedError => ErrorKind::Dropped,
+            Field::DuplicatedError => ErrorKind::Duplicated,
+            Field::DamagedSector => ErrorKind::DamagedSectors,
+            Field::InconsistentErrorSectors => ErrorKind::InconsistentErrorSectors,
+            _ => return None,
+        })
+    }
+
+    pub fn data(self, errors: &TrackError) -> &TrackErrorData {
+        match self {
+            ErrorKind::Read => &errors.read,
+            ErrorKind::Skip => &errors.skip,
+            ErrorKind::JitterGeneric => &errors.jitter_generic,
+            ErrorKind::JitterEdge => &errors.jitter_edge,
+            ErrorKind::JitterAtom => &errors.jitter_atom,
+            ErrorKind::Drift => &errors.drift,
+            ErrorKind::Dropped => &errors.dropped,
+            ErrorKind::Duplicated => &errors.duplicated,
+            ErrorKind::DamagedSectors => &errors.damaged_sectors,
+            ErrorKind::InconsistentErrorSectors => &errors.inconsistent_err_sectors,
+            ErrorKind::MissingSamples => &errors.missing_samples,
+        }
+    }
+
+    pub fn data_mut(self, errors: &mut TrackError) -> &mut TrackErrorData {
+        match self {
+            ErrorKind::Read => &mut errors.read,
+            ErrorKind::Skip => &mut errors.skip,
+            ErrorKind::JitterGeneric => &mut errors.jitter_generic,
+            ErrorKind::JitterEdge => &mut errors.jitter_edge,
+            ErrorKind::JitterAtom => &mut errors.jitter_atom,
+            ErrorKind::Drift => &mut errors.drift,
+            ErrorKind::Dropped => &mut errors.dropped,
+            ErrorKind::Duplicated => &mut errors.duplicated,
+            ErrorKind::DamagedSectors => &mut errors.damaged_sectors,
+            ErrorKind::InconsistentErrorSectors => &mut errors.inconsistent_err_sectors,
+            ErrorKind::MissingSamples => &mut errors.missing_samples,
+        }
+    }
+}
+
+/// Each kind of error with its data in `errors`, in `TrackError` order.
+pub fn each(errors: &TrackError) -> impl Iterator<Item = (ErrorKind, &TrackErrorData)> {
+    ErrorKind::ALL
+        .into_iter()
+        .map(move |kind| (kind, kind.data(errors)))
+}
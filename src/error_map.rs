@@ -0,0 +1,319 @@
+// Disc-wide error timeline built from track error ranges, and its SVG view
+use std::fmt::Write;
+
+use cambia_core::extract::Ripper;
+use cambia_core::parser::ParsedLog;
+use pyo3::prelude::*;
+
+use crate::error_kind::ErrorKind;
+use crate::position::{self, DiscRange};
+
+/// A stretch of the disc where the same error kinds apply.
+pub struct Span {
+    pub start: u32,
+    /// Inclusive.
+    pub end: u32,
+    pub kinds: Vec<ErrorKind>,
+}
+
+pub struct ErrorMap {
+    /// First and last sector of the disc from the TOC, if it has entries.
+    pub disc: Option<(u32, u32)>,
+    /// Merged inclusive intervals per error kind, in `TrackError` order.
+    /// Kinds without errors are left out.
+    pub intervals: Vec<(ErrorKind, Vec<(u32, u32)>)>,
+    pub timeline: Vec<Span>,
+}
+
+/// Merge overlapping and touching intervals.
+fn merge(mut intervals: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Cut the disc at every range boundary and keep the pieces that have
+/// errors, joining neighbours with the same kinds.
+fn timeline(ranges: &[DiscRange]) -> Vec<Span> {
+    let mut cuts: Vec<u32> = ranges
+        .iter()
+        .flat_map(|r| [r.start_sector, r.end_sector.saturating_add(1)])
+        .collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut spans: Vec<Span> = Vec::new();
+    for pair in cuts.windows(2) {
+        let (start, end) = (pair[0], pair[1] - 1);
+        let kinds: Vec<ErrorKind> = ErrorKind::ALL
+            .into_iter()
+            .filter(|kind| {
+                ranges
+                    .iter()
+                    .any(|r| r.kind == *kind && r.start_sector <= start && r.end_sector >= end)
+            })
+            .collect();
+        if kinds.is_empty() {
+            continue;
+        }
+        match spans.last_mut() {
+            Some(last) if last.end + 1 == start && last.kinds == kinds => last.end = end,
+            _ => spans.push(Span { start, end, kinds }),
+        }
+    }
+    spans
+}
+
+pub fn build(log: &ParsedLog) -> PyResult<ErrorMap> {
+    let ranges = position::disc_ranges(log)?;
+    let intervals = ErrorKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let of_kind = ranges
+                .iter()
+                .filter(|r| r.kind == kind)
+                .map(|r| (r.start_sector, r.end_sector))
+                .collect();
+            (kind, merge(of_kind))
+        })
+        .filter(|(_, intervals)| !intervals.is_empty())
+        .collect();
+    let entries = &log.toc.raw.entries;
+    Ok(ErrorMap {
+        disc: entries
+            .first()
+            .zip(entries.last())
+            .map(|(first, last)| (first.start_sector, last.end_sector)),
+        intervals,
+        timeline: timeline(&ranges),
+    })
+}
+
+fn colour(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Read => "#d62728",
+        ErrorKind::Skip => "#8c1c13",
+        ErrorKind::JitterGeneric => "#ff7f0e",
+        ErrorKind::JitterEdge => "#f5a623",
+        ErrorKind::JitterAtom => "#e3c200",
+        ErrorKind::Drift => "#9467bd",
+        ErrorKind::Dropped => "#1f77b4",
+        ErrorKind::Duplicated => "#17becf",
+        ErrorKind::DamagedSectors => "#e377c2",
+        ErrorKind::InconsistentErrorSectors => "#8c564b",
+        ErrorKind::MissingSamples => "#7f7f7f",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn sector_count(sectors: u32) -> String {
+    if sectors == 1 {
+        "1 sector".to_string()
+    } else {
+        format!("{} sectors", sectors)
+    }
+}
+
+/// Peak level as a fraction of full scale. EAC logs give it in percent,
+/// the other rippers as a fraction.
+fn peak_fraction(ripper: &Ripper, peak: f64) -> f64 {
+    let peak = if matches!(ripper, Ripper::EAC) {
+        peak / 100.0
+    } else {
+        peak
+    };
+    peak.clamp(0.0, 1.0)
+}
+
+const MARGIN: f64 = 10.0;
+const BAR_TOP: f64 = 24.0;
+const BAR_HEIGHT: f64 = 40.0;
+const PEAK_TOP: f64 = BAR_TOP + BAR_HEIGHT + 6.0;
+const PEAK_HEIGHT: f64 = 20.0;
+const SPEED_Y: f64 = PEAK_TOP + PEAK_HEIGHT + 14.0;
+const LEGEND_TOP: f64 = SPEED_Y + 14.0;
+const LEGEND_ROW: f64 = 16.0;
+/// Narrower tracks go without a number or speed label; tooltips still
+/// have both.
+const MIN_LABEL_WIDTH: f64 = 12.0;
+const MIN_SPEED_WIDTH: f64 = 28.0;
+
+/// Draw the disc as a bar `width` pixels wide: track boundaries from the
+/// TOC, one coloured band per merged error interval, and per-track peak
+/// level bars and extraction speeds below it. Bands are at least a pixel
+/// wide so single sectors stay visible; exact positions are in tooltips.
+pub fn svg(log: &ParsedLog, width: u32) -> PyResult<String> {
+    let map = build(log)?;
+    let width = f64::from(width.max(200));
+    let legend_rows = map.intervals.len().max(1) as f64;
+    let height = LEGEND_TOP + legend_rows * LEGEND_ROW;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="10">"#,
+        w = width,
+        h = height
+    );
+    let release = &log.release_info;
+    if !release.artist.is_empty() || !release.title.is_empty() {
+        let _ = writeln!(
+            out,
+            "<title>Error map of {} / {}</title>",
+            escape(&release.artist),
+            escape(&release.title)
+        );
+    }
+    let (first, last) = match map.disc {
+        Some(disc) => disc,
+        None => {
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}">No TOC in this log; errors cannot be placed on the disc.</text>"#,
+                MARGIN,
+                BAR_TOP + BAR_HEIGHT / 2.0
+            );
+            out.push_str("</svg>\n");
+            return Ok(out);
+        }
+    };
+    let span = f64::from(last - first + 1);
+    let scale = (width - 2.0 * MARGIN) / span;
+    let x = |sector: u32| MARGIN + f64::from(sector.saturating_sub(first)) * scale;
+
+    let _ = writeln!(
+        out,
+        r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#e8f0e8" stroke="#555"/>"##,
+        MARGIN,
+        BAR_TOP,
+        width - 2.0 * MARGIN,
+        BAR_HEIGHT
+    );
+
+    for (kind, intervals) in &map.intervals {
+        for (start, end) in intervals {
+            let left = x(*start);
+            let band = (x(end.saturating_add(1)) - left).max(1.0);
+            let _ = writeln!(
+                out,
+                r#"<rect x="{:.2}" y="{}" width="{:.2}" height="{}" fill="{}" fill-opacity="0.8"><title>{}: {} to {} ({})</title></rect>"#,
+                left,
+                BAR_TOP,
+                band,
+                BAR_HEIGHT,
+                colour(*kind),
+                kind.key(),
+                position::format_msf(*start),
+                position::format_msf(*end),
+                sector_count(position::span(*start, *end))
+            );
+        }
+    }
+
+    for entry in &log.toc.raw.entries {
+        let left = x(entry.start_sector);
+        let right = x(entry.end_sector.saturating_add(1));
+        let centre = (left + right) / 2.0;
+        let _ = writeln!(
+            out,
+            r##"<line x1="{:.2}" y1="{}" x2="{:.2}" y2="{}" stroke="#555"/>"##,
+            left,
+            BAR_TOP - 4.0,
+            left,
+            PEAK_TOP + PEAK_HEIGHT
+        );
+        if right - left >= MIN_LABEL_WIDTH {
+            let _ = writeln!(
+                out,
+                r#"<text x="{:.2}" y="{}" text-anchor="middle">{}</text>"#,
+                centre,
+                BAR_TOP - 6.0,
+                entry.track
+            );
+        }
+
+        let track = log
+            .tracks
+            .iter()
+            .find(|track| u32::from(track.num) == entry.track);
+        let Some(track) = track else { continue };
+        let mut tooltip = format!("Track {}", entry.track);
+        if let Some(peak) = track.peak_level {
+            let _ = write!(
+                tooltip,
+                ", peak level {:.1} %",
+                peak_fraction(&log.ripper, peak) * 100.0
+            );
+        }
+        if let Some(speed) = track.extraction_speed {
+            let _ = write!(tooltip, ", extraction speed {:.1}x", speed);
+        }
+        if let Some(peak) = track.peak_level {
+            let bar = PEAK_HEIGHT * peak_fraction(&log.ripper, peak);
+            let _ = writeln!(
+                out,
+                r##"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="#6a9fd8"><title>{}</title></rect>"##,
+                left + 1.0,
+                PEAK_TOP + PEAK_HEIGHT - bar,
+                (right - left - 2.0).max(1.0),
+                bar,
+                tooltip
+            );
+        }
+        match track.extraction_speed {
+            Some(speed) if right - left >= MIN_SPEED_WIDTH => {
+                let _ = writeln!(
+                    out,
+                    r#"<text x="{:.2}" y="{}" text-anchor="middle">{:.1}x<title>{}</title></text>"#,
+                    centre, SPEED_Y, speed, tooltip
+                );
+            }
+            _ => {}
+        }
+    }
+
+    if map.intervals.is_empty() {
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}">No errors</text>"#,
+            MARGIN,
+            LEGEND_TOP + 10.0
+        );
+    }
+    for (row, (kind, intervals)) in map.intervals.iter().enumerate() {
+        let top = LEGEND_TOP + row as f64 * LEGEND_ROW;
+        let sectors: u32 = intervals
+            .iter()
+            .map(|(start, end)| position::span(*start, *end))
+            .sum();
+        let _ = writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="10" height="10" fill="{}"/>"#,
+            MARGIN,
+            top,
+            colour(*kind)
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}">{} ({})</text>"#,
+            MARGIN + 14.0,
+            top + 9.0,
+            kind.key(),
+            sector_count(sectors)
+        );
+    }
+    out.push_str("</svg>\n");
+    Ok(out)
+}
@@ -0,0 +1,37 @@
+// Python exceptions raised by the bindings beyond the builtin ones
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use pyo3::create_exception;
+use pyo3::prelude::*;
+
+create_exception!(
+    cambia,
+    InternalError,
+    pyo3::exceptions::PyException,
+    "Raised when cambia panics on an input instead of returning an error."
+);
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Run an entry point body, turning a panic into `InternalError` so that a
+/// malformed log raises instead of taking down the interpreter.
+///
+/// Requires the unwinding panic strategy, which is why the release profile
+/// does not set `panic = "abort"`.
+pub fn catch_panic<T>(f: impl FnOnce() -> PyResult<T>) -> PyResult<T> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        Err(InternalError::new_err(format!(
+            "cambia panicked: {}",
+            panic_message(payload.as_ref())
+        )))
+    })
+}
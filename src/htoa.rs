@@ -0,0 +1,109 @@
+// Hidden track one audio: whether a disc has it and how the log dealt with it
+use cambia_core::extract::Ripper;
+use cambia_core::integrity::Integrity;
+use cambia_core::parser::ParsedLog;
+
+use crate::coverage::{self, HTOA_MIN_SECTORS};
+
+/// How the hidden track was extracted.
+pub enum Extraction {
+    /// As a track numbered 0.
+    Track,
+    /// As a range. The parsed log keeps no range sectors, so the range is
+    /// only known to be in a log of a disc with a hidden track.
+    Range,
+}
+
+pub struct Htoa {
+    /// Sectors before track one in the TOC.
+    pub sectors: u32,
+    pub extraction: Option<Extraction>,
+    /// Index of the entry holding the hidden track in `ParsedLog.tracks`.
+    pub index: Option<usize>,
+    /// Whether the entry has a test CRC next to its copy CRC.
+    pub tested: bool,
+    pub crcs_match: bool,
+}
+
+impl Htoa {
+    pub fn present(&self) -> bool {
+        self.sectors >= HTOA_MIN_SECTORS
+    }
+}
+
+pub fn htoa(log: &ParsedLog) -> Htoa {
+    let sectors = coverage::htoa_sectors(&log.toc.raw.entries);
+    let track = log
+        .tracks
+        .iter()
+        .position(|track| !track.is_range && track.num == 0);
+    let (extraction, index) = match track {
+        Some(index) => (Some(Extraction::Track), Some(index)),
+        None if sectors >= HTOA_MIN_SECTORS => {
+            match log.tracks.iter().position(|track| track.is_range) {
+                Some(index) => (Some(Extraction::Range), Some(index)),
+                None => (None, None),
+            }
+        }
+        None => (None, None),
+    };
+    let entry = index.map(|index| &log.tracks[index]);
+    Htoa {
+        sectors,
+        extraction,
+        index,
+        tested: entry.is_some_and(|entry| !entry.test_and_copy.test_hash.is_empty()),
+        crcs_match: entry
+            .is_some_and(|entry| matches!(entry.test_and_copy.integrity, Integrity::Match)),
+    }
+}
+
+/// What to do about the hidden track of a log from the given ripper, or
+/// None if there is nothing to do: the disc has no hidden track, or it was
+/// extracted with matching test and copy CRCs.
+pub fn guidance(htoa: &Htoa, ripper: &Ripper) -> Option<String> {
+    if !htoa.present() {
+        return None;
+    }
+    let last = htoa.sectors - 1;
+    if htoa.extraction.is_none() {
+        let advice = match ripper {
+            Ripper::EAC => format!(
+                "Detect gaps (Action > Detect Gaps), then copy sectors 0 to {} with \
+                 Action > Copy Range using Test & Copy, and save the range as track 00.",
+                last
+            ),
+            Ripper::XLD => "Rip the disc again with test before copy on; XLD saves the \
+                            hidden track as track 00 when it finds audio before track 1."
+                .to_string(),
+            Ripper::Whipper => "Rip the disc again with a current whipper, which saves the \
+                                hidden track as track 00 on its own."
+                .to_string(),
+            _ => format!(
+                "Copy sectors 0 to {} as track 00 twice and compare the CRCs.",
+                last
+            ),
+        };
+        return Some(advice);
+    }
+    if !htoa.tested {
+        let test = match ripper {
+            Ripper::EAC => "Test & Copy",
+            Ripper::XLD => "test before copy",
+            _ => "a test pass",
+        };
+        return Some(format!(
+            "The hidden track was copied without a test; copy sectors 0 to {} again with {} \
+             so that the CRCs can be compared.",
+            last, test
+        ));
+    }
+    if !htoa.crcs_match {
+        return Some(
+            "The test and copy CRCs of the hidden track differ; clean the disc and copy it \
+             again."
+                .to_string(),
+        );
+    }
+    None
+}
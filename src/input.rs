@@ -0,0 +1,89 @@
+// Conversion of Python log content into bytes, borrowed where possible
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyMemoryView, PyString};
+
+/// Log content extracted from a Python object.
+///
+/// `bytes` are immutable and borrowed as they are. Everything else is copied
+/// while the GIL is held, since parsing runs with the GIL released and a
+/// mutable buffer could be resized or freed by another thread meanwhile.
+pub enum LogContent<'py> {
+    Bytes(Bound<'py, PyBytes>),
+    Owned(Vec<u8>),
+}
+
+impl LogContent<'_> {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            LogContent::Bytes(b) => b.as_bytes(),
+            LogContent::Owned(v) => v,
+        }
+    }
+
+    /// Keep the content past this call, without copying it.
+    pub fn unbind(self) -> RawLog {
+        match self {
+            LogContent::Bytes(b) => RawLog::Bytes(b.unbind()),
+            LogContent::Owned(v) => RawLog::Owned(v),
+        }
+    }
+}
+
+/// Log content kept for reading again after the call that extracted it.
+pub enum RawLog {
+    Bytes(Py<PyBytes>),
+    Owned(Vec<u8>),
+}
+
+impl RawLog {
+    pub fn as_bytes<'a>(&'a self, py: Python<'_>) -> &'a [u8] {
+        match self {
+            RawLog::Bytes(b) => b.as_bytes(py),
+            RawLog::Owned(v) => v,
+        }
+    }
+}
+
+/// Extract bytes-like content without reading file objects.
+fn extract_bytes_like<'py>(content: &Bound<'py, PyAny>) -> PyResult<Option<LogContent<'py>>> {
+    if let Ok(s) = content.cast::<PyString>() {
+        return Ok(Some(LogContent::Owned(
+            s.to_cow()?.into_owned().into_bytes(),
+        )));
+    }
+    if let Ok(b) = content.cast::<PyBytes>() {
+        return Ok(Some(LogContent::Bytes(b.clone())));
+    }
+    if let Ok(b) = content.cast::<PyByteArray>() {
+        return Ok(Some(LogContent::Owned(b.to_vec())));
+    }
+
+    // The buffer API is not in the Python 3.10 stable ABI, so other buffer
+    // objects such as memoryview and mmap are copied through a memoryview.
+    if let Ok(view) = PyMemoryView::from(content) {
+        let bytes = view.call_method0("tobytes")?.cast_into::<PyBytes>()?;
+        return Ok(Some(LogContent::Bytes(bytes)));
+    }
+
+    Ok(None)
+}
+
+/// Extract log content from `str`, any bytes-like or buffer-protocol object,
+/// or a readable binary file object.
+pub fn extract_content<'py>(content: &Bound<'py, PyAny>) -> PyResult<LogContent<'py>> {
+    if let Some(log) = extract_bytes_like(content)? {
+        return Ok(log);
+    }
+    if content.hasattr("read")? {
+        let data = content.call_method0("read")?;
+        if let Some(log) = extract_bytes_like(&data)? {
+            return Ok(log);
+        }
+        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "read() must return bytes or str",
+        ));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "content must be str, bytes, a buffer-protocol object or a binary file object",
+    ))
+}
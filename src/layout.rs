@@ -0,0 +1,70 @@
+// Audio and data tracks, sessions and the layout of a disc from its TOC
+use cambia_core::toc::TocEntry;
+
+/// Sectors between the end of the audio session and the data track of an
+/// Enhanced CD: the lead-out, lead-in and pre-gap of the second session.
+pub const SESSION_GAP: u32 = 11_400;
+
+/// A data track followed by audio ends with a post-gap of at least two
+/// seconds, which the TOC leaves out of the data track.
+pub const DATA_POSTGAP: u32 = 150;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TrackType {
+    Audio,
+    Data,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// Audio tracks only.
+    Plain,
+    /// Audio in the first session and a data track in a second one.
+    Enhanced,
+    /// A data track first, then audio, in one session.
+    MixedMode,
+}
+
+/// Logs do not say which tracks are data, so the layout is read from the
+/// gaps the TOC leaves between entries. An audio CD whose first track is
+/// followed by a gap of `DATA_POSTGAP` sectors or more is taken for
+/// mixed-mode.
+pub fn layout(entries: &[TocEntry]) -> Layout {
+    match entries {
+        [.., previous, last]
+            if last.start_sector >= previous.end_sector.saturating_add(1 + SESSION_GAP) =>
+        {
+            Layout::Enhanced
+        }
+        [first, next, ..]
+            if next.start_sector >= first.end_sector.saturating_add(1 + DATA_POSTGAP) =>
+        {
+            Layout::MixedMode
+        }
+        _ => Layout::Plain,
+    }
+}
+
+/// The data tracks of a disc: the last track of an Enhanced CD, after the
+/// session gap, or the first of a mixed-mode CD, before its post-gap.
+pub fn data_tracks(entries: &[TocEntry]) -> Vec<u32> {
+    let track = match layout(entries) {
+        Layout::Plain => None,
+        Layout::Enhanced => entries.last(),
+        Layout::MixedMode => entries.first(),
+    };
+    track.map(|entry| entry.track).into_iter().collect()
+}
+
+/// Type and session of every entry, in TOC order.
+pub fn tracks(entries: &[TocEntry]) -> Vec<(TrackType, u8)> {
+    let layout = layout(entries);
+    let last = entries.len().saturating_sub(1);
+    (0..entries.len())
+        .map(|index| match layout {
+            Layout::Enhanced if index == last => (TrackType::Data, 2),
+            Layout::MixedMode if index == 0 => (TrackType::Data, 1),
+            _ => (TrackType::Audio, 1),
+        })
+        .collect()
+}
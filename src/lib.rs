@@ -1,60 +1,520 @@
+mod accuraterip;
+mod archive;
+mod build;
+mod coverage;
+mod ctdb;
+mod disc_id;
+mod disc_info;
+mod encoding;
+mod error_kind;
+mod error_map;
+mod errors;
+mod htoa;
+mod input;
+mod layout;
+mod log_data;
+mod pipeline;
+mod position;
 mod py_classes;
+mod range;
+mod remediation;
+mod render;
+mod replace;
+mod scores;
+mod shared;
+mod sniff;
+mod translate;
 
+use cambia_core::extract::Ripper;
+use cambia_core::response::CambiaResponse;
+use encoding_rs::Encoding;
 use pyo3::prelude::*;
-use pyo3::types::PyModule;
+use pyo3::types::{PyModule, PyString};
 
-use py_classes::{PyCambiaResponse, PyRipper};
+use archive::{ArchiveError, ArchiveLimits};
+use input::RawLog;
+use log_data::LogData;
+use py_classes::{
+    PyArchiveEntry, PyCambiaResponse, PyCtdbResult, PyDiscInfo, PyEncodingDetection, PyParsedLog,
+    PyParsedLogCombined, PyRangeSplit, PyRipper, PyScoreComparison, PySniffResult, PyTranslatedLog,
+};
+use shared::Shared;
 
-/// Parse a CD ripping log file and return typed Python objects.
-///
-/// Accepts either a string path or a PathLike object (e.g., pathlib.Path).
-#[pyfunction]
-fn parse_log_file(py: Python<'_>, path: &Bound<'_, PyAny>) -> PyResult<PyCambiaResponse> {
+/// Extract a filesystem path from a string or PathLike object.
+fn extract_path(path: &Bound<'_, PyAny>) -> PyResult<std::path::PathBuf> {
     // Try to extract as PathBuf first (handles pathlib.Path and similar)
-    let path_buf: std::path::PathBuf = if let Ok(p) = path.extract::<std::path::PathBuf>() {
-        p
+    if let Ok(p) = path.extract::<std::path::PathBuf>() {
+        Ok(p)
     } else if let Ok(s) = path.extract::<String>() {
         // Fallback to string extraction
-        std::path::PathBuf::from(s)
+        Ok(std::path::PathBuf::from(s))
     } else {
-        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
             "path must be str or PathLike",
-        ));
+        ))
+    }
+}
+
+fn archive_error(e: ArchiveError) -> PyErr {
+    match e {
+        ArchiveError::Io(e) => {
+            PyErr::new::<pyo3::exceptions::PyOSError, _>(format!("Could not read archive: {}", e))
+        }
+        e => PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Could not read archive: {}",
+            e
+        )),
+    }
+}
+
+/// Resolve the `encoding` keyword argument of the parse functions.
+fn lookup_encoding(label: Option<&str>) -> PyResult<Option<&'static Encoding>> {
+    label
+        .map(|label| {
+            encoding::lookup(label).ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyLookupError, _>(format!(
+                    "unknown encoding: {}",
+                    label
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// A response parsed from raw log bytes, with the text they were decoded
+/// to where that was done here rather than in cambia-core.
+struct Parse {
+    response: CambiaResponse,
+    text: Option<String>,
+}
+
+impl Parse {
+    /// Pair the response with the CUETools DB results and disc info of its
+    /// logs, which cambia-core does not parse, to be read on first use from
+    /// the decoded text or else from `raw`.
+    fn into_response(
+        self,
+        raw: impl FnOnce() -> RawLog,
+        encoding: Option<&'static Encoding>,
+    ) -> PyCambiaResponse {
+        let log_data = match self.text {
+            Some(text) => LogData::from_text(text),
+            None => LogData::from_raw(raw(), encoding),
+        };
+        PyCambiaResponse::with_log_data(self.response, Shared::new(log_data))
+    }
+}
+
+/// Parse raw log bytes with cambia-core, forcing a codec with `encoding`
+/// and a parser with `ripper` instead of auto-detecting them.
+fn parse_raw(
+    raw: &[u8],
+    encoding: Option<&'static Encoding>,
+    ripper: Option<&Ripper>,
+) -> Result<Parse, String> {
+    match ripper {
+        Some(ripper) => {
+            let (parsed, text) = pipeline::parse_as(raw, encoding, Some(ripper))
+                .map_err(|e| format!("Could not parse log: {}", e))?;
+            Ok(Parse {
+                response: pipeline::respond(parsed),
+                text: Some(text.into_owned()),
+            })
+        }
+        None => Ok(Parse {
+            response: parse_detected(raw, encoding)?,
+            text: None,
+        }),
+    }
+}
+
+/// Run cambia-core's detection and evaluation over raw log bytes,
+/// optionally forcing the encoding.
+fn parse_detected(
+    raw: &[u8],
+    encoding: Option<&'static Encoding>,
+) -> Result<CambiaResponse, String> {
+    let parse = |raw: &[u8]| -> Result<CambiaResponse, String> {
+        let mut response = cambia_core::handler::parse_log_bytes(Vec::new(), raw)
+            .map_err(|e| format!("Could not parse log: {:?}", e))?;
+        disc_id::correct(&mut response.parsed);
+        Ok(response)
     };
+    match encoding {
+        Some(encoding) if !raw.is_empty() => {
+            let mut response = parse(&encoding::transcode(raw, encoding))?;
+            response.parsed.encoding = encoding.name().to_string();
+            Ok(response)
+        }
+        _ => parse(raw),
+    }
+}
+
+/// Parse a CD ripping log file and return typed Python objects.
+///
+/// Accepts either a string path or a PathLike object (e.g., pathlib.Path).
+/// Gzip-compressed logs (e.g. `.log.gz`) are decompressed transparently.
+/// `encoding` forces a codec and `ripper` a parser instead of
+/// auto-detecting them.
+#[pyfunction]
+#[pyo3(signature = (path, *, encoding=None, ripper=None))]
+fn parse_log_file(
+    py: Python<'_>,
+    path: &Bound<'_, PyAny>,
+    encoding: Option<&str>,
+    ripper: Option<PyRipper>,
+) -> PyResult<PyCambiaResponse> {
+    errors::catch_panic(|| {
+        let path_buf = extract_path(path)?;
+        let encoding = lookup_encoding(encoding)?;
+        let ripper = ripper.as_ref().map(Ripper::from);
 
-    let response = py.detach(|| {
-        let raw = std::fs::read(&path_buf).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyOSError, _>(format!("Could not read file: {}", e))
+        let response = py.detach(|| {
+            let mut raw = std::fs::read(&path_buf).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyOSError, _>(format!("Could not read file: {}", e))
+            })?;
+            if archive::is_gzip(&raw) {
+                raw = archive::gunzip(&raw, ArchiveLimits::default().max_entry_size).map_err(
+                    |e| {
+                        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                            "Could not decompress log: {}",
+                            e
+                        ))
+                    },
+                )?;
+            }
+            parse_raw(&raw, encoding, ripper.as_ref())
+                .map(|parse| parse.into_response(|| RawLog::Owned(raw), encoding))
+                .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
         })?;
-        cambia_core::handler::parse_log_bytes(Vec::new(), &raw).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Could not parse log: {:?}", e))
-        })
-    })?;
-    Ok(PyCambiaResponse::from_response(&response))
+        Ok(response)
+    })
+}
+
+/// Parse log content from a string, bytes-like object or binary file.
+///
+/// Accepts `str` (converted to UTF-8 bytes), `bytes`, `bytearray` or any
+/// other buffer-protocol object such as `memoryview` or `mmap` (copied once,
+/// unlike `bytes`, with automatic encoding detection by cambia-core), or a
+/// readable binary file object, which is read to the end. `encoding`
+/// forces a codec for byte input and `ripper` a parser instead of
+/// auto-detecting them.
+#[pyfunction]
+#[pyo3(signature = (content, *, encoding=None, ripper=None))]
+fn parse_log_content(
+    content: &Bound<'_, PyAny>,
+    encoding: Option<&str>,
+    ripper: Option<PyRipper>,
+) -> PyResult<PyCambiaResponse> {
+    errors::catch_panic(|| {
+        let encoding = lookup_encoding(encoding)?;
+        let ripper = ripper.as_ref().map(Ripper::from);
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let parse = content.py().detach(|| {
+            parse_raw(raw, encoding, ripper.as_ref())
+                .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)
+        })?;
+        Ok(parse.into_response(|| log.unbind(), encoding))
+    })
 }
 
-/// Parse log content from a string or bytes.
+/// Parse a log without evaluating it, for callers that only need the parsed
+/// data. The result can be scored later with `evaluate`.
 ///
-/// Accepts either `str` (converted to UTF-8 bytes) or `bytes` (used as-is
-/// with automatic encoding detection by cambia-core).
+/// Accepts the same content types and keywords as `parse_log_content`.
+/// Without `ripper`, the parser is picked from the first log header in the
+/// text, so no evaluator runs either way.
 #[pyfunction]
-fn parse_log_content(content: &Bound<'_, PyAny>) -> PyResult<PyCambiaResponse> {
-    let raw: Vec<u8> = if let Ok(s) = content.extract::<String>() {
-        s.into_bytes()
-    } else if let Ok(b) = content.extract::<Vec<u8>>() {
-        b
+#[pyo3(signature = (content, *, encoding=None, ripper=None))]
+fn parse_only(
+    content: &Bound<'_, PyAny>,
+    encoding: Option<&str>,
+    ripper: Option<PyRipper>,
+) -> PyResult<PyParsedLogCombined> {
+    errors::catch_panic(|| {
+        let encoding = lookup_encoding(encoding)?;
+        let ripper = ripper.as_ref().map(Ripper::from);
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let (parsed, text) = content.py().detach(|| {
+            pipeline::parse_as(raw, encoding, ripper.as_ref())
+                .map(|(parsed, text)| (parsed, text.into_owned()))
+                .map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Could not parse log: {}",
+                        e
+                    ))
+                })
+        })?;
+        Ok(PyParsedLogCombined::from_parsed(
+            parsed,
+            LogData::from_text(text),
+        ))
+    })
+}
+
+/// Take a `ParsedLogCombined`, or a single `ParsedLog` such as one returned
+/// by `ParsedLog.replace()`, as input to the evaluators.
+fn extract_parsed(parsed: &Bound<'_, PyAny>) -> PyResult<PyParsedLogCombined> {
+    if let Ok(combined) = parsed.cast::<PyParsedLogCombined>() {
+        Ok(combined.get().clone())
+    } else if let Ok(log) = parsed.cast::<PyParsedLog>() {
+        Ok(PyParsedLogCombined::from(log.get()))
     } else {
-        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-            "content must be str or bytes",
-        ));
-    };
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "expected ParsedLogCombined or ParsedLog",
+        ))
+    }
+}
 
-    let response = content.py().detach(|| {
-        cambia_core::handler::parse_log_bytes(Vec::new(), &raw).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Could not parse log: {:?}", e))
-        })
-    })?;
-    Ok(PyCambiaResponse::from_response(&response))
+/// Run the evaluators over a log returned by `parse_only`, or over a single
+/// `ParsedLog`.
+///
+/// The evaluations carry the CUETools DB result each log was parsed with,
+/// unless `ctdb` gives one per log in its place.
+#[pyfunction]
+#[pyo3(signature = (parsed, *, ctdb=None))]
+fn evaluate(
+    py: Python<'_>,
+    parsed: &Bound<'_, PyAny>,
+    ctdb: Option<Vec<Option<PyCtdbResult>>>,
+) -> PyResult<PyCambiaResponse> {
+    errors::catch_panic(|| {
+        let parsed = extract_parsed(parsed)?;
+        let log_data = match ctdb {
+            Some(ctdb) => {
+                let ctdb: Vec<Option<ctdb::Ctdb>> = ctdb
+                    .iter()
+                    .map(|result| result.as_ref().map(PyCtdbResult::to_ctdb))
+                    .collect();
+                let disc_info = parsed.disc_info().to_vec();
+                if ctdb.len() > disc_info.len() {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "{} CTDB results given for {} logs",
+                        ctdb.len(),
+                        disc_info.len()
+                    )));
+                }
+                Shared::new(LogData::known(ctdb, disc_info))
+            }
+            None => parsed.log_data(),
+        };
+        let parsed = parsed.to_parsed();
+        let response = py.detach(|| pipeline::respond(parsed));
+        Ok(PyCambiaResponse::with_log_data(response, log_data))
+    })
+}
+
+/// Evaluate two versions of a log, typically one from `ParsedLog.replace()`
+/// against the log it was made from, and report the score difference.
+#[pyfunction]
+fn compare(
+    py: Python<'_>,
+    original: &Bound<'_, PyAny>,
+    modified: &Bound<'_, PyAny>,
+) -> PyResult<PyScoreComparison> {
+    errors::catch_panic(|| {
+        let original = extract_parsed(original)?.to_parsed();
+        let modified = extract_parsed(modified)?.to_parsed();
+        let (original, modified) =
+            py.detach(|| (pipeline::respond(original), pipeline::respond(modified)));
+        Ok(PyScoreComparison::new(original, modified))
+    })
+}
+
+/// Split the pseudo-track of a range rip into one entry per TOC track, so
+/// range rips can be handled like track rips.
+#[pyfunction]
+fn split_range(parsed_log: PyRef<'_, PyParsedLog>) -> PyResult<PyRangeSplit> {
+    errors::catch_panic(|| parsed_log.split_range())
+}
+
+/// Rank the encodings a log could be in, for re-parsing with `encoding=`.
+///
+/// Accepts the same content types as `parse_log_content`.
+#[pyfunction]
+fn detect_encoding(content: &Bound<'_, PyAny>) -> PyResult<PyEncodingDetection> {
+    errors::catch_panic(|| {
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let detection = content.py().detach(|| encoding::detect(raw));
+        Ok(PyEncodingDetection::from_detection(&detection))
+    })
+}
+
+/// Parse every log found inside a zip, tar, tar.gz or gzip archive.
+///
+/// Accepts a path (str or PathLike), the archive content as a bytes-like or
+/// buffer-protocol object, or a readable binary file object. Log
+/// candidates are found by their `.log`/`.log.gz` extension and by sniffing
+/// the content of every other file. Entry count and decompressed size are
+/// capped by the given limits.
+#[pyfunction]
+#[pyo3(signature = (source, *, max_entries=None, max_entry_size=None, max_total_size=None))]
+fn parse_archive(
+    py: Python<'_>,
+    source: &Bound<'_, PyAny>,
+    max_entries: Option<usize>,
+    max_entry_size: Option<u64>,
+    max_total_size: Option<u64>,
+) -> PyResult<Vec<PyArchiveEntry>> {
+    errors::catch_panic(|| {
+        let defaults = ArchiveLimits::default();
+        let limits = ArchiveLimits {
+            max_entries: max_entries.unwrap_or(defaults.max_entries),
+            max_entry_size: max_entry_size.unwrap_or(defaults.max_entry_size),
+            max_total_size: max_total_size.unwrap_or(defaults.max_total_size),
+        };
+
+        let walk = |raw: &[u8], name: &str| {
+            archive::extract_logs(raw, name, limits)
+                .map_err(archive_error)
+                .map(|members| {
+                    members
+                        .into_iter()
+                        .map(|member| {
+                            let result = parse_raw(&member.data, None, None).map(|parse| {
+                                parse.into_response(|| RawLog::Owned(member.data), None)
+                            });
+                            (member.path, result)
+                        })
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        let is_path = source.is_instance_of::<PyString>() || source.hasattr("__fspath__")?;
+        let results = if !is_path {
+            let content = input::extract_content(source).map_err(|e| {
+                if e.is_instance_of::<pyo3::exceptions::PyTypeError>(py) {
+                    PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                        "source must be str, PathLike, bytes-like or a binary file object",
+                    )
+                } else {
+                    e
+                }
+            })?;
+            let raw = content.as_bytes();
+            py.detach(|| walk(raw, ""))?
+        } else {
+            let path_buf = extract_path(source)?;
+            py.detach(|| {
+                let raw = std::fs::read(&path_buf).map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyOSError, _>(format!(
+                        "Could not read file: {}",
+                        e
+                    ))
+                })?;
+                let name = path_buf
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                walk(&raw, &name)
+            })?
+        };
+
+        Ok(results
+            .into_iter()
+            .map(|(path, result)| PyArchiveEntry::from_result(path, result))
+            .collect())
+    })
+}
+
+/// Identify a log's ripper, version and language from its headers, without
+/// parsing tracks or running evaluators.
+///
+/// Accepts the same content types as `parse_log_content`.
+#[pyfunction(name = "sniff")]
+fn sniff_log(content: &Bound<'_, PyAny>) -> PyResult<PySniffResult> {
+    errors::catch_panic(|| {
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let sniff = content.py().detach(|| sniff::sniff(raw));
+        Ok(PySniffResult::from_sniff(&sniff))
+    })
+}
+
+/// Translate a localized EAC log into English, line by line.
+///
+/// Accepts the same content types as `parse_log_content`. Every line of the
+/// original maps to one line of the result. The language tables only cover
+/// the wording found in the bundled logs, so lines they do not know are
+/// kept with an "[untranslated] " marker and listed in `untranslated`, or
+/// raise with `strict`.
+#[pyfunction]
+#[pyo3(signature = (content, target="en", *, strict=false))]
+fn translate_log(
+    content: &Bound<'_, PyAny>,
+    target: &str,
+    strict: bool,
+) -> PyResult<PyTranslatedLog> {
+    errors::catch_panic(|| {
+        if !render::LANGUAGES.contains(&target) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported target language '{}'; expected one of: {}",
+                target,
+                render::LANGUAGES.join(", ")
+            )));
+        }
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let translation = content
+            .py()
+            .detach(|| translate::translate(raw))
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        let untranslated = translation.untranslated();
+        if strict && !untranslated.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Could not translate {} line(s): {}",
+                untranslated.len(),
+                untranslated
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+        Ok(PyTranslatedLog::from_translation(&translation, target))
+    })
+}
+
+/// Read the ISRCs, media catalog number and CD-Text of every log in a file.
+///
+/// Accepts the same content types as `parse_log_content`. Returns one entry
+/// per log, in the order of a combined file.
+#[pyfunction]
+fn read_disc_info(content: &Bound<'_, PyAny>) -> PyResult<Vec<PyDiscInfo>> {
+    errors::catch_panic(|| {
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let info = content.py().detach(|| disc_info::disc_info(raw));
+        Ok(info.iter().map(PyDiscInfo::from_disc_info).collect())
+    })
+}
+
+/// Read the CUETools DB results of every log in a file.
+///
+/// Accepts the same content types as `parse_log_content`. Returns one entry
+/// per log, in the order of a combined file, None for logs without a CTDB
+/// section.
+#[pyfunction]
+fn read_ctdb(content: &Bound<'_, PyAny>) -> PyResult<Vec<Option<PyCtdbResult>>> {
+    errors::catch_panic(|| {
+        let log = input::extract_content(content)?;
+        let raw = log.as_bytes();
+
+        let results = content.py().detach(|| ctdb::read(raw, None));
+        Ok(results
+            .into_iter()
+            .map(|result| result.map(PyCtdbResult::from_ctdb))
+            .collect())
+    })
 }
 
 /// Get supported log file formats.
@@ -69,13 +529,35 @@ fn get_supported_rippers() -> PyResult<Vec<PyRipper>> {
     Ok(rippers)
 }
 
+/// Panic with `message` while the GIL is released, as a parse would, so that
+/// tests can check that panics surface as `InternalError`. Only built with
+/// the `test-hooks` feature.
+#[cfg(feature = "test-hooks")]
+#[pyfunction]
+fn _panic(py: Python<'_>, message: String) -> PyResult<()> {
+    errors::catch_panic(|| py.detach(|| -> PyResult<()> { panic!("{}", message) }))
+}
+
 /// A Python module implemented in Rust.
 #[pymodule(gil_used = false)]
 fn _cambia(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse_log_file, m)?)?;
     m.add_function(wrap_pyfunction!(parse_log_content, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_only, m)?)?;
+    m.add_function(wrap_pyfunction!(evaluate, m)?)?;
+    m.add_function(wrap_pyfunction!(compare, m)?)?;
+    m.add_function(wrap_pyfunction!(split_range, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_archive, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_encoding, m)?)?;
+    m.add_function(wrap_pyfunction!(sniff_log, m)?)?;
+    m.add_function(wrap_pyfunction!(translate_log, m)?)?;
+    m.add_function(wrap_pyfunction!(read_disc_info, m)?)?;
+    m.add_function(wrap_pyfunction!(read_ctdb, m)?)?;
     m.add_function(wrap_pyfunction!(get_supported_rippers, m)?)?;
+    #[cfg(feature = "test-hooks")]
+    m.add_function(wrap_pyfunction!(_panic, m)?)?;
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("InternalError", m.py().get_type::<errors::InternalError>())?;
     py_classes::register_classes(m)?;
     Ok(())
 }
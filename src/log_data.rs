@@ -0,0 +1,139 @@
+// What is read from the text of the logs beside cambia-core's parse
+use std::sync::{Mutex, OnceLock};
+
+use cambia_core::parser::ParsedLogCombined;
+use encoding_rs::Encoding;
+use pyo3::prelude::*;
+
+use crate::ctdb::{self, Ctdb};
+use crate::disc_info::{self, DiscInfo};
+use crate::input::RawLog;
+use crate::shared::Shared;
+use crate::{encoding, sniff};
+
+/// The text the logs were parsed from, as it was at hand.
+enum Source {
+    Raw(RawLog, Option<&'static Encoding>),
+    Text(String),
+}
+
+/// The CUETools DB result and disc info of each parsed log, which
+/// cambia-core does not parse.
+///
+/// They are read from the part of the text each log was parsed from on first
+/// use, so a caller that only wants the score does not pay for splitting and
+/// scanning the text. The text is dropped once read.
+pub struct LogData {
+    source: Mutex<Option<Source>>,
+    read: OnceLock<Read>,
+}
+
+/// What was read, one entry per log.
+struct Read {
+    ctdb: Vec<Option<Ctdb>>,
+    disc_info: Vec<Option<DiscInfo>>,
+}
+
+impl LogData {
+    /// Log data to be read from raw log bytes, decoded as the parse did.
+    pub fn from_raw(raw: RawLog, encoding: Option<&'static Encoding>) -> Self {
+        Self::pending(Source::Raw(raw, encoding))
+    }
+
+    /// Log data to be read from text that was already decoded.
+    pub fn from_text(text: String) -> Self {
+        Self::pending(Source::Text(text))
+    }
+
+    /// Log data that is already known, one entry per log.
+    pub fn known(ctdb: Vec<Option<Ctdb>>, disc_info: Vec<Option<DiscInfo>>) -> Self {
+        LogData {
+            source: Mutex::new(None),
+            read: OnceLock::from(Read { ctdb, disc_info }),
+        }
+    }
+
+    fn pending(source: Source) -> Self {
+        LogData {
+            source: Mutex::new(Some(source)),
+            read: OnceLock::new(),
+        }
+    }
+
+    /// The CUETools DB result of each log in `parsed`, the logs this data
+    /// was made for.
+    pub fn ctdb(&self, parsed: &ParsedLogCombined) -> &[Option<Ctdb>] {
+        &self.get(parsed).ctdb
+    }
+
+    /// The disc info of each log in `parsed`, the logs this data was made
+    /// for.
+    pub fn disc_info(&self, parsed: &ParsedLogCombined) -> &[Option<DiscInfo>] {
+        &self.get(parsed).disc_info
+    }
+
+    fn get(&self, parsed: &ParsedLogCombined) -> &Read {
+        self.read.get_or_init(|| {
+            let source = self
+                .source
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .take();
+            match source {
+                Some(Source::Raw(raw, forced)) => Python::attach(|py| {
+                    read(&encoding::decode_as(raw.as_bytes(py), forced).0, parsed)
+                }),
+                Some(Source::Text(text)) => read(&text, parsed),
+                None => Read {
+                    ctdb: Vec::new(),
+                    disc_info: Vec::new(),
+                },
+            }
+        })
+    }
+}
+
+/// Read the CUETools DB result and disc info of each parsed log from the
+/// sub-log `sniff::sources` matches it to.
+fn read(text: &str, parsed: &ParsedLogCombined) -> Read {
+    let logs = sniff::split(text);
+    let sources = sniff::sources(&parsed.parsed_logs, &logs);
+    Read {
+        ctdb: ctdb::for_sources(&logs, &sources),
+        disc_info: disc_info::for_sources(&logs, &sources),
+    }
+}
+
+/// One log's part of the `LogData` of the logs it was parsed with.
+#[derive(Clone)]
+pub struct LogEntry {
+    data: Shared<LogData>,
+    parsed: Shared<ParsedLogCombined>,
+    index: usize,
+}
+
+impl LogEntry {
+    pub fn new(data: Shared<LogData>, parsed: Shared<ParsedLogCombined>, index: usize) -> Self {
+        LogEntry {
+            data,
+            parsed,
+            index,
+        }
+    }
+
+    pub fn ctdb(&self) -> Option<Shared<Ctdb>> {
+        self.data.map_opt(|data| {
+            data.ctdb(&self.parsed)
+                .get(self.index)
+                .and_then(Option::as_ref)
+        })
+    }
+
+    pub fn disc_info(&self) -> Option<Shared<DiscInfo>> {
+        self.data.map_opt(|data| {
+            data.disc_info(&self.parsed)
+                .get(self.index)
+                .and_then(Option::as_ref)
+        })
+    }
+}
@@ -0,0 +1,93 @@
+// Direct use of cambia-core's per-ripper parsers and evaluators, for the
+// cases where `handler::parse_log_bytes` does too much or too little
+use std::borrow::Cow;
+
+use cambia_core::evaluate::ops::OpsEvaluator;
+use cambia_core::evaluate::{EvaluationCombined, Evaluator};
+use cambia_core::extract::Ripper;
+use cambia_core::parser::{ParsedLogCombined, ParserCombined};
+use cambia_core::response::CambiaResponse;
+#[cfg(feature = "experimental_rippers")]
+use cambia_core::rippers::cueripper::CueRipperParser;
+use cambia_core::rippers::eac::EacParser;
+use cambia_core::rippers::whipper::WhipperParser;
+use cambia_core::rippers::xld::XldParser;
+use encoding_rs::Encoding;
+
+use crate::{disc_id, encoding, sniff};
+
+/// Run one ripper's parser over decoded log text, skipping detection.
+///
+/// cambia-core's parsers do not reject foreign input; they return logs with
+/// nothing filled in. A result without a single track or TOC entry is
+/// therefore reported as the forced parser not recognizing the log, which
+/// is the per-parser error `ripper=` documents.
+pub fn parse_text_as(ripper: &Ripper, text: &str) -> Result<ParsedLogCombined, String> {
+    let text = text.to_string();
+    let parsed = match ripper {
+        Ripper::EAC => EacParser::new(text).parse_combined(),
+        Ripper::XLD => XldParser::new(text).parse_combined(),
+        Ripper::Whipper => WhipperParser::new(text).parse_combined(),
+        #[cfg(feature = "experimental_rippers")]
+        Ripper::CueRipper => CueRipperParser::new(text).parse_combined(),
+        _ => return Err(format!("No parser is available for {:?} logs", ripper)),
+    };
+    let empty = parsed
+        .parsed_logs
+        .iter()
+        .all(|log| log.tracks.is_empty() && log.toc.raw.entries.is_empty());
+    if empty {
+        return Err(format!(
+            "The {:?} parser could not read the log: no tracks or TOC found",
+            ripper
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Evaluate a parsed log with the evaluators `handler::parse_log_bytes` runs.
+pub fn evaluate(parsed: &ParsedLogCombined) -> Vec<EvaluationCombined> {
+    vec![OpsEvaluator::new().evaluate_combined(parsed)]
+}
+
+/// Attach evaluations to a parsed log.
+pub fn respond(parsed: ParsedLogCombined) -> CambiaResponse {
+    CambiaResponse {
+        id: Vec::new(),
+        evaluation_combined: evaluate(&parsed),
+        parsed,
+    }
+}
+
+/// The parser for the log `text` starts with, going by its header as
+/// `sniff` reads it. morituri logs are in the whipper format.
+fn detect(text: &str) -> Result<Ripper, String> {
+    let header = sniff::split(text)
+        .into_iter()
+        .next()
+        .map(|log| log.header)
+        .ok_or_else(|| "No log header recognized".to_string())?;
+    Ok(match header.ripper {
+        Ripper::Morituri => Ripper::Whipper,
+        ripper => ripper,
+    })
+}
+
+/// Decode raw log bytes and parse them without evaluating them, with the
+/// parser of a forced ripper or else of the log's first header. The decoded
+/// text is returned with the logs, for reading what cambia-core does not.
+pub fn parse_as<'a>(
+    raw: &'a [u8],
+    forced_encoding: Option<&'static Encoding>,
+    ripper: Option<&Ripper>,
+) -> Result<(ParsedLogCombined, Cow<'a, str>), String> {
+    let (text, encoding) = encoding::decode_as(raw, forced_encoding);
+    let ripper = match ripper {
+        Some(ripper) => ripper.clone(),
+        None => detect(&text)?,
+    };
+    let mut parsed = parse_text_as(&ripper, &text)?;
+    parsed.encoding = encoding.name().to_string();
+    disc_id::correct(&mut parsed);
+    Ok((parsed, text))
+}
@@ -0,0 +1,121 @@
+// Exact CD positions: sectors, samples and minutes:seconds:frames
+use cambia_core::parser::ParsedLog;
+use cambia_core::track::TrackErrorRange;
+use cambia_core::util::Time;
+use pyo3::prelude::*;
+
+use crate::build::SECTORS_PER_SECOND;
+use crate::error_kind::{self, ErrorKind};
+use crate::py_classes::time_to_duration;
+
+/// 44.1 kHz stereo samples in one CD sector.
+pub const SAMPLES_PER_SECTOR: u64 = 588;
+
+/// Nearest whole sector to a time, which parsers only ever derive from
+/// sectors or whole seconds.
+pub fn sectors(time: &Time) -> PyResult<u32> {
+    let sectors = time_to_duration(time)?.as_secs_f64() * f64::from(SECTORS_PER_SECOND);
+    // Saturating: times beyond u32 sectors are not on a CD anyway.
+    Ok(sectors.round() as u32)
+}
+
+pub fn samples(time: &Time) -> PyResult<u64> {
+    let samples = time_to_duration(time)?.as_secs_f64()
+        * f64::from(SECTORS_PER_SECOND)
+        * SAMPLES_PER_SECTOR as f64;
+    Ok(samples.round() as u64)
+}
+
+/// Sectors from `start` to `end` inclusive, or 0 if `end` is before
+/// `start`, as it can be in hand-built objects.
+pub fn span(start: u32, end: u32) -> u32 {
+    end.saturating_add(1).saturating_sub(start)
+}
+
+/// "mm:ss:ff", with minutes allowed past 99 for overlong positions.
+pub fn format_msf(sectors: u32) -> String {
+    let frames = sectors % SECTORS_PER_SECOND;
+    let seconds = sectors / SECTORS_PER_SECOND;
+    format!("{:02}:{:02}:{:02}", seconds / 60, seconds % 60, frames)
+}
+
+pub fn msf_to_sectors(minutes: u32, seconds: u32, frames: u32) -> Result<u32, String> {
+    if seconds >= 60 {
+        return Err(format!("seconds must be below 60, got {}", seconds));
+    }
+    if frames >= SECTORS_PER_SECOND {
+        return Err(format!(
+            "frames must be below {}, got {}",
+            SECTORS_PER_SECOND, frames
+        ));
+    }
+    minutes
+        .checked_mul(60)
+        .and_then(|s| s.checked_add(seconds))
+        .and_then(|s| s.checked_mul(SECTORS_PER_SECOND))
+        .and_then(|s| s.checked_add(frames))
+        .ok_or_else(|| format!("{} minutes is out of range", minutes))
+}
+
+/// Parse "mm:ss:ff"; EAC's "mm:ss.ff" TOC notation is accepted too.
+pub fn parse_msf(text: &str) -> Result<u32, String> {
+    let invalid = || format!("'{}' is not in mm:ss:ff form", text);
+    let (minutes, rest) = text.trim().split_once(':').ok_or_else(invalid)?;
+    let (seconds, frames) = rest
+        .split_once(':')
+        .or_else(|| rest.split_once('.'))
+        .ok_or_else(invalid)?;
+    let number = |part: &str| part.parse::<u32>().map_err(|_| invalid());
+    msf_to_sectors(number(minutes)?, number(seconds)?, number(frames)?)
+}
+
+/// An error range placed on the disc.
+pub struct DiscRange {
+    pub track: u8,
+    pub kind: ErrorKind,
+    /// The range as the ripper reported it, relative to its track.
+    pub range: TrackErrorRange,
+    pub start_sector: u32,
+    /// Inclusive. A range with no length still covers the sector it is in.
+    pub end_sector: u32,
+}
+
+/// Every error range of a log in absolute disc sectors, in track order.
+///
+/// Ripper ranges count from the start of their track, or from the start of
+/// the disc for range rips. Tracks that are not in the TOC are left out
+/// since there is nothing to place them against.
+pub fn disc_ranges(log: &ParsedLog) -> PyResult<Vec<DiscRange>> {
+    let entries = &log.toc.raw.entries;
+    let mut out = Vec::new();
+    for track in &log.tracks {
+        let base = if track.is_range {
+            entries.first()
+        } else {
+            entries
+                .iter()
+                .find(|entry| entry.track == u32::from(track.num))
+        };
+        let Some(base) = base.map(|entry| entry.start_sector) else {
+            continue;
+        };
+        for (kind, data) in error_kind::each(&track.errors) {
+            for range in &data.ranges {
+                let (start, length) = range_sectors(range)?;
+                let start_sector = base.saturating_add(start);
+                out.push(DiscRange {
+                    track: track.num,
+                    kind,
+                    range: range.clone(),
+                    start_sector,
+                    end_sector: start_sector.saturating_add(length.max(1) - 1),
+                });
+            }
+        }
+    }
+    Ok(out)
+}
+
+pub fn range_sectors(range: &TrackErrorRange) -> PyResult<(u32, u32)> {
+    Ok((sectors(&range.start)?, sectors(&range.length)?))
+}
@@ -16,13 +16,50 @@ use cambia_core::{
     util::Time,
 };
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict};
 use pythonize::pythonize;
+use std::collections::BTreeMap;
+
+use crate::accuraterip::{self, Consistency, Outcome, Summary};
+use crate::build;
+use crate::coverage::{self, Coverage, HTOA_MIN_SECTORS};
+use crate::ctdb::{self, Ctdb};
+use crate::disc_id;
+use crate::disc_info::{DiscInfo, Isrc, Mcn};
+use crate::encoding::Detection;
+use crate::error_kind::ErrorKind;
+use crate::error_map;
+use crate::errors;
+use crate::htoa;
+use crate::layout::{self, Layout, TrackType};
+use crate::log_data::{LogData, LogEntry};
+use crate::position;
+use crate::range;
+use crate::remediation::{self, Remediation};
+use crate::render;
+use crate::replace;
+use crate::scores;
+use crate::shared::Shared;
+use crate::sniff::Sniff;
+use crate::translate::Translation;
+
 /// Convert a Time value to std::time::Duration via serde serialization.
 /// Time serializes as f64 seconds, which we use to reconstruct a Duration.
-fn time_to_duration(time: &Time) -> std::time::Duration {
-    let secs = serde_json::to_value(time).unwrap().as_f64().unwrap();
-    std::time::Duration::from_secs_f64(secs)
+/// Values that are not a valid duration (negative, NaN or out of range)
+/// raise `InternalError`, since neither cambia-core nor the constructors
+/// should make one.
+pub fn time_to_duration(time: &Time) -> PyResult<std::time::Duration> {
+    let value = serde_json::to_value(time).ok();
+    value
+        .as_ref()
+        .and_then(serde_json::Value::as_f64)
+        .and_then(|secs| std::time::Duration::try_from_secs_f64(secs).ok())
+        .ok_or_else(|| {
+            errors::InternalError::new_err(format!(
+                "Cannot convert the time {} to a duration",
+                value.map_or_else(|| "?".to_string(), |value| value.to_string())
+            ))
+        })
 }
 
 // ============= Enums =============
@@ -65,19 +102,7 @@ impl PyRipper {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyRipper::EAC => Ripper::EAC,
-            PyRipper::XLD => Ripper::XLD,
-            PyRipper::Whipper => Ripper::Whipper,
-            PyRipper::CueRipper => Ripper::CueRipper,
-            PyRipper::DBPA => Ripper::DBPA,
-            PyRipper::CyanRip => Ripper::CyanRip,
-            PyRipper::EZCD => Ripper::EZCD,
-            PyRipper::Morituri => Ripper::Morituri,
-            PyRipper::Rip => Ripper::Rip,
-            PyRipper::FreAc => Ripper::FreAc,
-            PyRipper::Other => Ripper::Other,
-        };
+        let rust_enum = Ripper::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -106,6 +131,24 @@ impl From<&Ripper> for PyRipper {
     }
 }
 
+impl From<&PyRipper> for Ripper {
+    fn from(ripper: &PyRipper) -> Self {
+        match ripper {
+            PyRipper::EAC => Ripper::EAC,
+            PyRipper::XLD => Ripper::XLD,
+            PyRipper::Whipper => Ripper::Whipper,
+            PyRipper::CueRipper => Ripper::CueRipper,
+            PyRipper::DBPA => Ripper::DBPA,
+            PyRipper::CyanRip => Ripper::CyanRip,
+            PyRipper::EZCD => Ripper::EZCD,
+            PyRipper::Morituri => Ripper::Morituri,
+            PyRipper::Rip => Ripper::Rip,
+            PyRipper::FreAc => Ripper::FreAc,
+            PyRipper::Other => Ripper::Other,
+        }
+    }
+}
+
 #[pyclass(name = "MediaType", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyMediaType {
@@ -130,12 +173,7 @@ impl PyMediaType {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyMediaType::Pressed => MediaType::Pressed,
-            PyMediaType::CDR => MediaType::CDR,
-            PyMediaType::Other => MediaType::Other,
-            PyMediaType::Unknown => MediaType::Unknown,
-        };
+        let rust_enum = MediaType::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -157,6 +195,17 @@ impl From<&MediaType> for PyMediaType {
     }
 }
 
+impl From<&PyMediaType> for MediaType {
+    fn from(media: &PyMediaType) -> Self {
+        match media {
+            PyMediaType::Pressed => MediaType::Pressed,
+            PyMediaType::CDR => MediaType::CDR,
+            PyMediaType::Other => MediaType::Other,
+            PyMediaType::Unknown => MediaType::Unknown,
+        }
+    }
+}
+
 #[pyclass(name = "Quartet", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyQuartet {
@@ -208,6 +257,17 @@ impl From<&Quartet> for PyQuartet {
     }
 }
 
+impl From<&PyQuartet> for Quartet {
+    fn from(q: &PyQuartet) -> Self {
+        match q {
+            PyQuartet::True => Quartet::True,
+            PyQuartet::False => Quartet::False,
+            PyQuartet::Unknown => Quartet::Unknown,
+            PyQuartet::Unsupported => Quartet::Unsupported,
+        }
+    }
+}
+
 #[pyclass(name = "ReadMode", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyReadMode {
@@ -234,13 +294,7 @@ impl PyReadMode {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyReadMode::Secure => ReadMode::Secure,
-            PyReadMode::Paranoid => ReadMode::Paranoid,
-            PyReadMode::Fast => ReadMode::Fast,
-            PyReadMode::Burst => ReadMode::Burst,
-            PyReadMode::Unknown => ReadMode::Unknown,
-        };
+        let rust_enum = ReadMode::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -263,6 +317,18 @@ impl From<&ReadMode> for PyReadMode {
     }
 }
 
+impl From<&PyReadMode> for ReadMode {
+    fn from(mode: &PyReadMode) -> Self {
+        match mode {
+            PyReadMode::Secure => ReadMode::Secure,
+            PyReadMode::Paranoid => ReadMode::Paranoid,
+            PyReadMode::Fast => ReadMode::Fast,
+            PyReadMode::Burst => ReadMode::Burst,
+            PyReadMode::Unknown => ReadMode::Unknown,
+        }
+    }
+}
+
 #[pyclass(name = "Gap", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyGap {
@@ -293,15 +359,7 @@ impl PyGap {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyGap::Append => Gap::Append,
-            PyGap::AppendNoHtoa => Gap::AppendNoHtoa,
-            PyGap::AppendUndetected => Gap::AppendUndetected,
-            PyGap::Prepend => Gap::Prepend,
-            PyGap::Discard => Gap::Discard,
-            PyGap::Unknown => Gap::Unknown,
-            PyGap::Inapplicable => Gap::Inapplicable,
-        };
+        let rust_enum = Gap::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -326,6 +384,20 @@ impl From<&Gap> for PyGap {
     }
 }
 
+impl From<&PyGap> for Gap {
+    fn from(gap: &PyGap) -> Self {
+        match gap {
+            PyGap::Append => Gap::Append,
+            PyGap::AppendNoHtoa => Gap::AppendNoHtoa,
+            PyGap::AppendUndetected => Gap::AppendUndetected,
+            PyGap::Prepend => Gap::Prepend,
+            PyGap::Discard => Gap::Discard,
+            PyGap::Unknown => Gap::Unknown,
+            PyGap::Inapplicable => Gap::Inapplicable,
+        }
+    }
+}
+
 #[pyclass(name = "Integrity", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyIntegrity {
@@ -348,11 +420,7 @@ impl PyIntegrity {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyIntegrity::Match => Integrity::Match,
-            PyIntegrity::Mismatch => Integrity::Mismatch,
-            PyIntegrity::Unknown => Integrity::Unknown,
-        };
+        let rust_enum = Integrity::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -373,6 +441,16 @@ impl From<&Integrity> for PyIntegrity {
     }
 }
 
+impl From<&PyIntegrity> for Integrity {
+    fn from(integrity: &PyIntegrity) -> Self {
+        match integrity {
+            PyIntegrity::Match => Integrity::Match,
+            PyIntegrity::Mismatch => Integrity::Mismatch,
+            PyIntegrity::Unknown => Integrity::Unknown,
+        }
+    }
+}
+
 #[pyclass(name = "AccurateRipStatus", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyAccurateRipStatus {
@@ -399,13 +477,7 @@ impl PyAccurateRipStatus {
     #[getter]
     fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
         // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyAccurateRipStatus::Match => AccurateRipStatus::Match,
-            PyAccurateRipStatus::Mismatch => AccurateRipStatus::Mismatch,
-            PyAccurateRipStatus::Offsetted => AccurateRipStatus::Offsetted,
-            PyAccurateRipStatus::NotFound => AccurateRipStatus::NotFound,
-            PyAccurateRipStatus::Disabled => AccurateRipStatus::Disabled,
-        };
+        let rust_enum = AccurateRipStatus::from(self);
         Ok(pythonize(py, &rust_enum)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
             .unbind())
@@ -428,6 +500,18 @@ impl From<&AccurateRipStatus> for PyAccurateRipStatus {
     }
 }
 
+impl From<&PyAccurateRipStatus> for AccurateRipStatus {
+    fn from(status: &PyAccurateRipStatus) -> Self {
+        match status {
+            PyAccurateRipStatus::Match => AccurateRipStatus::Match,
+            PyAccurateRipStatus::Mismatch => AccurateRipStatus::Mismatch,
+            PyAccurateRipStatus::Offsetted => AccurateRipStatus::Offsetted,
+            PyAccurateRipStatus::NotFound => AccurateRipStatus::NotFound,
+            PyAccurateRipStatus::Disabled => AccurateRipStatus::Disabled,
+        }
+    }
+}
+
 #[pyclass(name = "EvaluatorType", eq, eq_int)]
 #[derive(Clone, PartialEq)]
 pub enum PyEvaluatorType {
@@ -475,1048 +559,4140 @@ impl From<&EvaluatorType> for PyEvaluatorType {
     }
 }
 
-// ============= TOC Classes =============
-
-#[pyclass(name = "TocEntry")]
-#[derive(Clone)]
-pub struct PyTocEntry {
-    #[pyo3(get)]
-    pub track: u32,
-    #[pyo3(get)]
-    pub start: std::time::Duration,
-    #[pyo3(get)]
-    pub length: std::time::Duration,
-    #[pyo3(get)]
-    pub start_sector: u32,
-    #[pyo3(get)]
-    pub end_sector: u32,
+#[pyclass(name = "TrackType", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyTrackType {
+    Audio,
+    Data,
 }
 
-impl From<&TocEntry> for PyTocEntry {
-    fn from(entry: &TocEntry) -> Self {
-        PyTocEntry {
-            track: entry.track,
-            start: time_to_duration(&entry.start),
-            length: time_to_duration(&entry.length),
-            start_sector: entry.start_sector,
-            end_sector: entry.end_sector,
+#[pymethods]
+impl PyTrackType {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyTrackType::Audio => "Audio",
+            PyTrackType::Data => "Data",
         }
     }
-}
 
-#[pymethods]
-impl PyTocEntry {
-    fn __repr__(&self) -> String {
-        format!(
-            "<TocEntry track={} start_sector={} end_sector={}>",
-            self.track, self.start_sector, self.end_sector
-        )
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
     }
-}
 
-#[pyclass(name = "TocHash")]
-#[derive(Clone)]
-pub struct PyTocHash {
-    #[pyo3(get)]
-    pub hash: String,
-    #[pyo3(get)]
-    pub url: String,
+    fn __repr__(&self) -> String {
+        format!("<TrackType.{}>", self.name())
+    }
 }
 
-impl From<&TocHash> for PyTocHash {
-    fn from(hash: &TocHash) -> Self {
-        PyTocHash {
-            hash: hash.hash.clone(),
-            url: hash.url.clone(),
+impl From<TrackType> for PyTrackType {
+    fn from(track_type: TrackType) -> Self {
+        match track_type {
+            TrackType::Audio => PyTrackType::Audio,
+            TrackType::Data => PyTrackType::Data,
         }
     }
 }
 
+#[pyclass(name = "DiscLayout", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyDiscLayout {
+    Plain,
+    Enhanced,
+    MixedMode,
+}
+
 #[pymethods]
-impl PyTocHash {
-    fn __repr__(&self) -> String {
-        format!("<TocHash hash='{}'>", self.hash)
+impl PyDiscLayout {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyDiscLayout::Plain => "Plain",
+            PyDiscLayout::Enhanced => "Enhanced",
+            PyDiscLayout::MixedMode => "MixedMode",
+        }
     }
-}
 
-#[pyclass(name = "TocRaw")]
-#[derive(Clone)]
-pub struct PyTocRaw {
-    #[pyo3(get)]
-    pub entries: Vec<PyTocEntry>,
-}
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
 
-impl From<&TocRaw> for PyTocRaw {
-    fn from(raw: &TocRaw) -> Self {
-        PyTocRaw {
-            entries: raw.entries.iter().map(PyTocEntry::from).collect(),
-        }
+    fn __repr__(&self) -> String {
+        format!("<DiscLayout.{}>", self.name())
     }
 }
 
-#[pymethods]
-impl PyTocRaw {
-    fn __repr__(&self) -> String {
-        format!("<TocRaw entries={}>", self.entries.len())
+impl From<Layout> for PyDiscLayout {
+    fn from(layout: Layout) -> Self {
+        match layout {
+            Layout::Plain => PyDiscLayout::Plain,
+            Layout::Enhanced => PyDiscLayout::Enhanced,
+            Layout::MixedMode => PyDiscLayout::MixedMode,
+        }
     }
 }
 
-#[pyclass(name = "Toc")]
-#[derive(Clone)]
-pub struct PyToc {
-    #[pyo3(get)]
-    pub raw: PyTocRaw,
-    #[pyo3(get)]
-    pub freedb: PyTocHash,
-    #[pyo3(get)]
-    pub accurip_tocid: PyTocHash,
-    #[pyo3(get)]
-    pub ctdb_tocid: PyTocHash,
-    #[pyo3(get)]
-    pub mbz: PyTocHash,
-    #[pyo3(get)]
-    pub gn: PyTocHash,
-    #[pyo3(get)]
-    pub mcdi: PyTocHash,
+#[pyclass(name = "AccurateRipOffsetKind", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyAccurateRipOffsetKind {
+    Same,
+    Different,
 }
 
-impl From<&Toc> for PyToc {
-    fn from(toc: &Toc) -> Self {
-        PyToc {
-            raw: PyTocRaw::from(&toc.raw),
-            freedb: PyTocHash::from(&toc.freedb),
-            accurip_tocid: PyTocHash::from(&toc.accurip_tocid),
-            ctdb_tocid: PyTocHash::from(&toc.ctdb_tocid),
-            mbz: PyTocHash::from(&toc.mbz),
-            gn: PyTocHash::from(&toc.gn),
-            mcdi: PyTocHash::from(&toc.mcdi),
+#[pymethods]
+impl PyAccurateRipOffsetKind {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyAccurateRipOffsetKind::Same => "Same",
+            PyAccurateRipOffsetKind::Different => "Different",
         }
     }
-}
 
-#[pymethods]
-impl PyToc {
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
+
     fn __repr__(&self) -> String {
-        format!("<Toc entries={}>", self.raw.entries.len())
+        format!("<AccurateRipOffsetKind.{}>", self.name())
     }
 }
 
-// ============= Checksum and Integrity =============
-
-#[pyclass(name = "Checksum")]
-#[derive(Clone)]
-pub struct PyChecksum {
-    #[pyo3(get)]
-    pub calculated: String,
-    #[pyo3(get)]
-    pub log: String,
-    #[pyo3(get)]
-    pub integrity: PyIntegrity,
+#[pyclass(name = "AccurateRipTotalKind", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyAccurateRipTotalKind {
+    /// Submissions of all AccurateRip versions.
+    All,
+    /// Submissions of the version of the result only.
+    Version,
 }
 
-impl From<&Checksum> for PyChecksum {
-    fn from(checksum: &Checksum) -> Self {
-        PyChecksum {
-            calculated: checksum.calculated.clone(),
-            log: checksum.log.clone(),
-            integrity: PyIntegrity::from(&checksum.integrity),
+#[pymethods]
+impl PyAccurateRipTotalKind {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyAccurateRipTotalKind::All => "All",
+            PyAccurateRipTotalKind::Version => "Version",
         }
     }
-}
 
-#[pymethods]
-impl PyChecksum {
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
+
     fn __repr__(&self) -> String {
-        format!("<Checksum integrity={}>", self.integrity.name())
+        format!("<AccurateRipTotalKind.{}>", self.name())
     }
 }
 
-// ============= Release Info =============
-
-#[pyclass(name = "ReleaseInfo")]
-#[derive(Clone)]
-pub struct PyReleaseInfo {
-    #[pyo3(get)]
-    pub artist: String,
-    #[pyo3(get)]
-    pub title: String,
+#[pyclass(name = "CtdbStatus", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyCtdbStatus {
+    Verified,
+    Differs,
+    NoMatch,
+    NotFound,
+    Unknown,
 }
 
-impl From<&ReleaseInfo> for PyReleaseInfo {
-    fn from(info: &ReleaseInfo) -> Self {
-        PyReleaseInfo {
-            artist: info.artist.clone(),
-            title: info.title.clone(),
+#[pymethods]
+impl PyCtdbStatus {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyCtdbStatus::Verified => "Verified",
+            PyCtdbStatus::Differs => "Differs",
+            PyCtdbStatus::NoMatch => "NoMatch",
+            PyCtdbStatus::NotFound => "NotFound",
+            PyCtdbStatus::Unknown => "Unknown",
         }
     }
-}
 
-#[pymethods]
-impl PyReleaseInfo {
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
+
     fn __repr__(&self) -> String {
-        format!(
-            "<ReleaseInfo artist='{}' title='{}'>",
-            self.artist, self.title
-        )
+        format!("<CtdbStatus.{}>", self.name())
     }
 }
 
-// ============= Track Classes =============
-
-#[pyclass(name = "TrackErrorRange")]
-#[derive(Clone)]
-pub struct PyTrackErrorRange {
-    #[pyo3(get)]
-    pub start: std::time::Duration,
-    #[pyo3(get)]
-    pub length: std::time::Duration,
+impl From<ctdb::Status> for PyCtdbStatus {
+    fn from(status: ctdb::Status) -> Self {
+        match status {
+            ctdb::Status::Verified => PyCtdbStatus::Verified,
+            ctdb::Status::Differs => PyCtdbStatus::Differs,
+            ctdb::Status::NoMatch => PyCtdbStatus::NoMatch,
+            ctdb::Status::NotFound => PyCtdbStatus::NotFound,
+            ctdb::Status::Unknown => PyCtdbStatus::Unknown,
+        }
+    }
 }
 
-impl From<&TrackErrorRange> for PyTrackErrorRange {
-    fn from(range: &TrackErrorRange) -> Self {
-        PyTrackErrorRange {
-            start: time_to_duration(&range.start),
-            length: time_to_duration(&range.length),
+impl From<&PyCtdbStatus> for ctdb::Status {
+    fn from(status: &PyCtdbStatus) -> Self {
+        match status {
+            PyCtdbStatus::Verified => ctdb::Status::Verified,
+            PyCtdbStatus::Differs => ctdb::Status::Differs,
+            PyCtdbStatus::NoMatch => ctdb::Status::NoMatch,
+            PyCtdbStatus::NotFound => ctdb::Status::NotFound,
+            PyCtdbStatus::Unknown => ctdb::Status::Unknown,
         }
     }
 }
 
+/// A kind of track error. The value is the name of its `TrackError`
+/// attribute, as `ParsedLog.replace()` also takes it.
+#[pyclass(name = "ErrorKind", eq, eq_int, hash, frozen)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum PyErrorKind {
+    Read,
+    Skip,
+    JitterGeneric,
+    JitterEdge,
+    JitterAtom,
+    Drift,
+    Dropped,
+    Duplicated,
+    DamagedSectors,
+    InconsistentErrorSectors,
+    MissingSamples,
+}
+
 #[pymethods]
-impl PyTrackErrorRange {
-    fn __repr__(&self) -> String {
-        format!(
-            "<TrackErrorRange start={:?} length={:?}>",
-            self.start, self.length
-        )
+impl PyErrorKind {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyErrorKind::Read => "Read",
+            PyErrorKind::Skip => "Skip",
+            PyErrorKind::JitterGeneric => "JitterGeneric",
+            PyErrorKind::JitterEdge => "JitterEdge",
+            PyErrorKind::JitterAtom => "JitterAtom",
+            PyErrorKind::Drift => "Drift",
+            PyErrorKind::Dropped => "Dropped",
+            PyErrorKind::Duplicated => "Duplicated",
+            PyErrorKind::DamagedSectors => "DamagedSectors",
+            PyErrorKind::InconsistentErrorSectors => "InconsistentErrorSectors",
+            PyErrorKind::MissingSamples => "MissingSamples",
+        }
     }
-}
 
-#[pyclass(name = "TrackErrorData")]
-#[derive(Clone)]
-pub struct PyTrackErrorData {
-    #[pyo3(get)]
-    pub count: u32,
-    #[pyo3(get)]
-    pub ranges: Vec<PyTrackErrorRange>,
+    #[getter]
+    fn value(&self) -> &str {
+        ErrorKind::from(self).key()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<ErrorKind.{}>", self.name())
+    }
 }
 
-impl From<&TrackErrorData> for PyTrackErrorData {
-    fn from(data: &TrackErrorData) -> Self {
-        PyTrackErrorData {
-            count: data.count,
-            ranges: data.ranges.iter().map(PyTrackErrorRange::from).collect(),
+impl From<ErrorKind> for PyErrorKind {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::Read => PyErrorKind::Read,
+            ErrorKind::Skip => PyErrorKind::Skip,
+            ErrorKind::JitterGeneric => PyErrorKind::JitterGeneric,
+            ErrorKind::JitterEdge => PyErrorKind::JitterEdge,
+            ErrorKind::JitterAtom => PyErrorKind::JitterAtom,
+            ErrorKind::Drift => PyErrorKind::Drift,
+            ErrorKind::Dropped => PyErrorKind::Dropped,
+            ErrorKind::Duplicated => PyErrorKind::Duplicated,
+            ErrorKind::DamagedSectors => PyErrorKind::DamagedSectors,
+            ErrorKind::InconsistentErrorSectors => PyErrorKind::InconsistentErrorSectors,
+            ErrorKind::MissingSamples => PyErrorKind::MissingSamples,
         }
     }
 }
 
-#[pymethods]
-impl PyTrackErrorData {
-    fn __repr__(&self) -> String {
-        format!("<TrackErrorData count={}>", self.count)
+impl From<&PyErrorKind> for ErrorKind {
+    fn from(kind: &PyErrorKind) -> Self {
+        match kind {
+            PyErrorKind::Read => ErrorKind::Read,
+            PyErrorKind::Skip => ErrorKind::Skip,
+            PyErrorKind::JitterGeneric => ErrorKind::JitterGeneric,
+            PyErrorKind::JitterEdge => ErrorKind::JitterEdge,
+            PyErrorKind::JitterAtom => ErrorKind::JitterAtom,
+            PyErrorKind::Drift => ErrorKind::Drift,
+            PyErrorKind::Dropped => ErrorKind::Dropped,
+            PyErrorKind::Duplicated => ErrorKind::Duplicated,
+            PyErrorKind::DamagedSectors => ErrorKind::DamagedSectors,
+            PyErrorKind::InconsistentErrorSectors => ErrorKind::InconsistentErrorSectors,
+            PyErrorKind::MissingSamples => ErrorKind::MissingSamples,
+        }
     }
 }
 
-#[pyclass(name = "TrackError")]
-#[derive(Clone)]
-pub struct PyTrackError {
-    #[pyo3(get)]
-    pub read: PyTrackErrorData,
-    #[pyo3(get)]
-    pub skip: PyTrackErrorData,
-    #[pyo3(get)]
-    pub jitter_generic: PyTrackErrorData,
-    #[pyo3(get)]
-    pub jitter_edge: PyTrackErrorData,
-    #[pyo3(get)]
-    pub jitter_atom: PyTrackErrorData,
-    #[pyo3(get)]
-    pub drift: PyTrackErrorData,
-    #[pyo3(get)]
-    pub dropped: PyTrackErrorData,
-    #[pyo3(get)]
-    pub duplicated: PyTrackErrorData,
-    #[pyo3(get)]
-    pub damaged_sectors: PyTrackErrorData,
-    #[pyo3(get)]
-    pub inconsistent_err_sectors: PyTrackErrorData,
-    #[pyo3(get)]
-    pub missing_samples: PyTrackErrorData,
+#[pyclass(name = "HtoaExtraction", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyHtoaExtraction {
+    Track,
+    Range,
 }
 
-impl From<&TrackError> for PyTrackError {
-    fn from(error: &TrackError) -> Self {
-        PyTrackError {
-            read: PyTrackErrorData::from(&error.read),
-            skip: PyTrackErrorData::from(&error.skip),
-            jitter_generic: PyTrackErrorData::from(&error.jitter_generic),
-            jitter_edge: PyTrackErrorData::from(&error.jitter_edge),
-            jitter_atom: PyTrackErrorData::from(&error.jitter_atom),
-            drift: PyTrackErrorData::from(&error.drift),
-            dropped: PyTrackErrorData::from(&error.dropped),
-            duplicated: PyTrackErrorData::from(&error.duplicated),
-            damaged_sectors: PyTrackErrorData::from(&error.damaged_sectors),
-            inconsistent_err_sectors: PyTrackErrorData::from(&error.inconsistent_err_sectors),
-            missing_samples: PyTrackErrorData::from(&error.missing_samples),
+#[pymethods]
+impl PyHtoaExtraction {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyHtoaExtraction::Track => "Track",
+            PyHtoaExtraction::Range => "Range",
         }
     }
-}
 
-#[pymethods]
-impl PyTrackError {
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
+
     fn __repr__(&self) -> String {
-        format!(
-            "<TrackError read={} skip={} jitter={}>",
-            self.read.count, self.skip.count, self.jitter_generic.count
-        )
+        format!("<HtoaExtraction.{}>", self.name())
     }
 }
 
-#[pyclass(name = "AccurateRipConfidence")]
-#[derive(Clone)]
-pub struct PyAccurateRipConfidence {
-    #[pyo3(get)]
-    pub matching: Option<u32>,
-    #[pyo3(get)]
-    pub total: Option<u32>,
-    #[pyo3(get)]
-    pub offset: String,
+impl From<&htoa::Extraction> for PyHtoaExtraction {
+    fn from(extraction: &htoa::Extraction) -> Self {
+        match extraction {
+            htoa::Extraction::Track => PyHtoaExtraction::Track,
+            htoa::Extraction::Range => PyHtoaExtraction::Range,
+        }
+    }
 }
 
-impl From<&AccurateRipConfidence> for PyAccurateRipConfidence {
-    fn from(conf: &AccurateRipConfidence) -> Self {
-        let total = match conf.total {
-            Some(AccurateRipConfidenceTotal::All(n)) => Some(n),
-            Some(AccurateRipConfidenceTotal::Version(n)) => Some(n),
-            None => None,
-        };
+// ============= Position Classes =============
 
-        let offset = match conf.offset {
-            AccurateRipOffset::Same => "Same".to_string(),
-            AccurateRipOffset::Different(Some(n)) => format!("Different ({})", n),
-            AccurateRipOffset::Different(None) => "Different".to_string(),
-        };
+/// A CD position or length in minutes, seconds and frames, kept as a whole
+/// number of sectors so arithmetic is exact.
+#[pyclass(name = "MSF", frozen, eq, ord, hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PyMsf {
+    sectors: u32,
+}
 
-        PyAccurateRipConfidence {
-            matching: conf.matching,
-            total,
-            offset,
-        }
-    }
+/// The right-hand side of MSF arithmetic: another MSF or a sector count.
+#[derive(FromPyObject)]
+enum MsfOperand {
+    Msf(PyMsf),
+    Sectors(i64),
 }
 
-#[pymethods]
-impl PyAccurateRipConfidence {
-    fn __repr__(&self) -> String {
-        format!(
-            "<AccurateRipConfidence matching={:?} total={:?}>",
-            self.matching, self.total
-        )
+impl MsfOperand {
+    fn sectors(&self) -> i64 {
+        match self {
+            MsfOperand::Msf(msf) => i64::from(msf.sectors),
+            MsfOperand::Sectors(sectors) => *sectors,
+        }
     }
 }
 
-#[pyclass(name = "AccurateRipUnit")]
-#[derive(Clone)]
-pub struct PyAccurateRipUnit {
-    #[pyo3(get)]
-    pub status: PyAccurateRipStatus,
-    #[pyo3(get)]
-    pub confidence: Option<PyAccurateRipConfidence>,
-    #[pyo3(get)]
-    pub sign: String,
-    #[pyo3(get)]
-    pub version: Option<u8>,
-}
+impl PyMsf {
+    pub fn from_sectors(sectors: u32) -> Self {
+        PyMsf { sectors }
+    }
 
-impl From<&AccurateRipUnit> for PyAccurateRipUnit {
-    fn from(ar: &AccurateRipUnit) -> Self {
-        PyAccurateRipUnit {
-            status: PyAccurateRipStatus::from(&ar.status),
-            confidence: ar.confidence.as_ref().map(PyAccurateRipConfidence::from),
-            sign: ar.sign.clone(),
-            version: ar.version,
-        }
+    fn offset(&self, delta: i64) -> PyResult<Self> {
+        i64::from(self.sectors)
+            .checked_add(delta)
+            .and_then(|sectors| u32::try_from(sectors).ok())
+            .map(PyMsf::from_sectors)
+            .ok_or_else(|| build::error("MSF", "result is before the start of the disc"))
     }
 }
 
 #[pymethods]
-impl PyAccurateRipUnit {
-    fn __repr__(&self) -> String {
-        format!("<AccurateRipUnit status={}>", self.status.name())
+impl PyMsf {
+    #[new]
+    #[pyo3(signature = (minutes=0, seconds=0, frames=0))]
+    fn new(minutes: u32, seconds: u32, frames: u32) -> PyResult<Self> {
+        position::msf_to_sectors(minutes, seconds, frames)
+            .map(PyMsf::from_sectors)
+            .map_err(|e| build::error("MSF", e))
     }
-}
 
-#[pyclass(name = "TestAndCopy")]
-#[derive(Clone)]
-pub struct PyTestAndCopy {
-    #[pyo3(get)]
-    pub test_hash: String,
-    #[pyo3(get)]
-    pub copy_hash: String,
-    #[pyo3(get)]
-    pub integrity: PyIntegrity,
-}
+    #[staticmethod]
+    #[pyo3(name = "from_sectors")]
+    fn py_from_sectors(sectors: u32) -> Self {
+        PyMsf::from_sectors(sectors)
+    }
 
-impl From<&TestAndCopy> for PyTestAndCopy {
-    fn from(tc: &TestAndCopy) -> Self {
-        PyTestAndCopy {
-            test_hash: tc.test_hash.clone(),
-            copy_hash: tc.copy_hash.clone(),
-            integrity: PyIntegrity::from(&tc.integrity),
-        }
+    /// Parse "mm:ss:ff", or EAC's "mm:ss.ff".
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        position::parse_msf(text)
+            .map(PyMsf::from_sectors)
+            .map_err(|e| build::error("MSF", e))
     }
-}
 
-#[pymethods]
-impl PyTestAndCopy {
-    fn __repr__(&self) -> String {
-        format!("<TestAndCopy integrity={}>", self.integrity.name())
+    #[getter]
+    fn minutes(&self) -> u32 {
+        self.sectors / build::SECTORS_PER_SECOND / 60
     }
-}
 
-#[pyclass(name = "TrackEntry")]
-#[derive(Clone)]
-pub struct PyTrackEntry {
-    #[pyo3(get)]
-    pub num: u8,
-    #[pyo3(get)]
-    pub is_range: bool,
-    #[pyo3(get)]
-    pub aborted: bool,
-    #[pyo3(get)]
-    pub filenames: Vec<String>,
-    #[pyo3(get)]
-    pub peak_level: Option<f64>,
-    #[pyo3(get)]
-    pub pregap_length: Option<std::time::Duration>,
-    #[pyo3(get)]
-    pub extraction_speed: Option<f64>,
-    #[pyo3(get)]
-    pub gain: Option<f64>,
-    #[pyo3(get)]
-    pub preemphasis: Option<bool>,
-    #[pyo3(get)]
-    pub test_and_copy: PyTestAndCopy,
-    #[pyo3(get)]
-    pub errors: PyTrackError,
-    #[pyo3(get)]
-    pub ar_info: Vec<PyAccurateRipUnit>,
-}
+    #[getter]
+    fn seconds(&self) -> u32 {
+        self.sectors / build::SECTORS_PER_SECOND % 60
+    }
 
-impl From<&TrackEntry> for PyTrackEntry {
-    fn from(entry: &TrackEntry) -> Self {
-        PyTrackEntry {
-            num: entry.num,
-            is_range: entry.is_range,
-            aborted: entry.aborted,
-            filenames: entry.filenames.clone(),
-            peak_level: entry.peak_level,
-            pregap_length: entry.pregap_length.as_ref().map(time_to_duration),
-            extraction_speed: entry.extraction_speed,
-            gain: entry.gain,
-            preemphasis: entry.preemphasis,
-            test_and_copy: PyTestAndCopy::from(&entry.test_and_copy),
-            errors: PyTrackError::from(&entry.errors),
-            ar_info: entry.ar_info.iter().map(PyAccurateRipUnit::from).collect(),
-        }
+    #[getter]
+    fn frames(&self) -> u32 {
+        self.sectors % build::SECTORS_PER_SECOND
+    }
+
+    #[getter]
+    fn sectors(&self) -> u32 {
+        self.sectors
+    }
+
+    #[getter]
+    fn samples(&self) -> u64 {
+        u64::from(self.sectors) * position::SAMPLES_PER_SECTOR
+    }
+
+    /// Rounded to the nanosecond, the only inexact view of an MSF.
+    #[getter]
+    fn duration(&self) -> std::time::Duration {
+        let per_second = build::SECTORS_PER_SECOND;
+        std::time::Duration::from_secs(u64::from(self.sectors / per_second))
+            + std::time::Duration::from_nanos(
+                u64::from(self.sectors % per_second) * 1_000_000_000 / u64::from(per_second),
+            )
+    }
+
+    fn __add__(&self, other: MsfOperand) -> PyResult<Self> {
+        self.offset(other.sectors())
+    }
+
+    fn __radd__(&self, other: MsfOperand) -> PyResult<Self> {
+        self.offset(other.sectors())
+    }
+
+    fn __sub__(&self, other: MsfOperand) -> PyResult<Self> {
+        self.offset(-other.sectors())
+    }
+
+    fn __int__(&self) -> u32 {
+        self.sectors
+    }
+
+    fn __str__(&self) -> String {
+        position::format_msf(self.sectors)
     }
-}
 
-#[pymethods]
-impl PyTrackEntry {
     fn __repr__(&self) -> String {
-        format!("<TrackEntry num={} aborted={}>", self.num, self.aborted)
+        format!("<MSF {}>", position::format_msf(self.sectors))
     }
 }
 
-// ============= Parsed Log =============
+// ============= TOC Classes =============
 
-#[pyclass(name = "ParsedLog")]
+#[pyclass(name = "TocEntry", frozen)]
 #[derive(Clone)]
-pub struct PyParsedLog {
-    #[pyo3(get)]
-    pub ripper: PyRipper,
-    #[pyo3(get)]
-    pub ripper_version: String,
-    #[pyo3(get)]
-    pub release_info: PyReleaseInfo,
-    #[pyo3(get)]
-    pub language: String,
-    #[pyo3(get)]
-    pub read_offset: Option<i16>,
-    #[pyo3(get)]
-    pub combined_rw_offset: Option<i32>,
-    #[pyo3(get)]
-    pub drive: String,
-    #[pyo3(get)]
-    pub media_type: PyMediaType,
-    #[pyo3(get)]
-    pub accurate_stream: PyQuartet,
-    #[pyo3(get)]
-    pub defeat_audio_cache: PyQuartet,
-    #[pyo3(get)]
-    pub use_c2: PyQuartet,
-    #[pyo3(get)]
-    pub overread: PyQuartet,
-    #[pyo3(get)]
-    pub fill_silence: PyQuartet,
-    #[pyo3(get)]
-    pub delete_silence: PyQuartet,
-    #[pyo3(get)]
-    pub use_null_samples: PyQuartet,
-    #[pyo3(get)]
-    pub test_and_copy: PyQuartet,
-    #[pyo3(get)]
-    pub normalize: PyQuartet,
-    #[pyo3(get)]
-    pub read_mode: PyReadMode,
-    #[pyo3(get)]
-    pub gap_handling: PyGap,
-    #[pyo3(get)]
-    pub checksum: PyChecksum,
-    #[pyo3(get)]
-    pub toc: PyToc,
-    #[pyo3(get)]
-    pub tracks: Vec<PyTrackEntry>,
-    #[pyo3(get)]
-    pub id3_enabled: PyQuartet,
-    #[pyo3(get)]
-    pub audio_encoder: Vec<String>,
+pub struct PyTocEntry {
+    inner: Shared<TocEntry>,
+    // Where the entry sits on the disc, which only the whole TOC tells
+    track_type: TrackType,
+    session: u8,
 }
 
-impl PyParsedLog {
-    pub fn from_log(log: &ParsedLog) -> Self {
-        let tracks = log.tracks.iter().map(PyTrackEntry::from).collect();
-
-        PyParsedLog {
-            ripper: PyRipper::from(&log.ripper),
-            ripper_version: log.ripper_version.clone(),
-            release_info: PyReleaseInfo::from(&log.release_info),
-            language: log.language.clone(),
-            read_offset: log.read_offset,
-            combined_rw_offset: log.combined_rw_offset,
-            drive: log.drive.clone(),
-            media_type: PyMediaType::from(&log.media_type),
-            accurate_stream: PyQuartet::from(&log.accurate_stream),
-            defeat_audio_cache: PyQuartet::from(&log.defeat_audio_cache),
-            use_c2: PyQuartet::from(&log.use_c2),
-            overread: PyQuartet::from(&log.overread),
-            fill_silence: PyQuartet::from(&log.fill_silence),
-            delete_silence: PyQuartet::from(&log.delete_silence),
-            use_null_samples: PyQuartet::from(&log.use_null_samples),
-            test_and_copy: PyQuartet::from(&log.test_and_copy),
-            normalize: PyQuartet::from(&log.normalize),
-            read_mode: PyReadMode::from(&log.read_mode),
-            gap_handling: PyGap::from(&log.gap_handling),
-            checksum: PyChecksum::from(&log.checksum),
-            toc: PyToc::from(&log.toc),
-            tracks,
-            id3_enabled: PyQuartet::from(&log.id3_enabled),
-            audio_encoder: log.audio_encoder.clone(),
+impl From<Shared<TocEntry>> for PyTocEntry {
+    fn from(inner: Shared<TocEntry>) -> Self {
+        PyTocEntry {
+            inner,
+            track_type: TrackType::Audio,
+            session: 1,
         }
     }
 }
 
 #[pymethods]
-impl PyParsedLog {
-    fn __repr__(&self) -> String {
-        format!(
-            "<ParsedLog ripper={} tracks={}>",
-            self.ripper.name(),
-            self.tracks.len()
-        )
+impl PyTocEntry {
+    /// Start and length default to the times the sectors span.
+    #[new]
+    #[pyo3(signature = (track, start_sector, end_sector, *, start=None, length=None))]
+    fn new(
+        track: u32,
+        start_sector: u32,
+        end_sector: u32,
+        start: Option<std::time::Duration>,
+        length: Option<std::time::Duration>,
+    ) -> PyResult<Self> {
+        build::check_track("TocEntry", track)?;
+        if end_sector > build::MAX_SECTOR {
+            return Err(build::error(
+                "TocEntry",
+                format!("end_sector {} is above {}", end_sector, build::MAX_SECTOR),
+            ));
+        }
+        if end_sector < start_sector {
+            return Err(build::error(
+                "TocEntry",
+                format!(
+                    "end_sector {} is before start_sector {}",
+                    end_sector, start_sector
+                ),
+            ));
+        }
+        Ok(PyTocEntry::from(Shared::new(TocEntry {
+            track,
+            start: match start {
+                Some(start) => build::time(start)?,
+                None => build::sectors_to_time(start_sector)?,
+            },
+            length: match length {
+                Some(length) => build::time(length)?,
+                None => build::sectors_to_time(position::span(start_sector, end_sector))?,
+            },
+            start_sector,
+            end_sector,
+        })))
     }
-}
 
-// ============= Parsed Combined =============
+    #[getter]
+    fn track(&self) -> u32 {
+        self.inner.track
+    }
 
-#[pyclass(name = "ParsedLogCombined")]
-#[derive(Clone)]
-pub struct PyParsedLogCombined {
-    #[pyo3(get)]
-    pub encoding: String,
-    #[pyo3(get)]
-    pub parsed_logs: Vec<PyParsedLog>,
-}
+    #[getter]
+    fn start(&self) -> PyResult<std::time::Duration> {
+        time_to_duration(&self.inner.start)
+    }
 
-impl PyParsedLogCombined {
-    pub fn from_combined(combined: &ParsedLogCombined) -> Self {
-        let parsed_logs = combined
-            .parsed_logs
-            .iter()
-            .map(|log| PyParsedLog::from_log(log))
-            .collect();
+    #[getter]
+    fn length(&self) -> PyResult<std::time::Duration> {
+        time_to_duration(&self.inner.length)
+    }
 
-        PyParsedLogCombined {
-            encoding: combined.encoding.clone(),
-            parsed_logs,
-        }
+    #[getter]
+    fn start_sector(&self) -> u32 {
+        self.inner.start_sector
+    }
+
+    #[getter]
+    fn end_sector(&self) -> u32 {
+        self.inner.end_sector
+    }
+
+    #[getter]
+    fn length_sectors(&self) -> u32 {
+        position::span(self.inner.start_sector, self.inner.end_sector)
+    }
+
+    #[getter]
+    fn start_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.inner.start_sector)
+    }
+
+    #[getter]
+    fn length_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.length_sectors())
+    }
+
+    #[getter]
+    fn start_sample(&self) -> u64 {
+        u64::from(self.inner.start_sector) * position::SAMPLES_PER_SECTOR
+    }
+
+    /// Audio or data, from the layout of the TOC the entry came from. An
+    /// entry on its own is taken to be audio.
+    #[getter]
+    fn track_type(&self) -> PyTrackType {
+        PyTrackType::from(self.track_type)
+    }
+
+    #[getter]
+    fn session(&self) -> u8 {
+        self.session
+    }
+
+    #[getter]
+    fn length_samples(&self) -> u64 {
+        u64::from(self.length_sectors()) * position::SAMPLES_PER_SECTOR
     }
-}
 
-#[pymethods]
-impl PyParsedLogCombined {
     fn __repr__(&self) -> String {
         format!(
-            "<ParsedLogCombined encoding='{}' logs={}>",
-            self.encoding,
-            self.parsed_logs.len()
+            "<TocEntry track={} start_sector={} end_sector={}>",
+            self.inner.track, self.inner.start_sector, self.inner.end_sector
         )
     }
 }
 
-// ============= Evaluation Classes =============
+#[pyclass(name = "TocHash", frozen)]
+#[derive(Clone)]
+pub struct PyTocHash {
+    inner: Shared<TocHash>,
+}
 
-#[pyclass(name = "EvaluationUnitScope")]
-#[derive(Clone, PartialEq)]
-pub enum PyEvaluationUnitScope {
-    Release(),
-    Track(Option<u8>),
+impl From<Shared<TocHash>> for PyTocHash {
+    fn from(inner: Shared<TocHash>) -> Self {
+        PyTocHash { inner }
+    }
 }
 
 #[pymethods]
-impl PyEvaluationUnitScope {
-    #[getter]
-    fn name(&self) -> &str {
-        match self {
-            PyEvaluationUnitScope::Release() => "Release",
-            PyEvaluationUnitScope::Track(_) => "Track",
+impl PyTocHash {
+    #[new]
+    #[pyo3(signature = (hash=String::new(), url=String::new()))]
+    fn new(hash: String, url: String) -> Self {
+        PyTocHash {
+            inner: Shared::new(TocHash { hash, url }),
         }
     }
 
     #[getter]
-    fn value(&self) -> String {
-        match self {
-            PyEvaluationUnitScope::Release() => "Release".to_string(),
-            PyEvaluationUnitScope::Track(track_num) => {
-                if let Some(num) = track_num {
-                    format!("Track {}", num)
-                } else {
-                    "Track".to_string()
-                }
+    fn hash(&self) -> &str {
+        &self.inner.hash
+    }
+
+    #[getter]
+    fn url(&self) -> &str {
+        &self.inner.url
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TocHash hash='{}'>", self.inner.hash)
+    }
+}
+
+#[pyclass(name = "TocRaw", frozen)]
+#[derive(Clone)]
+pub struct PyTocRaw {
+    inner: Shared<TocRaw>,
+}
+
+impl From<Shared<TocRaw>> for PyTocRaw {
+    fn from(inner: Shared<TocRaw>) -> Self {
+        PyTocRaw { inner }
+    }
+}
+
+#[pymethods]
+impl PyTocRaw {
+    #[new]
+    #[pyo3(signature = (entries=Vec::new()))]
+    fn new(entries: Vec<PyTocEntry>) -> PyResult<Self> {
+        let entries: Vec<TocEntry> = entries
+            .iter()
+            .map(|entry| TocEntry::clone(&entry.inner))
+            .collect();
+        build::check_toc(&entries)?;
+        Ok(PyTocRaw {
+            inner: Shared::new(TocRaw { entries }),
+        })
+    }
+
+    #[getter]
+    fn entries(&self) -> Vec<PyTocEntry> {
+        self.inner
+            .map_each(|raw| &raw.entries)
+            .into_iter()
+            .zip(layout::tracks(&self.inner.entries))
+            .map(|(inner, (track_type, session))| PyTocEntry {
+                inner,
+                track_type,
+                session,
+            })
+            .collect()
+    }
+
+    #[getter]
+    fn disc_layout(&self) -> PyDiscLayout {
+        PyDiscLayout::from(layout::layout(&self.inner.entries))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TocRaw entries={}>", self.inner.entries.len())
+    }
+}
+
+#[pyclass(name = "Toc", frozen)]
+#[derive(Clone)]
+pub struct PyToc {
+    inner: Shared<Toc>,
+}
+
+impl From<Shared<Toc>> for PyToc {
+    fn from(inner: Shared<Toc>) -> Self {
+        PyToc { inner }
+    }
+}
+
+#[pymethods]
+impl PyToc {
+    /// Disc IDs that are not given are computed from the entries, with
+    /// data tracks left out or kept as each service does.
+    #[new]
+    #[pyo3(signature = (
+        raw=None,
+        *,
+        freedb=None,
+        accurip_tocid=None,
+        ctdb_tocid=None,
+        mbz=None,
+        gn=None,
+        mcdi=None
+    ))]
+    fn new(
+        raw: Option<PyTocRaw>,
+        freedb: Option<PyTocHash>,
+        accurip_tocid: Option<PyTocHash>,
+        ctdb_tocid: Option<PyTocHash>,
+        mbz: Option<PyTocHash>,
+        gn: Option<PyTocHash>,
+        mcdi: Option<PyTocHash>,
+    ) -> Self {
+        let mut toc = build::empty_toc();
+        if let Some(raw) = raw {
+            toc.raw = TocRaw::clone(&raw.inner);
+        }
+        if let Some(ids) = disc_id::compute(&toc.raw.entries) {
+            ids.apply(&mut toc);
+        }
+        let set = |hash: &mut TocHash, given: Option<PyTocHash>| {
+            if let Some(given) = given {
+                *hash = TocHash::clone(&given.inner);
             }
+        };
+        set(&mut toc.freedb, freedb);
+        set(&mut toc.accurip_tocid, accurip_tocid);
+        set(&mut toc.ctdb_tocid, ctdb_tocid);
+        set(&mut toc.mbz, mbz);
+        set(&mut toc.gn, gn);
+        set(&mut toc.mcdi, mcdi);
+        PyToc {
+            inner: Shared::new(toc),
         }
     }
+
+    #[getter]
+    fn raw(&self) -> PyTocRaw {
+        PyTocRaw::from(self.inner.map(|toc| &toc.raw))
+    }
+
+    #[getter]
+    fn disc_layout(&self) -> PyDiscLayout {
+        PyDiscLayout::from(layout::layout(&self.inner.raw.entries))
+    }
+
+    #[getter]
+    fn freedb(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.freedb))
+    }
+
+    #[getter]
+    fn accurip_tocid(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.accurip_tocid))
+    }
+
+    #[getter]
+    fn ctdb_tocid(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.ctdb_tocid))
+    }
+
+    #[getter]
+    fn mbz(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.mbz))
+    }
+
+    #[getter]
+    fn gn(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.gn))
+    }
+
+    #[getter]
+    fn mcdi(&self) -> PyTocHash {
+        PyTocHash::from(self.inner.map(|toc| &toc.mcdi))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Toc entries={}>", self.inner.raw.entries.len())
+    }
 }
 
-impl From<&EvaluationUnitScope> for PyEvaluationUnitScope {
-    fn from(scope: &EvaluationUnitScope) -> Self {
-        match scope {
-            EvaluationUnitScope::Release => PyEvaluationUnitScope::Release(),
-            EvaluationUnitScope::Track(track_num) => PyEvaluationUnitScope::Track(*track_num),
+// ============= Checksum and Integrity =============
+
+#[pyclass(name = "Checksum", frozen)]
+#[derive(Clone)]
+pub struct PyChecksum {
+    inner: Shared<Checksum>,
+}
+
+impl From<Shared<Checksum>> for PyChecksum {
+    fn from(inner: Shared<Checksum>) -> Self {
+        PyChecksum { inner }
+    }
+}
+
+#[pymethods]
+impl PyChecksum {
+    /// Integrity defaults to comparing the two checksums.
+    #[new]
+    #[pyo3(signature = (calculated=String::new(), log=String::new(), integrity=None))]
+    fn new(calculated: String, log: String, integrity: Option<PyIntegrity>) -> Self {
+        let integrity = integrity.map_or_else(
+            || build::integrity(&calculated, &log),
+            |integrity| Integrity::from(&integrity),
+        );
+        PyChecksum {
+            inner: Shared::new(Checksum {
+                calculated,
+                log,
+                integrity,
+            }),
         }
     }
+
+    #[getter]
+    fn calculated(&self) -> &str {
+        &self.inner.calculated
+    }
+
+    #[getter]
+    fn log(&self) -> &str {
+        &self.inner.log
+    }
+
+    #[getter]
+    fn integrity(&self) -> PyIntegrity {
+        PyIntegrity::from(&self.inner.integrity)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Checksum integrity={}>", self.integrity().name())
+    }
 }
 
-#[pyclass(name = "EvaluationUnitField")]
-#[derive(Clone, PartialEq)]
-pub enum PyEvaluationUnitField {
-    Encoding,
-    RipperVersion,
-    Drive,
-    Ripper,
-    Offset,
-    Cache,
-    TestAndCopy,
-    Encoder,
-    Checksum,
-    MediaType,
-    ReadMode,
-    MaxRetryCount,
-    AccurateStream,
-    C2,
-    SilentSamples,
-    NullSamples,
-    Gap,
-    Tag,
-    Gain,
-    RangeSplit,
-    Samples,
-    SilentBlocks,
-    Normalization,
-    Filename,
-    ReadError,
-    SkipError,
-    JitterGenericError,
-    JitterEdgeError,
-    JitterAtomError,
-    DriftError,
-    DroppedError,
-    DuplicatedError,
-    InconsistentErrorSectors,
-    DamagedSector,
-    Abort,
+// ============= Release Info =============
+
+#[pyclass(name = "ReleaseInfo", frozen)]
+#[derive(Clone)]
+pub struct PyReleaseInfo {
+    inner: Shared<ReleaseInfo>,
+}
+
+impl From<Shared<ReleaseInfo>> for PyReleaseInfo {
+    fn from(inner: Shared<ReleaseInfo>) -> Self {
+        PyReleaseInfo { inner }
+    }
+}
+
+#[pymethods]
+impl PyReleaseInfo {
+    #[new]
+    #[pyo3(signature = (artist=String::new(), title=String::new()))]
+    fn new(artist: String, title: String) -> Self {
+        PyReleaseInfo {
+            inner: Shared::new(ReleaseInfo { artist, title }),
+        }
+    }
+
+    #[getter]
+    fn artist(&self) -> &str {
+        &self.inner.artist
+    }
+
+    #[getter]
+    fn title(&self) -> &str {
+        &self.inner.title
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ReleaseInfo artist='{}' title='{}'>",
+            self.inner.artist, self.inner.title
+        )
+    }
+}
+
+// ============= Track Classes =============
+
+#[pyclass(name = "TrackErrorRange", frozen)]
+#[derive(Clone)]
+pub struct PyTrackErrorRange {
+    inner: Shared<TrackErrorRange>,
+}
+
+impl From<Shared<TrackErrorRange>> for PyTrackErrorRange {
+    fn from(inner: Shared<TrackErrorRange>) -> Self {
+        PyTrackErrorRange { inner }
+    }
+}
+
+#[pymethods]
+impl PyTrackErrorRange {
+    #[new]
+    fn new(start: std::time::Duration, length: std::time::Duration) -> PyResult<Self> {
+        Ok(PyTrackErrorRange {
+            inner: Shared::new(TrackErrorRange {
+                start: build::time(start)?,
+                length: build::time(length)?,
+            }),
+        })
+    }
+
+    #[getter]
+    fn start(&self) -> PyResult<std::time::Duration> {
+        time_to_duration(&self.inner.start)
+    }
+
+    #[getter]
+    fn length(&self) -> PyResult<std::time::Duration> {
+        time_to_duration(&self.inner.length)
+    }
+
+    /// Relative to the start of the track, like `start`.
+    #[getter]
+    fn start_sector(&self) -> PyResult<u32> {
+        position::sectors(&self.inner.start)
+    }
+
+    #[getter]
+    fn length_sectors(&self) -> PyResult<u32> {
+        position::sectors(&self.inner.length)
+    }
+
+    #[getter]
+    fn start_msf(&self) -> PyResult<PyMsf> {
+        self.start_sector().map(PyMsf::from_sectors)
+    }
+
+    #[getter]
+    fn length_msf(&self) -> PyResult<PyMsf> {
+        self.length_sectors().map(PyMsf::from_sectors)
+    }
+
+    #[getter]
+    fn start_sample(&self) -> PyResult<u64> {
+        position::samples(&self.inner.start)
+    }
+
+    #[getter]
+    fn length_samples(&self) -> PyResult<u64> {
+        position::samples(&self.inner.length)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!(
+            "<TrackErrorRange start={:?} length={:?}>",
+            self.start()?,
+            self.length()?
+        ))
+    }
+}
+
+/// A track error range placed on the disc with `ParsedLog.error_ranges()`.
+#[pyclass(name = "DiscErrorRange")]
+#[derive(Clone)]
+pub struct PyDiscErrorRange {
+    #[pyo3(get)]
+    pub track: u8,
+    #[pyo3(get)]
+    pub kind: PyErrorKind,
+    #[pyo3(get)]
+    pub start_sector: u32,
+    #[pyo3(get)]
+    pub end_sector: u32,
+    #[pyo3(get)]
+    pub range: PyTrackErrorRange,
+}
+
+impl PyDiscErrorRange {
+    pub fn from_range(range: position::DiscRange) -> Self {
+        PyDiscErrorRange {
+            track: range.track,
+            kind: PyErrorKind::from(range.kind),
+            start_sector: range.start_sector,
+            end_sector: range.end_sector,
+            range: PyTrackErrorRange::from(Shared::new(range.range)),
+        }
+    }
+}
+
+#[pymethods]
+impl PyDiscErrorRange {
+    #[getter]
+    fn length_sectors(&self) -> u32 {
+        position::span(self.start_sector, self.end_sector)
+    }
+
+    #[getter]
+    fn start_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.start_sector)
+    }
+
+    #[getter]
+    fn end_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.end_sector)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<DiscErrorRange track={} kind={} sectors={}-{}>",
+            self.track,
+            self.kind.name(),
+            self.start_sector,
+            self.end_sector
+        )
+    }
+}
+
+#[pyclass(name = "TrackErrorData", frozen)]
+#[derive(Clone)]
+pub struct PyTrackErrorData {
+    inner: Shared<TrackErrorData>,
+}
+
+impl From<Shared<TrackErrorData>> for PyTrackErrorData {
+    fn from(inner: Shared<TrackErrorData>) -> Self {
+        PyTrackErrorData { inner }
+    }
 }
 
-#[pymethods]
-impl PyEvaluationUnitField {
+#[pymethods]
+impl PyTrackErrorData {
+    /// Count defaults to the number of ranges.
+    #[new]
+    #[pyo3(signature = (count=None, ranges=Vec::new()))]
+    fn new(count: Option<u32>, ranges: Vec<PyTrackErrorRange>) -> PyResult<Self> {
+        let ranges: Vec<TrackErrorRange> = ranges
+            .iter()
+            .map(|range| TrackErrorRange::clone(&range.inner))
+            .collect();
+        let count = match count {
+            Some(count) => count,
+            None => u32::try_from(ranges.len())
+                .map_err(|_| build::error("TrackErrorData", "too many ranges"))?,
+        };
+        Ok(PyTrackErrorData {
+            inner: Shared::new(TrackErrorData { count, ranges }),
+        })
+    }
+
+    #[getter]
+    fn count(&self) -> u32 {
+        self.inner.count
+    }
+
+    #[getter]
+    fn ranges(&self) -> Vec<PyTrackErrorRange> {
+        self.inner
+            .map_each(|data| &data.ranges)
+            .into_iter()
+            .map(PyTrackErrorRange::from)
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TrackErrorData count={}>", self.inner.count)
+    }
+}
+
+#[pyclass(name = "TrackError", frozen)]
+#[derive(Clone)]
+pub struct PyTrackError {
+    inner: Shared<TrackError>,
+}
+
+impl From<Shared<TrackError>> for PyTrackError {
+    fn from(inner: Shared<TrackError>) -> Self {
+        PyTrackError { inner }
+    }
+}
+
+#[pymethods]
+impl PyTrackError {
+    /// Error kinds that are not given have no errors.
+    #[new]
+    #[pyo3(signature = (
+        *,
+        read=None,
+        skip=None,
+        jitter_generic=None,
+        jitter_edge=None,
+        jitter_atom=None,
+        drift=None,
+        dropped=None,
+        duplicated=None,
+        damaged_sectors=None,
+        inconsistent_err_sectors=None,
+        missing_samples=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        read: Option<PyTrackErrorData>,
+        skip: Option<PyTrackErrorData>,
+        jitter_generic: Option<PyTrackErrorData>,
+        jitter_edge: Option<PyTrackErrorData>,
+        jitter_atom: Option<PyTrackErrorData>,
+        drift: Option<PyTrackErrorData>,
+        dropped: Option<PyTrackErrorData>,
+        duplicated: Option<PyTrackErrorData>,
+        damaged_sectors: Option<PyTrackErrorData>,
+        inconsistent_err_sectors: Option<PyTrackErrorData>,
+        missing_samples: Option<PyTrackErrorData>,
+    ) -> Self {
+        let mut errors = build::no_errors();
+        if let Some(data) = read {
+            errors.read = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = skip {
+            errors.skip = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = jitter_generic {
+            errors.jitter_generic = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = jitter_edge {
+            errors.jitter_edge = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = jitter_atom {
+            errors.jitter_atom = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = drift {
+            errors.drift = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = dropped {
+            errors.dropped = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = duplicated {
+            errors.duplicated = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = damaged_sectors {
+            errors.damaged_sectors = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = inconsistent_err_sectors {
+            errors.inconsistent_err_sectors = TrackErrorData::clone(&data.inner);
+        }
+        if let Some(data) = missing_samples {
+            errors.missing_samples = TrackErrorData::clone(&data.inner);
+        }
+        PyTrackError {
+            inner: Shared::new(errors),
+        }
+    }
+
+    #[getter]
+    fn read(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.read))
+    }
+
+    #[getter]
+    fn skip(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.skip))
+    }
+
+    #[getter]
+    fn jitter_generic(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.jitter_generic))
+    }
+
+    #[getter]
+    fn jitter_edge(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.jitter_edge))
+    }
+
+    #[getter]
+    fn jitter_atom(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.jitter_atom))
+    }
+
+    #[getter]
+    fn drift(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.drift))
+    }
+
+    #[getter]
+    fn dropped(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.dropped))
+    }
+
+    #[getter]
+    fn duplicated(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.duplicated))
+    }
+
+    #[getter]
+    fn damaged_sectors(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.damaged_sectors))
+    }
+
+    #[getter]
+    fn inconsistent_err_sectors(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.inconsistent_err_sectors))
+    }
+
+    #[getter]
+    fn missing_samples(&self) -> PyTrackErrorData {
+        PyTrackErrorData::from(self.inner.map(|error| &error.missing_samples))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<TrackError read={} skip={} jitter={}>",
+            self.inner.read.count, self.inner.skip.count, self.inner.jitter_generic.count
+        )
+    }
+}
+
+#[pyclass(name = "AccurateRipConfidence", frozen)]
+#[derive(Clone)]
+pub struct PyAccurateRipConfidence {
+    inner: Shared<AccurateRipConfidence>,
+}
+
+impl From<Shared<AccurateRipConfidence>> for PyAccurateRipConfidence {
+    fn from(inner: Shared<AccurateRipConfidence>) -> Self {
+        PyAccurateRipConfidence { inner }
+    }
+}
+
+#[pymethods]
+impl PyAccurateRipConfidence {
+    /// An offset of None means the rip used the same offset as the
+    /// database; an int is the differing offset. `offset_kind` Different
+    /// with no offset is a match at a different offset that was not logged.
+    #[new]
+    #[pyo3(signature = (matching=None, total=None, offset=None, *, total_kind=PyAccurateRipTotalKind::All, offset_kind=None))]
+    fn new(
+        matching: Option<u32>,
+        total: Option<u32>,
+        offset: Option<i16>,
+        total_kind: PyAccurateRipTotalKind,
+        offset_kind: Option<PyAccurateRipOffsetKind>,
+    ) -> PyResult<Self> {
+        if let (Some(matching), Some(total)) = (matching, total) {
+            if matching > total {
+                return Err(build::error(
+                    "AccurateRipConfidence",
+                    format!("matching {} is above total {}", matching, total),
+                ));
+            }
+        }
+        let offset = match (offset_kind, offset) {
+            (Some(PyAccurateRipOffsetKind::Same), Some(offset)) => {
+                return Err(build::error(
+                    "AccurateRipConfidence",
+                    format!("offset {} given with offset_kind Same", offset),
+                ));
+            }
+            (Some(PyAccurateRipOffsetKind::Same), None) | (None, None) => AccurateRipOffset::Same,
+            (_, offset) => AccurateRipOffset::Different(offset),
+        };
+        Ok(PyAccurateRipConfidence {
+            inner: Shared::new(AccurateRipConfidence {
+                matching,
+                total: total.map(|total| match total_kind {
+                    PyAccurateRipTotalKind::All => AccurateRipConfidenceTotal::All(total),
+                    PyAccurateRipTotalKind::Version => AccurateRipConfidenceTotal::Version(total),
+                }),
+                offset,
+            }),
+        })
+    }
+
+    #[getter]
+    fn matching(&self) -> Option<u32> {
+        self.inner.matching
+    }
+
+    #[getter]
+    fn total(&self) -> Option<u32> {
+        match self.inner.total {
+            Some(AccurateRipConfidenceTotal::All(n)) => Some(n),
+            Some(AccurateRipConfidenceTotal::Version(n)) => Some(n),
+            None => None,
+        }
+    }
+
+    /// Whether `total` counts the submissions of all versions or of the
+    /// result's version only.
+    #[getter]
+    fn total_kind(&self) -> Option<PyAccurateRipTotalKind> {
+        self.inner.total.as_ref().map(|total| match total {
+            AccurateRipConfidenceTotal::All(_) => PyAccurateRipTotalKind::All,
+            AccurateRipConfidenceTotal::Version(_) => PyAccurateRipTotalKind::Version,
+        })
+    }
+
+    /// The offset as text, "Same", "Different" or "Different (n)". See
+    /// `offset_kind` and `offset_value`.
+    #[getter]
+    fn offset(&self) -> String {
+        match self.inner.offset {
+            AccurateRipOffset::Same => "Same".to_string(),
+            AccurateRipOffset::Different(Some(n)) => format!("Different ({})", n),
+            AccurateRipOffset::Different(None) => "Different".to_string(),
+        }
+    }
+
+    #[getter]
+    fn offset_kind(&self) -> PyAccurateRipOffsetKind {
+        match self.inner.offset {
+            AccurateRipOffset::Same => PyAccurateRipOffsetKind::Same,
+            AccurateRipOffset::Different(_) => PyAccurateRipOffsetKind::Different,
+        }
+    }
+
+    /// The differing offset in samples, if logged.
+    #[getter]
+    fn offset_value(&self) -> Option<i16> {
+        match self.inner.offset {
+            AccurateRipOffset::Different(offset) => offset,
+            AccurateRipOffset::Same => None,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<AccurateRipConfidence matching={:?} total={:?}>",
+            self.matching(),
+            self.total()
+        )
+    }
+}
+
+#[pyclass(name = "AccurateRipUnit", frozen)]
+#[derive(Clone)]
+pub struct PyAccurateRipUnit {
+    inner: Shared<AccurateRipUnit>,
+}
+
+impl From<Shared<AccurateRipUnit>> for PyAccurateRipUnit {
+    fn from(inner: Shared<AccurateRipUnit>) -> Self {
+        PyAccurateRipUnit { inner }
+    }
+}
+
+#[pymethods]
+impl PyAccurateRipUnit {
+    #[new]
+    #[pyo3(signature = (status, *, confidence=None, sign=String::new(), version=None))]
+    fn new(
+        status: PyAccurateRipStatus,
+        confidence: Option<PyAccurateRipConfidence>,
+        sign: String,
+        version: Option<u8>,
+    ) -> PyResult<Self> {
+        if let Some(version @ (0 | 3..)) = version {
+            return Err(build::error(
+                "AccurateRipUnit",
+                format!("version must be 1 or 2, not {}", version),
+            ));
+        }
+        Ok(PyAccurateRipUnit {
+            inner: Shared::new(AccurateRipUnit {
+                status: AccurateRipStatus::from(&status),
+                confidence: confidence
+                    .map(|confidence| AccurateRipConfidence::clone(&confidence.inner)),
+                sign,
+                version,
+            }),
+        })
+    }
+
+    #[getter]
+    fn status(&self) -> PyAccurateRipStatus {
+        PyAccurateRipStatus::from(&self.inner.status)
+    }
+
+    #[getter]
+    fn confidence(&self) -> Option<PyAccurateRipConfidence> {
+        self.inner
+            .map_opt(|ar| ar.confidence.as_ref())
+            .map(PyAccurateRipConfidence::from)
+    }
+
+    #[getter]
+    fn sign(&self) -> &str {
+        &self.inner.sign
+    }
+
+    #[getter]
+    fn version(&self) -> Option<u8> {
+        self.inner.version
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<AccurateRipUnit status={}>", self.status().name())
+    }
+}
+
+#[pyclass(name = "TestAndCopy", frozen)]
+#[derive(Clone)]
+pub struct PyTestAndCopy {
+    inner: Shared<TestAndCopy>,
+}
+
+impl From<Shared<TestAndCopy>> for PyTestAndCopy {
+    fn from(inner: Shared<TestAndCopy>) -> Self {
+        PyTestAndCopy { inner }
+    }
+}
+
+#[pymethods]
+impl PyTestAndCopy {
+    /// Integrity defaults to comparing the two hashes.
+    #[new]
+    #[pyo3(signature = (test_hash=String::new(), copy_hash=String::new(), integrity=None))]
+    fn new(test_hash: String, copy_hash: String, integrity: Option<PyIntegrity>) -> Self {
+        let integrity = integrity.map_or_else(
+            || build::integrity(&test_hash, &copy_hash),
+            |integrity| Integrity::from(&integrity),
+        );
+        PyTestAndCopy {
+            inner: Shared::new(TestAndCopy {
+                test_hash,
+                copy_hash,
+                integrity,
+            }),
+        }
+    }
+
+    #[getter]
+    fn test_hash(&self) -> &str {
+        &self.inner.test_hash
+    }
+
+    #[getter]
+    fn copy_hash(&self) -> &str {
+        &self.inner.copy_hash
+    }
+
+    #[getter]
+    fn integrity(&self) -> PyIntegrity {
+        PyIntegrity::from(&self.inner.integrity)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<TestAndCopy integrity={}>", self.integrity().name())
+    }
+}
+
+#[pyclass(name = "TrackEntry", frozen)]
+#[derive(Clone)]
+pub struct PyTrackEntry {
+    inner: Shared<TrackEntry>,
+}
+
+impl From<Shared<TrackEntry>> for PyTrackEntry {
+    fn from(inner: Shared<TrackEntry>) -> Self {
+        PyTrackEntry { inner }
+    }
+}
+
+#[pymethods]
+impl PyTrackEntry {
+    #[new]
+    #[pyo3(signature = (
+        num,
+        *,
+        is_range=false,
+        aborted=false,
+        filenames=Vec::new(),
+        peak_level=None,
+        pregap_length=None,
+        extraction_speed=None,
+        gain=None,
+        preemphasis=None,
+        test_and_copy=None,
+        errors=None,
+        ar_info=Vec::new()
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        num: u8,
+        is_range: bool,
+        aborted: bool,
+        filenames: Vec<String>,
+        peak_level: Option<f64>,
+        pregap_length: Option<std::time::Duration>,
+        extraction_speed: Option<f64>,
+        gain: Option<f64>,
+        preemphasis: Option<bool>,
+        test_and_copy: Option<PyTestAndCopy>,
+        errors: Option<PyTrackError>,
+        ar_info: Vec<PyAccurateRipUnit>,
+    ) -> PyResult<Self> {
+        build::check_track("TrackEntry", u32::from(num))?;
+        for (name, value) in [
+            ("peak_level", peak_level),
+            ("extraction_speed", extraction_speed),
+        ] {
+            if value.is_some_and(|value| !(value.is_finite() && value >= 0.0)) {
+                return Err(build::error(
+                    "TrackEntry",
+                    format!("{} must be a non-negative number", name),
+                ));
+            }
+        }
+        Ok(PyTrackEntry {
+            inner: Shared::new(TrackEntry {
+                num,
+                is_range,
+                aborted,
+                filenames,
+                peak_level,
+                pregap_length: pregap_length.map(build::time).transpose()?,
+                extraction_speed,
+                gain,
+                preemphasis,
+                test_and_copy: test_and_copy.map_or_else(
+                    || TestAndCopy {
+                        test_hash: String::new(),
+                        copy_hash: String::new(),
+                        integrity: Integrity::Unknown,
+                    },
+                    |test_and_copy| TestAndCopy::clone(&test_and_copy.inner),
+                ),
+                errors: errors
+                    .map_or_else(build::no_errors, |errors| TrackError::clone(&errors.inner)),
+                ar_info: ar_info
+                    .iter()
+                    .map(|unit| AccurateRipUnit::clone(&unit.inner))
+                    .collect(),
+            }),
+        })
+    }
+
+    #[getter]
+    fn num(&self) -> u8 {
+        self.inner.num
+    }
+
+    #[getter]
+    fn is_range(&self) -> bool {
+        self.inner.is_range
+    }
+
+    #[getter]
+    fn aborted(&self) -> bool {
+        self.inner.aborted
+    }
+
+    #[getter]
+    fn filenames(&self) -> Vec<&str> {
+        self.inner.filenames.iter().map(String::as_str).collect()
+    }
+
+    #[getter]
+    fn peak_level(&self) -> Option<f64> {
+        self.inner.peak_level
+    }
+
+    #[getter]
+    fn pregap_length(&self) -> PyResult<Option<std::time::Duration>> {
+        self.inner
+            .pregap_length
+            .as_ref()
+            .map(time_to_duration)
+            .transpose()
+    }
+
+    #[getter]
+    fn extraction_speed(&self) -> Option<f64> {
+        self.inner.extraction_speed
+    }
+
+    #[getter]
+    fn gain(&self) -> Option<f64> {
+        self.inner.gain
+    }
+
+    #[getter]
+    fn preemphasis(&self) -> Option<bool> {
+        self.inner.preemphasis
+    }
+
+    #[getter]
+    fn test_and_copy(&self) -> PyTestAndCopy {
+        PyTestAndCopy::from(self.inner.map(|entry| &entry.test_and_copy))
+    }
+
+    #[getter]
+    fn errors(&self) -> PyTrackError {
+        PyTrackError::from(self.inner.map(|entry| &entry.errors))
+    }
+
+    #[getter]
+    fn ar_info(&self) -> Vec<PyAccurateRipUnit> {
+        self.inner
+            .map_each(|entry| &entry.ar_info)
+            .into_iter()
+            .map(PyAccurateRipUnit::from)
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<TrackEntry num={} aborted={}>",
+            self.inner.num, self.inner.aborted
+        )
+    }
+}
+
+// ============= Parsed Log =============
+
+#[pyclass(name = "ParsedLog", frozen)]
+#[derive(Clone)]
+pub struct PyParsedLog {
+    inner: Shared<ParsedLog>,
+    // The encoding of the file the log came from, which is evaluated too
+    encoding: Shared<String>,
+    // What the text of the log says about the disc and its CUETools DB
+    // result, for parsed logs
+    log_data: Option<LogEntry>,
+}
+
+/// The parts of a hand-built log that are objects, not settings.
+struct LogParts {
+    tracks: Vec<PyTrackEntry>,
+    toc: Option<PyToc>,
+    release_info: Option<PyReleaseInfo>,
+    checksum: Option<PyChecksum>,
+    encoding: String,
+}
+
+impl PyParsedLog {
+    fn build(
+        mut log: ParsedLog,
+        parts: LogParts,
+        settings: Option<&Bound<'_, PyDict>>,
+        func: &str,
+    ) -> PyResult<Self> {
+        log.tracks = parts
+            .tracks
+            .iter()
+            .map(|track| TrackEntry::clone(&track.inner))
+            .collect();
+        build::check_tracks(&log.tracks)?;
+        if let Some(toc) = parts.toc {
+            log.toc = Toc::clone(&toc.inner);
+        }
+        if let Some(release_info) = parts.release_info {
+            log.release_info = ReleaseInfo::clone(&release_info.inner);
+        }
+        if let Some(checksum) = parts.checksum {
+            log.checksum = Checksum::clone(&checksum.inner);
+        }
+        if let Some(settings) = settings {
+            replace::apply(&mut log, settings, func)?;
+        }
+        let combined = Shared::new(ParsedLogCombined {
+            encoding: parts.encoding,
+            parsed_logs: vec![log],
+        });
+        Ok(PyParsedLog {
+            inner: combined.map(|combined| &combined.parsed_logs[0]),
+            encoding: combined.map(|combined| &combined.encoding),
+            log_data: None,
+        })
+    }
+
+    /// A copy of this log on its own, as cambia-core's evaluators take it.
+    pub fn to_combined(&self) -> ParsedLogCombined {
+        ParsedLogCombined {
+            encoding: String::clone(&self.encoding),
+            parsed_logs: vec![ParsedLog::clone(&self.inner)],
+        }
+    }
+
+    pub fn split_range(&self) -> PyResult<PyRangeSplit> {
+        let split = range::split(&self.inner)?;
+        let log = PyParsedLog {
+            inner: Shared::new(split.log),
+            encoding: self.encoding.clone(),
+            log_data: self.log_data.clone(),
+        };
+        let entries = log.inner.map_each(|log| &log.tracks);
+        let tracks = split
+            .spans
+            .iter()
+            .filter_map(|span| {
+                let index = log.inner.tracks.iter().position(|t| t.num == span.track)?;
+                Some(PyRangeTrack {
+                    track: span.track,
+                    start_sector: span.start_sector,
+                    end_sector: span.end_sector,
+                    derived: span.derived,
+                    entry: PyTrackEntry::from(entries[index].clone()),
+                })
+            })
+            .collect();
+        Ok(PyRangeSplit {
+            tracks,
+            range: split
+                .range
+                .map(|track| PyTrackEntry::from(Shared::new(track))),
+            unplaced: split.unplaced,
+            log,
+        })
+    }
+}
+
+#[pymethods]
+impl PyParsedLog {
+    /// Build a log by hand. Other settings are passed as keywords, with the
+    /// same names and types as `replace()`; settings that are not given are
+    /// Unknown, as if the log did not mention them.
+    #[new]
+    #[pyo3(signature = (
+        ripper,
+        *,
+        tracks=Vec::new(),
+        toc=None,
+        release_info=None,
+        checksum=None,
+        encoding=String::from("UTF-8"),
+        **settings
+    ))]
+    fn new(
+        ripper: PyRipper,
+        tracks: Vec<PyTrackEntry>,
+        toc: Option<PyToc>,
+        release_info: Option<PyReleaseInfo>,
+        checksum: Option<PyChecksum>,
+        encoding: String,
+        settings: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        let log = build::log(Ripper::from(&ripper));
+        PyParsedLog::build(
+            log,
+            LogParts {
+                tracks,
+                toc,
+                release_info,
+                checksum,
+                encoding,
+            },
+            settings,
+            "ParsedLog",
+        )
+    }
+
+    /// Like the constructor, but settings that are not given take their
+    /// recommended values: cache defeating, Accurate Stream, test and copy
+    /// and secure mode on, C2 and normalization off, gaps appended and a
+    /// pressed disc.
+    #[staticmethod]
+    #[pyo3(signature = (
+        ripper,
+        *,
+        tracks=Vec::new(),
+        toc=None,
+        release_info=None,
+        checksum=None,
+        encoding=String::from("UTF-8"),
+        **settings
+    ))]
+    fn secure(
+        ripper: PyRipper,
+        tracks: Vec<PyTrackEntry>,
+        toc: Option<PyToc>,
+        release_info: Option<PyReleaseInfo>,
+        checksum: Option<PyChecksum>,
+        encoding: String,
+        settings: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        let mut log = build::log(Ripper::from(&ripper));
+        build::secure(&mut log);
+        PyParsedLog::build(
+            log,
+            LogParts {
+                tracks,
+                toc,
+                release_info,
+                checksum,
+                encoding,
+            },
+            settings,
+            "secure",
+        )
+    }
+
+    /// Return a copy of this log with the given attributes changed, for
+    /// scoring a log as if it had been ripped with other settings.
+    ///
+    /// `tracks` maps track numbers to per-track changes. The copy is
+    /// independent of the response this log came from.
+    #[pyo3(signature = (**changes))]
+    fn replace(&self, changes: Option<&Bound<'_, PyDict>>) -> PyResult<PyParsedLog> {
+        let mut combined = self.to_combined();
+        if let Some(changes) = changes {
+            replace::apply(&mut combined.parsed_logs[0], changes, "replace")?;
+        }
+        let combined = Shared::new(combined);
+        Ok(PyParsedLog {
+            inner: combined.map(|combined| &combined.parsed_logs[0]),
+            encoding: combined.map(|combined| &combined.encoding),
+            log_data: self.log_data.clone(),
+        })
+    }
+
+    /// Write this log back out as English text in the given ripper's
+    /// format, by default its own. `language` only accepts "en".
+    ///
+    /// The text ends with a line marking it as regenerated and has no
+    /// checksum. Parsing it again gives an equivalent log.
+    #[pyo3(signature = (ripper=None, language="en"))]
+    fn to_log_text(&self, ripper: Option<PyRipper>, language: &str) -> PyResult<String> {
+        let ripper = ripper.map_or_else(|| self.inner.ripper.clone(), |r| Ripper::from(&r));
+        render::render(&self.inner, &ripper, language)
+    }
+
+    #[getter]
+    fn ripper(&self) -> PyRipper {
+        PyRipper::from(&self.inner.ripper)
+    }
+
+    #[getter]
+    fn ripper_version(&self) -> &str {
+        &self.inner.ripper_version
+    }
+
+    #[getter]
+    fn release_info(&self) -> PyReleaseInfo {
+        PyReleaseInfo::from(self.inner.map(|log| &log.release_info))
+    }
+
+    #[getter]
+    fn language(&self) -> &str {
+        &self.inner.language
+    }
+
+    #[getter]
+    fn read_offset(&self) -> Option<i16> {
+        self.inner.read_offset
+    }
+
+    #[getter]
+    fn combined_rw_offset(&self) -> Option<i32> {
+        self.inner.combined_rw_offset
+    }
+
+    #[getter]
+    fn drive(&self) -> &str {
+        &self.inner.drive
+    }
+
+    #[getter]
+    fn media_type(&self) -> PyMediaType {
+        PyMediaType::from(&self.inner.media_type)
+    }
+
+    #[getter]
+    fn accurate_stream(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.accurate_stream)
+    }
+
+    #[getter]
+    fn defeat_audio_cache(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.defeat_audio_cache)
+    }
+
+    #[getter]
+    fn use_c2(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.use_c2)
+    }
+
+    #[getter]
+    fn overread(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.overread)
+    }
+
+    #[getter]
+    fn fill_silence(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.fill_silence)
+    }
+
+    #[getter]
+    fn delete_silence(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.delete_silence)
+    }
+
+    #[getter]
+    fn use_null_samples(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.use_null_samples)
+    }
+
+    #[getter]
+    fn test_and_copy(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.test_and_copy)
+    }
+
+    #[getter]
+    fn normalize(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.normalize)
+    }
+
+    #[getter]
+    fn read_mode(&self) -> PyReadMode {
+        PyReadMode::from(&self.inner.read_mode)
+    }
+
+    #[getter]
+    fn gap_handling(&self) -> PyGap {
+        PyGap::from(&self.inner.gap_handling)
+    }
+
+    #[getter]
+    fn checksum(&self) -> PyChecksum {
+        PyChecksum::from(self.inner.map(|log| &log.checksum))
+    }
+
+    #[getter]
+    fn toc(&self) -> PyToc {
+        PyToc::from(self.inner.map(|log| &log.toc))
+    }
+
+    /// The ISRCs, media catalog number and CD-Text in the text of the log,
+    /// or None for a log that was built rather than parsed.
+    #[getter]
+    fn disc_info(&self) -> Option<PyDiscInfo> {
+        self.log_data
+            .as_ref()
+            .and_then(LogEntry::disc_info)
+            .as_deref()
+            .map(PyDiscInfo::from_disc_info)
+    }
+
+    /// The CUETools DB result in the text of the log, or None for a log
+    /// without one or that was built rather than parsed.
+    #[getter]
+    fn ctdb(&self) -> Option<PyCtdbResult> {
+        self.log_data
+            .as_ref()
+            .and_then(LogEntry::ctdb)
+            .map(PyCtdbResult::from)
+    }
+
+    #[getter]
+    fn tracks(&self) -> Vec<PyTrackEntry> {
+        self.inner
+            .map_each(|log| &log.tracks)
+            .into_iter()
+            .map(PyTrackEntry::from)
+            .collect()
+    }
+
+    #[getter]
+    fn id3_enabled(&self) -> PyQuartet {
+        PyQuartet::from(&self.inner.id3_enabled)
+    }
+
+    #[getter]
+    fn audio_encoder(&self) -> Vec<&str> {
+        self.inner
+            .audio_encoder
+            .iter()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Every track error range in absolute disc sectors, found by adding
+    /// each range to the start of its track in the TOC. Tracks missing
+    /// from the TOC are left out.
+    fn error_ranges(&self) -> PyResult<Vec<PyDiscErrorRange>> {
+        Ok(position::disc_ranges(&self.inner)?
+            .into_iter()
+            .map(PyDiscErrorRange::from_range)
+            .collect())
+    }
+
+    /// Merge the error ranges of every track into a disc-wide timeline.
+    fn error_map(&self) -> PyResult<PyErrorMap> {
+        error_map::build(&self.inner).map(PyErrorMap::from_map)
+    }
+
+    /// Draw the error map as a standalone SVG document.
+    #[pyo3(signature = (width=800))]
+    fn render_error_map_svg(&self, width: u32) -> PyResult<String> {
+        error_map::svg(&self.inner, width)
+    }
+
+    /// Line the tracks of the log up against its TOC.
+    fn coverage(&self) -> PyResult<PyCoverage> {
+        coverage::coverage(&self.inner).map(PyCoverage::from_coverage)
+    }
+
+    /// The AccurateRip results of the tracks, summed up for the disc.
+    #[getter]
+    fn accuraterip(&self) -> PyResult<PyAccurateRipSummary> {
+        accuraterip::summary(&self.inner).map(PyAccurateRipSummary::from_summary)
+    }
+
+    /// Check the AccurateRip results of the tracks against each other.
+    fn accuraterip_consistency(&self) -> PyResult<PyAccurateRipConsistency> {
+        accuraterip::consistency(&self.inner).map(PyAccurateRipConsistency::from_consistency)
+    }
+
+    /// Hidden track one audio on the disc, and how the log dealt with it.
+    #[getter]
+    fn htoa(&self) -> PyHtoaInfo {
+        let htoa = htoa::htoa(&self.inner);
+        PyHtoaInfo {
+            length_sectors: htoa.sectors,
+            present: htoa.present(),
+            extraction: htoa.extraction.as_ref().map(PyHtoaExtraction::from),
+            entry: htoa
+                .index
+                .map(|index| PyTrackEntry::from(self.inner.map(|log| &log.tracks[index]))),
+            tested: htoa.tested,
+            crcs_match: htoa.crcs_match,
+            guidance: htoa::guidance(&htoa, &self.inner.ripper),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ParsedLog ripper={} tracks={}>",
+            self.ripper().name(),
+            self.inner.tracks.len()
+        )
+    }
+}
+
+// ============= Error Map Classes =============
+
+#[pyclass(name = "ErrorSpan")]
+#[derive(Clone)]
+pub struct PyErrorSpan {
+    #[pyo3(get)]
+    pub start_sector: u32,
+    #[pyo3(get)]
+    pub end_sector: u32,
+    #[pyo3(get)]
+    pub kinds: Vec<PyErrorKind>,
+}
+
+#[pymethods]
+impl PyErrorSpan {
+    #[getter]
+    fn length_sectors(&self) -> u32 {
+        position::span(self.start_sector, self.end_sector)
+    }
+
+    #[getter]
+    fn start_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.start_sector)
+    }
+
+    #[getter]
+    fn end_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.end_sector)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ErrorSpan sectors={}-{} kinds=[{}]>",
+            self.start_sector,
+            self.end_sector,
+            self.kinds
+                .iter()
+                .map(PyErrorKind::name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[pyclass(name = "ErrorMap")]
+#[derive(Clone)]
+pub struct PyErrorMap {
+    #[pyo3(get)]
+    pub disc_start: Option<u32>,
+    #[pyo3(get)]
+    pub disc_end: Option<u32>,
+    #[pyo3(get)]
+    pub timeline: Vec<PyErrorSpan>,
+    intervals: Vec<(ErrorKind, Vec<(u32, u32)>)>,
+}
+
+impl PyErrorMap {
+    pub fn from_map(map: error_map::ErrorMap) -> Self {
+        PyErrorMap {
+            disc_start: map.disc.map(|(start, _)| start),
+            disc_end: map.disc.map(|(_, end)| end),
+            timeline: map
+                .timeline
+                .into_iter()
+                .map(|span| PyErrorSpan {
+                    start_sector: span.start,
+                    end_sector: span.end,
+                    kinds: span.kinds.into_iter().map(PyErrorKind::from).collect(),
+                })
+                .collect(),
+            intervals: map.intervals,
+        }
+    }
+}
+
+#[pymethods]
+impl PyErrorMap {
+    /// Merged inclusive sector intervals per error kind, in `TrackError`
+    /// order. Kinds without errors are left out.
+    #[getter]
+    fn intervals<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (kind, intervals) in &self.intervals {
+            dict.set_item(PyErrorKind::from(*kind), intervals.clone())?;
+        }
+        Ok(dict)
+    }
+
+    /// Sectors with at least one error, counted once however many kinds
+    /// they have.
+    #[getter]
+    fn error_sectors(&self) -> u32 {
+        self.timeline.iter().map(|span| span.length_sectors()).sum()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ErrorMap kinds={} spans={}>",
+            self.intervals.len(),
+            self.timeline.len()
+        )
+    }
+}
+
+// ============= Range Split Classes =============
+
+#[pyclass(name = "RangeTrack")]
+#[derive(Clone)]
+pub struct PyRangeTrack {
+    #[pyo3(get)]
+    pub track: u8,
+    #[pyo3(get)]
+    pub start_sector: u32,
+    #[pyo3(get)]
+    pub end_sector: u32,
+    #[pyo3(get)]
+    pub derived: bool,
+    #[pyo3(get)]
+    pub entry: PyTrackEntry,
+}
+
+#[pymethods]
+impl PyRangeTrack {
+    #[getter]
+    fn length_sectors(&self) -> u32 {
+        position::span(self.start_sector, self.end_sector)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<RangeTrack track={} sectors={}-{} derived={}>",
+            self.track, self.start_sector, self.end_sector, self.derived
+        )
+    }
+}
+
+#[pyclass(name = "RangeSplit")]
+#[derive(Clone)]
+pub struct PyRangeSplit {
+    #[pyo3(get)]
+    pub log: PyParsedLog,
+    #[pyo3(get)]
+    pub tracks: Vec<PyRangeTrack>,
+    #[pyo3(get)]
+    pub range: Option<PyTrackEntry>,
+    unplaced: Vec<(ErrorKind, u32)>,
+}
+
+#[pymethods]
+impl PyRangeSplit {
+    /// Error counts per kind that no track could be given, in `TrackError`
+    /// order.
+    #[getter]
+    fn unplaced_errors<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (kind, count) in &self.unplaced {
+            dict.set_item(PyErrorKind::from(*kind), count)?;
+        }
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<RangeSplit tracks={} split={}>",
+            self.tracks.len(),
+            self.range.is_some()
+        )
+    }
+}
+
+// ============= Coverage Classes =============
+
+#[pyclass(name = "CoverageIssue")]
+#[derive(Clone)]
+pub struct PyCoverageIssue {
+    #[pyo3(get)]
+    pub track: u32,
+    #[pyo3(get)]
+    pub message: String,
+}
+
+#[pymethods]
+impl PyCoverageIssue {
+    fn __repr__(&self) -> String {
+        format!(
+            "<CoverageIssue track={} message={:?}>",
+            self.track, self.message
+        )
+    }
+}
+
+#[pyclass(name = "Coverage")]
+#[derive(Clone)]
+pub struct PyCoverage {
+    #[pyo3(get)]
+    pub ripped: Vec<u32>,
+    #[pyo3(get)]
+    pub aborted: Vec<u32>,
+    #[pyo3(get)]
+    pub missing: Vec<u32>,
+    #[pyo3(get)]
+    pub duplicated: Vec<u32>,
+    #[pyo3(get)]
+    pub not_in_toc: Vec<u32>,
+    #[pyo3(get)]
+    pub data_tracks: Vec<u32>,
+    #[pyo3(get)]
+    pub range_rip: bool,
+    #[pyo3(get)]
+    pub htoa_sectors: u32,
+    #[pyo3(get)]
+    pub htoa_extracted: Option<bool>,
+    #[pyo3(get)]
+    pub issues: Vec<PyCoverageIssue>,
+}
+
+impl PyCoverage {
+    pub fn from_coverage(coverage: Coverage) -> Self {
+        PyCoverage {
+            ripped: coverage.ripped,
+            aborted: coverage.aborted,
+            missing: coverage.missing,
+            duplicated: coverage.duplicated,
+            not_in_toc: coverage.not_in_toc,
+            data_tracks: coverage.data_tracks,
+            range_rip: coverage.range_rip,
+            htoa_sectors: coverage.htoa_sectors,
+            htoa_extracted: coverage.htoa_extracted,
+            issues: coverage
+                .issues
+                .into_iter()
+                .map(|issue| PyCoverageIssue {
+                    track: issue.track,
+                    message: issue.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyCoverage {
+    /// Whether every audio track of the TOC was ripped.
+    #[getter]
+    fn complete(&self) -> bool {
+        self.missing.is_empty() && self.aborted.is_empty()
+    }
+
+    /// Whether the TOC leaves room for hidden audio before track one.
+    #[getter]
+    fn htoa_present(&self) -> bool {
+        self.htoa_sectors >= HTOA_MIN_SECTORS
+    }
+
+    #[getter]
+    fn consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Coverage ripped={} missing={:?} aborted={:?} consistent={}>",
+            self.ripped.len(),
+            self.missing,
+            self.aborted,
+            self.consistent()
+        )
+    }
+}
+
+// ============= HTOA Classes =============
+
+#[pyclass(name = "HtoaInfo")]
+#[derive(Clone)]
+pub struct PyHtoaInfo {
+    #[pyo3(get)]
+    pub length_sectors: u32,
+    #[pyo3(get)]
+    pub present: bool,
+    #[pyo3(get)]
+    pub extraction: Option<PyHtoaExtraction>,
+    #[pyo3(get)]
+    pub entry: Option<PyTrackEntry>,
+    #[pyo3(get)]
+    pub tested: bool,
+    #[pyo3(get)]
+    pub crcs_match: bool,
+    #[pyo3(get)]
+    pub guidance: Option<String>,
+}
+
+#[pymethods]
+impl PyHtoaInfo {
+    #[getter]
+    fn length_msf(&self) -> PyMsf {
+        PyMsf::from_sectors(self.length_sectors)
+    }
+
+    /// Whether the hidden track was extracted with matching test and copy
+    /// CRCs.
+    #[getter]
+    fn verified(&self) -> bool {
+        self.extraction.is_some() && self.tested && self.crcs_match
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<HtoaInfo present={} length={} extraction={}>",
+            self.present,
+            position::format_msf(self.length_sectors),
+            self.extraction
+                .as_ref()
+                .map_or("None", PyHtoaExtraction::name)
+        )
+    }
+}
+
+// ============= AccurateRip Classes =============
+
+#[pyclass(name = "AccurateRipSummary")]
+#[derive(Clone)]
+pub struct PyAccurateRipSummary {
+    #[pyo3(get)]
+    pub tracks: Vec<u32>,
+    #[pyo3(get)]
+    pub matched: Vec<u32>,
+    #[pyo3(get)]
+    pub all_tracks_matched: bool,
+    #[pyo3(get)]
+    pub min_confidence: Option<u32>,
+    #[pyo3(get)]
+    pub version_conflicts: Vec<u32>,
+    #[pyo3(get)]
+    pub different_offset: Vec<u32>,
+    #[pyo3(get)]
+    pub offsets: Vec<i16>,
+}
+
+impl PyAccurateRipSummary {
+    pub fn from_summary(summary: Summary) -> Self {
+        PyAccurateRipSummary {
+            all_tracks_matched: summary.all_matched(),
+            tracks: summary.tracks,
+            matched: summary.matched,
+            min_confidence: summary.min_confidence,
+            version_conflicts: summary.version_conflicts,
+            different_offset: summary.different_offset,
+            offsets: summary.offsets,
+        }
+    }
+}
+
+#[pymethods]
+impl PyAccurateRipSummary {
+    /// Tracks with a result that matched in no version.
+    #[getter]
+    fn unmatched(&self) -> Vec<u32> {
+        self.tracks
+            .iter()
+            .filter(|track| !self.matched.contains(track))
+            .copied()
+            .collect()
+    }
+
+    /// Whether no track has a v1 and a v2 result that disagree.
+    #[getter]
+    fn versions_agree(&self) -> bool {
+        self.version_conflicts.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<AccurateRipSummary matched={}/{} min_confidence={:?}>",
+            self.matched.len(),
+            self.tracks.len(),
+            self.min_confidence
+        )
+    }
+}
+
+#[pyclass(name = "AccurateRipOutcome", eq, eq_int)]
+#[derive(Clone, PartialEq)]
+pub enum PyAccurateRipOutcome {
+    Match,
+    Offset,
+    Mismatch,
+    NotFound,
+}
+
+#[pymethods]
+impl PyAccurateRipOutcome {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyAccurateRipOutcome::Match => "Match",
+            PyAccurateRipOutcome::Offset => "Offset",
+            PyAccurateRipOutcome::Mismatch => "Mismatch",
+            PyAccurateRipOutcome::NotFound => "NotFound",
+        }
+    }
+
+    #[getter]
+    fn value(&self) -> &str {
+        self.name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<AccurateRipOutcome.{}>", self.name())
+    }
+}
+
+impl From<&Outcome> for PyAccurateRipOutcome {
+    fn from(outcome: &Outcome) -> Self {
+        match outcome {
+            Outcome::Matched => PyAccurateRipOutcome::Match,
+            Outcome::Offset(_) => PyAccurateRipOutcome::Offset,
+            Outcome::Mismatch => PyAccurateRipOutcome::Mismatch,
+            Outcome::NotFound => PyAccurateRipOutcome::NotFound,
+        }
+    }
+}
+
+#[pyclass(name = "AccurateRipGroup")]
+#[derive(Clone)]
+pub struct PyAccurateRipGroup {
+    #[pyo3(get)]
+    pub outcome: PyAccurateRipOutcome,
+    #[pyo3(get)]
+    pub offset: Option<i16>,
+    #[pyo3(get)]
+    pub tracks: Vec<u32>,
+}
+
+#[pymethods]
+impl PyAccurateRipGroup {
+    fn __repr__(&self) -> String {
+        format!(
+            "<AccurateRipGroup outcome={} tracks={:?}>",
+            self.outcome.name(),
+            self.tracks
+        )
+    }
+}
+
+#[pyclass(name = "AccurateRipOutlier")]
+#[derive(Clone)]
+pub struct PyAccurateRipOutlier {
+    #[pyo3(get)]
+    pub track: u32,
+    #[pyo3(get)]
+    pub outcome: PyAccurateRipOutcome,
+    #[pyo3(get)]
+    pub offset: Option<i16>,
+    #[pyo3(get)]
+    pub message: String,
+}
+
+#[pymethods]
+impl PyAccurateRipOutlier {
+    fn __repr__(&self) -> String {
+        format!(
+            "<AccurateRipOutlier track={} message='{}'>",
+            self.track, self.message
+        )
+    }
+}
+
+#[pyclass(name = "AccurateRipConsistency")]
+#[derive(Clone)]
+pub struct PyAccurateRipConsistency {
+    #[pyo3(get)]
+    pub groups: Vec<PyAccurateRipGroup>,
+    pub totals: Vec<(u32, Vec<u32>)>,
+    #[pyo3(get)]
+    pub majority: Option<PyAccurateRipGroup>,
+    #[pyo3(get)]
+    pub outliers: Vec<PyAccurateRipOutlier>,
+    #[pyo3(get)]
+    pub unchecked: Vec<u32>,
+    #[pyo3(get)]
+    pub verified: bool,
+    #[pyo3(get)]
+    pub totals_agree: bool,
+}
+
+impl PyAccurateRipConsistency {
+    pub fn from_consistency(consistency: Consistency) -> Self {
+        let group = |(outcome, tracks): &(Outcome, Vec<u32>)| PyAccurateRipGroup {
+            outcome: PyAccurateRipOutcome::from(outcome),
+            offset: outcome.offset(),
+            tracks: tracks.clone(),
+        };
+        PyAccurateRipConsistency {
+            verified: consistency.verified(),
+            totals_agree: consistency.totals_agree(),
+            groups: consistency.groups.iter().map(group).collect(),
+            majority: consistency.majority.and_then(|majority| {
+                consistency
+                    .groups
+                    .iter()
+                    .find(|(outcome, _)| *outcome == majority)
+                    .map(group)
+            }),
+            totals: consistency.totals,
+            outliers: consistency
+                .outliers
+                .into_iter()
+                .map(|outlier| PyAccurateRipOutlier {
+                    track: outlier.track,
+                    outcome: PyAccurateRipOutcome::from(&outlier.outcome),
+                    offset: outlier.outcome.offset(),
+                    message: outlier.message,
+                })
+                .collect(),
+            unchecked: consistency.unchecked,
+        }
+    }
+}
+
+#[pymethods]
+impl PyAccurateRipConsistency {
+    /// Tracks by number of AccurateRip submissions.
+    #[getter]
+    fn totals<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (total, tracks) in &self.totals {
+            dict.set_item(total, tracks.clone())?;
+        }
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<AccurateRipConsistency verified={} groups={} outliers={} totals_agree={}>",
+            self.verified,
+            self.groups.len(),
+            self.outliers.len(),
+            self.totals_agree
+        )
+    }
+}
+
+// ============= CTDB Classes =============
+
+fn finding(
+    class: &str,
+    status: &PyCtdbStatus,
+    confidence: Option<u32>,
+    total: Option<u32>,
+    differing_samples: Option<u32>,
+) -> PyResult<ctdb::Finding> {
+    if let (Some(confidence), Some(total)) = (confidence, total) {
+        if confidence > total {
+            return Err(build::error(
+                class,
+                format!("confidence {} is above total {}", confidence, total),
+            ));
+        }
+    }
+    Ok(ctdb::Finding {
+        status: ctdb::Status::from(status),
+        confidence,
+        total,
+        differing_samples,
+    })
+}
+
+#[pyclass(name = "CtdbTrack", frozen)]
+#[derive(Clone)]
+pub struct PyCtdbTrack {
+    inner: Shared<ctdb::Track>,
+}
+
+impl From<Shared<ctdb::Track>> for PyCtdbTrack {
+    fn from(inner: Shared<ctdb::Track>) -> Self {
+        PyCtdbTrack { inner }
+    }
+}
+
+#[pymethods]
+impl PyCtdbTrack {
+    #[new]
+    #[pyo3(signature = (track, status, *, confidence=None, total=None, differing_samples=None))]
+    fn new(
+        track: u32,
+        status: PyCtdbStatus,
+        confidence: Option<u32>,
+        total: Option<u32>,
+        differing_samples: Option<u32>,
+    ) -> PyResult<Self> {
+        let result = finding("CtdbTrack", &status, confidence, total, differing_samples)?;
+        Ok(PyCtdbTrack {
+            inner: Shared::new(ctdb::Track { track, result }),
+        })
+    }
+
+    #[getter]
+    fn track(&self) -> u32 {
+        self.inner.track
+    }
+
+    #[getter]
+    fn status(&self) -> PyCtdbStatus {
+        PyCtdbStatus::from(self.inner.result.status)
+    }
+
+    #[getter]
+    fn confidence(&self) -> Option<u32> {
+        self.inner.result.confidence
+    }
+
+    #[getter]
+    fn total(&self) -> Option<u32> {
+        self.inner.result.total
+    }
+
+    #[getter]
+    fn differing_samples(&self) -> Option<u32> {
+        self.inner.result.differing_samples
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<CtdbTrack track={} status={}>",
+            self.inner.track,
+            self.status().name()
+        )
+    }
+}
+
+#[pyclass(name = "CtdbResult", frozen)]
+#[derive(Clone)]
+pub struct PyCtdbResult {
+    inner: Shared<Ctdb>,
+}
+
+impl From<Shared<Ctdb>> for PyCtdbResult {
+    fn from(inner: Shared<Ctdb>) -> Self {
+        PyCtdbResult { inner }
+    }
+}
+
+#[pymethods]
+impl PyCtdbResult {
+    #[new]
+    #[pyo3(signature = (status, *, confidence=None, total=None, differing_samples=None, tocid=None, tracks=Vec::new()))]
+    fn new(
+        status: PyCtdbStatus,
+        confidence: Option<u32>,
+        total: Option<u32>,
+        differing_samples: Option<u32>,
+        tocid: Option<String>,
+        tracks: Vec<PyCtdbTrack>,
+    ) -> PyResult<Self> {
+        let result = finding("CtdbResult", &status, confidence, total, differing_samples)?;
+        Ok(PyCtdbResult {
+            inner: Shared::new(Ctdb {
+                tocid,
+                result,
+                tracks: tracks
+                    .iter()
+                    .map(|track| ctdb::Track::clone(&track.inner))
+                    .collect(),
+            }),
+        })
+    }
+
+    #[getter]
+    fn tocid(&self) -> Option<&str> {
+        self.inner.tocid.as_deref()
+    }
+
+    #[getter]
+    fn status(&self) -> PyCtdbStatus {
+        PyCtdbStatus::from(self.inner.result.status)
+    }
+
+    #[getter]
+    fn confidence(&self) -> Option<u32> {
+        self.inner.result.confidence
+    }
+
+    #[getter]
+    fn total(&self) -> Option<u32> {
+        self.inner.result.total
+    }
+
+    #[getter]
+    fn differing_samples(&self) -> Option<u32> {
+        self.inner.result.differing_samples
+    }
+
+    #[getter]
+    fn tracks(&self) -> Vec<PyCtdbTrack> {
+        self.inner
+            .map_each(|ctdb| &ctdb.tracks)
+            .into_iter()
+            .map(PyCtdbTrack::from)
+            .collect()
+    }
+
+    #[getter]
+    fn verified(&self) -> bool {
+        self.inner.verified()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<CtdbResult status={} confidence={:?} total={:?}>",
+            self.status().name(),
+            self.inner.result.confidence,
+            self.inner.result.total
+        )
+    }
+}
+
+impl PyCtdbResult {
+    pub fn from_ctdb(ctdb: Ctdb) -> Self {
+        PyCtdbResult {
+            inner: Shared::new(ctdb),
+        }
+    }
+
+    pub fn to_ctdb(&self) -> Ctdb {
+        Ctdb::clone(&self.inner)
+    }
+}
+
+// ============= Disc Info Classes =============
+
+/// An ISRC, normalized to 12 characters without hyphens.
+#[pyclass(name = "Isrc", frozen)]
+#[derive(Clone)]
+pub struct PyIsrc {
+    #[pyo3(get)]
+    pub code: String,
+}
+
+impl PyIsrc {
+    fn isrc(&self) -> Isrc {
+        Isrc::new(&self.code)
+    }
+
+    fn part(&self, index: usize) -> Option<String> {
+        self.isrc().parts().map(|parts| parts[index].to_string())
+    }
+}
+
+#[pymethods]
+impl PyIsrc {
+    #[new]
+    fn new(code: &str) -> Self {
+        PyIsrc {
+            code: Isrc::new(code).code,
+        }
+    }
+
+    /// Whether the code has the ISRC format. ISRCs carry no check digit.
+    #[getter]
+    fn valid(&self) -> bool {
+        self.isrc().valid()
+    }
+
+    #[getter]
+    fn country(&self) -> Option<String> {
+        self.part(0)
+    }
+
+    #[getter]
+    fn registrant(&self) -> Option<String> {
+        self.part(1)
+    }
+
+    #[getter]
+    fn year(&self) -> Option<String> {
+        self.part(2)
+    }
+
+    #[getter]
+    fn designation(&self) -> Option<String> {
+        self.part(3)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        std::hash::Hash::hash(&self.code, &mut hasher);
+        std::hash::Hasher::finish(&hasher)
+    }
+
+    /// The hyphenated form, "CC-XXX-YY-NNNNN", if the code is valid.
+    fn __str__(&self) -> String {
+        match self.isrc().parts() {
+            Some(parts) => parts.join("-"),
+            None => self.code.clone(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Isrc code='{}' valid={}>", self.code, self.valid())
+    }
+}
+
+/// A media catalog number, the EAN-13 or UPC-A barcode of the disc.
+#[pyclass(name = "Mcn", frozen)]
+#[derive(Clone)]
+pub struct PyMcn {
+    #[pyo3(get)]
+    pub code: String,
+}
+
+impl PyMcn {
+    fn mcn(&self) -> Mcn {
+        Mcn::new(&self.code)
+    }
+
+    /// What equality compares: the EAN-13, so that a UPC-A equals its EAN.
+    fn key(&self) -> String {
+        self.mcn().ean().unwrap_or_else(|| self.code.clone())
+    }
+}
+
+#[pymethods]
+impl PyMcn {
+    #[new]
+    fn new(code: &str) -> Self {
+        PyMcn {
+            code: Mcn::new(code).code,
+        }
+    }
+
+    /// Whether the code is 12 or 13 digits with a matching check digit.
+    #[getter]
+    fn valid(&self) -> bool {
+        self.mcn().valid()
+    }
+
+    #[getter]
+    fn ean(&self) -> Option<String> {
+        self.mcn().ean()
+    }
+
+    /// The UPC-A, for an EAN-13 with a leading zero.
+    #[getter]
+    fn upc(&self) -> Option<String> {
+        self.mcn().upc()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        std::hash::Hash::hash(&self.key(), &mut hasher);
+        std::hash::Hasher::finish(&hasher)
+    }
+
+    fn __str__(&self) -> String {
+        self.code.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Mcn code='{}' valid={}>", self.code, self.valid())
+    }
+}
+
+#[pyclass(name = "TrackInfo")]
+#[derive(Clone)]
+pub struct PyTrackInfo {
+    #[pyo3(get)]
+    pub track: u8,
+    #[pyo3(get)]
+    pub isrc: Option<PyIsrc>,
+    #[pyo3(get)]
+    pub performer: Option<String>,
+    #[pyo3(get)]
+    pub title: Option<String>,
+}
+
+#[pymethods]
+impl PyTrackInfo {
+    fn __repr__(&self) -> String {
+        format!(
+            "<TrackInfo track={} isrc={:?}>",
+            self.track,
+            self.isrc.as_ref().map_or("", |isrc| isrc.code.as_str())
+        )
+    }
+}
+
+#[pyclass(name = "DiscInfo")]
+#[derive(Clone)]
+pub struct PyDiscInfo {
+    #[pyo3(get)]
+    pub ripper: PyRipper,
+    #[pyo3(get)]
+    pub mcn: Option<PyMcn>,
+    #[pyo3(get)]
+    pub performer: Option<String>,
+    #[pyo3(get)]
+    pub title: Option<String>,
+    #[pyo3(get)]
+    pub tracks: Vec<PyTrackInfo>,
+}
+
+impl PyDiscInfo {
+    pub fn from_disc_info(info: &DiscInfo) -> Self {
+        PyDiscInfo {
+            ripper: PyRipper::from(&info.ripper),
+            mcn: info.mcn.as_ref().map(|mcn| PyMcn {
+                code: mcn.code.clone(),
+            }),
+            performer: info.cd_text.performer.clone(),
+            title: info.cd_text.title.clone(),
+            tracks: info
+                .tracks
+                .iter()
+                .map(|track| PyTrackInfo {
+                    track: track.track,
+                    isrc: track.isrc.as_ref().map(|isrc| PyIsrc {
+                        code: isrc.code.clone(),
+                    }),
+                    performer: track.cd_text.performer.clone(),
+                    title: track.cd_text.title.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyDiscInfo {
+    /// ISRCs by track number, for the tracks that have one.
+    #[getter]
+    fn isrcs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for track in &self.tracks {
+            if let Some(isrc) = &track.isrc {
+                dict.set_item(track.track, isrc.clone())?;
+            }
+        }
+        Ok(dict)
+    }
+
+    /// Whether the catalog number and every ISRC are valid. True for a log
+    /// with none of them.
+    #[getter]
+    fn valid(&self) -> bool {
+        self.mcn.as_ref().is_none_or(|mcn| mcn.valid())
+            && self
+                .tracks
+                .iter()
+                .filter_map(|track| track.isrc.as_ref())
+                .all(|isrc| isrc.valid())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<DiscInfo ripper={} mcn={:?} tracks={}>",
+            self.ripper.name(),
+            self.mcn.as_ref().map_or("", |mcn| mcn.code.as_str()),
+            self.tracks.len()
+        )
+    }
+}
+
+// ============= Parsed Combined =============
+
+#[pyclass(name = "ParsedLogCombined", frozen)]
+#[derive(Clone)]
+pub struct PyParsedLogCombined {
+    inner: Shared<ParsedLogCombined>,
+    // The CUETools DB result and disc info of each log, where it was
+    // parsed from text
+    log_data: Shared<LogData>,
+}
+
+impl PyParsedLogCombined {
+    pub fn from_parsed(parsed: ParsedLogCombined, log_data: LogData) -> Self {
+        PyParsedLogCombined {
+            inner: Shared::new(parsed),
+            log_data: Shared::new(log_data),
+        }
+    }
+
+    /// Built from logs that may have come from different files, keeping
+    /// what each was parsed with.
+    fn from_logs(parsed: ParsedLogCombined, logs: &[&PyParsedLog]) -> Self {
+        let entries = logs.iter().map(|log| log.log_data.as_ref());
+        let log_data = LogData::known(
+            entries
+                .clone()
+                .map(|entry| entry.and_then(LogEntry::ctdb).as_deref().cloned())
+                .collect(),
+            entries
+                .map(|entry| entry.and_then(LogEntry::disc_info).as_deref().cloned())
+                .collect(),
+        );
+        PyParsedLogCombined::from_parsed(parsed, log_data)
+    }
+
+    pub fn to_parsed(&self) -> ParsedLogCombined {
+        ParsedLogCombined::clone(&self.inner)
+    }
+
+    /// What was read from the text of the logs, shared rather than read
+    /// again.
+    pub fn log_data(&self) -> Shared<LogData> {
+        self.log_data.clone()
+    }
+
+    /// The disc info of each log.
+    pub fn disc_info(&self) -> &[Option<DiscInfo>] {
+        self.log_data.disc_info(&self.inner)
+    }
+}
+
+impl From<&PyParsedLog> for PyParsedLogCombined {
+    fn from(log: &PyParsedLog) -> Self {
+        PyParsedLogCombined::from_logs(log.to_combined(), &[log])
+    }
+}
+
+#[pymethods]
+impl PyParsedLogCombined {
+    #[new]
+    #[pyo3(signature = (parsed_logs, *, encoding=String::from("UTF-8")))]
+    fn new(parsed_logs: Vec<PyParsedLog>, encoding: String) -> PyResult<Self> {
+        if parsed_logs.is_empty() {
+            return Err(build::error(
+                "ParsedLogCombined",
+                "parsed_logs must not be empty",
+            ));
+        }
+        Ok(PyParsedLogCombined::from_logs(
+            ParsedLogCombined {
+                encoding,
+                parsed_logs: parsed_logs
+                    .iter()
+                    .map(|log| ParsedLog::clone(&log.inner))
+                    .collect(),
+            },
+            &parsed_logs.iter().collect::<Vec<_>>(),
+        ))
+    }
+
+    #[getter]
+    fn encoding(&self) -> &str {
+        &self.inner.encoding
+    }
+
+    #[getter]
+    fn parsed_logs(&self) -> Vec<PyParsedLog> {
+        self.inner
+            .map_each(|combined| &combined.parsed_logs)
+            .into_iter()
+            .enumerate()
+            .map(|(index, inner)| PyParsedLog {
+                inner,
+                encoding: self.inner.map(|combined| &combined.encoding),
+                log_data: Some(LogEntry::new(
+                    self.log_data.clone(),
+                    self.inner.clone(),
+                    index,
+                )),
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ParsedLogCombined encoding='{}' logs={}>",
+            self.inner.encoding,
+            self.inner.parsed_logs.len()
+        )
+    }
+}
+
+// ============= Evaluation Classes =============
+
+#[pyclass(name = "EvaluationUnitScope", eq, hash, frozen)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum PyEvaluationUnitScope {
+    Release(),
+    Track(Option<u8>),
+}
+
+#[pymethods]
+impl PyEvaluationUnitScope {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyEvaluationUnitScope::Release() => "Release",
+            PyEvaluationUnitScope::Track(_) => "Track",
+        }
+    }
+
+    #[getter]
+    fn value(&self) -> String {
+        match self {
+            PyEvaluationUnitScope::Release() => "Release".to_string(),
+            PyEvaluationUnitScope::Track(track_num) => {
+                if let Some(num) = track_num {
+                    format!("Track {}", num)
+                } else {
+                    "Track".to_string()
+                }
+            }
+        }
+    }
+
+    /// The track number, or None for release scope or an unnumbered track.
+    #[getter]
+    fn track(&self) -> Option<u8> {
+        match self {
+            PyEvaluationUnitScope::Release() => None,
+            PyEvaluationUnitScope::Track(track_num) => *track_num,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match self {
+            PyEvaluationUnitScope::Release() => "<EvaluationUnitScope.Release()>".to_string(),
+            PyEvaluationUnitScope::Track(Some(num)) => {
+                format!("<EvaluationUnitScope.Track({})>", num)
+            }
+            PyEvaluationUnitScope::Track(None) => "<EvaluationUnitScope.Track(None)>".to_string(),
+        }
+    }
+}
+
+impl From<&EvaluationUnitScope> for PyEvaluationUnitScope {
+    fn from(scope: &EvaluationUnitScope) -> Self {
+        match scope {
+            EvaluationUnitScope::Release => PyEvaluationUnitScope::Release(),
+            EvaluationUnitScope::Track(track_num) => PyEvaluationUnitScope::Track(*track_num),
+        }
+    }
+}
+
+#[pyclass(name = "EvaluationUnitField")]
+#[derive(Clone, PartialEq)]
+pub enum PyEvaluationUnitField {
+    Encoding,
+    RipperVersion,
+    Drive,
+    Ripper,
+    Offset,
+    Cache,
+    TestAndCopy,
+    Encoder,
+    Checksum,
+    MediaType,
+    ReadMode,
+    MaxRetryCount,
+    AccurateStream,
+    C2,
+    SilentSamples,
+    NullSamples,
+    Gap,
+    Tag,
+    Gain,
+    RangeSplit,
+    Samples,
+    SilentBlocks,
+    Normalization,
+    Filename,
+    ReadError,
+    SkipError,
+    JitterGenericError,
+    JitterEdgeError,
+    JitterAtomError,
+    DriftError,
+    DroppedError,
+    DuplicatedError,
+    InconsistentErrorSectors,
+    DamagedSector,
+    Abort,
+}
+
+#[pymethods]
+impl PyEvaluationUnitField {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyEvaluationUnitField::Encoding => "Encoding",
+            PyEvaluationUnitField::RipperVersion => "RipperVersion",
+            PyEvaluationUnitField::Drive => "Drive",
+            PyEvaluationUnitField::Ripper => "Ripper",
+            PyEvaluationUnitField::Offset => "Offset",
+            PyEvaluationUnitField::Cache => "Cache",
+            PyEvaluationUnitField::TestAndCopy => "TestAndCopy",
+            PyEvaluationUnitField::Encoder => "Encoder",
+            PyEvaluationUnitField::Checksum => "Checksum",
+            PyEvaluationUnitField::MediaType => "MediaType",
+            PyEvaluationUnitField::ReadMode => "ReadMode",
+            PyEvaluationUnitField::MaxRetryCount => "MaxRetryCount",
+            PyEvaluationUnitField::AccurateStream => "AccurateStream",
+            PyEvaluationUnitField::C2 => "C2",
+            PyEvaluationUnitField::SilentSamples => "SilentSamples",
+            PyEvaluationUnitField::NullSamples => "NullSamples",
+            PyEvaluationUnitField::Gap => "Gap",
+            PyEvaluationUnitField::Tag => "Tag",
+            PyEvaluationUnitField::Gain => "Gain",
+            PyEvaluationUnitField::RangeSplit => "RangeSplit",
+            PyEvaluationUnitField::Samples => "Samples",
+            PyEvaluationUnitField::SilentBlocks => "SilentBlocks",
+            PyEvaluationUnitField::Normalization => "Normalization",
+            PyEvaluationUnitField::Filename => "Filename",
+            PyEvaluationUnitField::ReadError => "ReadError",
+            PyEvaluationUnitField::SkipError => "SkipError",
+            PyEvaluationUnitField::JitterGenericError => "JitterGenericError",
+            PyEvaluationUnitField::JitterEdgeError => "JitterEdgeError",
+            PyEvaluationUnitField::JitterAtomError => "JitterAtomError",
+            PyEvaluationUnitField::DriftError => "DriftError",
+            PyEvaluationUnitField::DroppedError => "DroppedError",
+            PyEvaluationUnitField::DuplicatedError => "DuplicatedError",
+            PyEvaluationUnitField::InconsistentErrorSectors => "InconsistentErrorSectors",
+            PyEvaluationUnitField::DamagedSector => "DamagedSector",
+            PyEvaluationUnitField::Abort => "Abort",
+        }
+    }
+
+    #[getter]
+    fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
+        // Convert to original Rust enum and serialize with pythonize
+        let rust_enum = match self {
+            PyEvaluationUnitField::Encoding => EvaluationUnitField::Encoding,
+            PyEvaluationUnitField::RipperVersion => EvaluationUnitField::RipperVersion,
+            PyEvaluationUnitField::Drive => EvaluationUnitField::Drive,
+            PyEvaluationUnitField::Ripper => EvaluationUnitField::Ripper,
+            PyEvaluationUnitField::Offset => EvaluationUnitField::Offset,
+            PyEvaluationUnitField::Cache => EvaluationUnitField::Cache,
+            PyEvaluationUnitField::TestAndCopy => EvaluationUnitField::TestAndCopy,
+            PyEvaluationUnitField::Encoder => EvaluationUnitField::Encoder,
+            PyEvaluationUnitField::Checksum => EvaluationUnitField::Checksum,
+            PyEvaluationUnitField::MediaType => EvaluationUnitField::MediaType,
+            PyEvaluationUnitField::ReadMode => EvaluationUnitField::ReadMode,
+            PyEvaluationUnitField::MaxRetryCount => EvaluationUnitField::MaxRetryCount,
+            PyEvaluationUnitField::AccurateStream => EvaluationUnitField::AccurateStream,
+            PyEvaluationUnitField::C2 => EvaluationUnitField::C2,
+            PyEvaluationUnitField::SilentSamples => EvaluationUnitField::SilentSamples,
+            PyEvaluationUnitField::NullSamples => EvaluationUnitField::NullSamples,
+            PyEvaluationUnitField::Gap => EvaluationUnitField::Gap,
+            PyEvaluationUnitField::Tag => EvaluationUnitField::Tag,
+            PyEvaluationUnitField::Gain => EvaluationUnitField::Gain,
+            PyEvaluationUnitField::RangeSplit => EvaluationUnitField::RangeSplit,
+            PyEvaluationUnitField::Samples => EvaluationUnitField::Samples,
+            PyEvaluationUnitField::SilentBlocks => EvaluationUnitField::SilentBlocks,
+            PyEvaluationUnitField::Normalization => EvaluationUnitField::Normalization,
+            PyEvaluationUnitField::Filename => EvaluationUnitField::Filename,
+            PyEvaluationUnitField::ReadError => EvaluationUnitField::ReadError,
+            PyEvaluationUnitField::SkipError => EvaluationUnitField::SkipError,
+            PyEvaluationUnitField::JitterGenericError => EvaluationUnitField::JitterGenericError,
+            PyEvaluationUnitField::JitterEdgeError => EvaluationUnitField::JitterEdgeError,
+            PyEvaluationUnitField::JitterAtomError => EvaluationUnitField::JitterAtomError,
+            PyEvaluationUnitField::DriftError => EvaluationUnitField::DriftError,
+            PyEvaluationUnitField::DroppedError => EvaluationUnitField::DroppedError,
+            PyEvaluationUnitField::DuplicatedError => EvaluationUnitField::DuplicatedError,
+            PyEvaluationUnitField::InconsistentErrorSectors => {
+                EvaluationUnitField::InconsistentErrorSectors
+            }
+            PyEvaluationUnitField::DamagedSector => EvaluationUnitField::DamagedSector,
+            PyEvaluationUnitField::Abort => EvaluationUnitField::Abort,
+        };
+        Ok(pythonize(py, &rust_enum)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
+            .unbind())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<EvaluationUnitField.{}>", self.name())
+    }
+}
+
+impl From<&EvaluationUnitField> for PyEvaluationUnitField {
+    fn from(field: &EvaluationUnitField) -> Self {
+        match field {
+            EvaluationUnitField::Encoding => PyEvaluationUnitField::Encoding,
+            EvaluationUnitField::RipperVersion => PyEvaluationUnitField::RipperVersion,
+            EvaluationUnitField::Drive => PyEvaluationUnitField::Drive,
+            EvaluationUnitField::Ripper => PyEvaluationUnitField::Ripper,
+            EvaluationUnitField::Offset => PyEvaluationUnitField::Offset,
+            EvaluationUnitField::Cache => PyEvaluationUnitField::Cache,
+            EvaluationUnitField::TestAndCopy => PyEvaluationUnitField::TestAndCopy,
+            EvaluationUnitField::Encoder => PyEvaluationUnitField::Encoder,
+            EvaluationUnitField::Checksum => PyEvaluationUnitField::Checksum,
+            EvaluationUnitField::MediaType => PyEvaluationUnitField::MediaType,
+            EvaluationUnitField::ReadMode => PyEvaluationUnitField::ReadMode,
+            EvaluationUnitField::MaxRetryCount => PyEvaluationUnitField::MaxRetryCount,
+            EvaluationUnitField::AccurateStream => PyEvaluationUnitField::AccurateStream,
+            EvaluationUnitField::C2 => PyEvaluationUnitField::C2,
+            EvaluationUnitField::SilentSamples => PyEvaluationUnitField::SilentSamples,
+            EvaluationUnitField::NullSamples => PyEvaluationUnitField::NullSamples,
+            EvaluationUnitField::Gap => PyEvaluationUnitField::Gap,
+            EvaluationUnitField::Tag => PyEvaluationUnitField::Tag,
+            EvaluationUnitField::Gain => PyEvaluationUnitField::Gain,
+            EvaluationUnitField::RangeSplit => PyEvaluationUnitField::RangeSplit,
+            EvaluationUnitField::Samples => PyEvaluationUnitField::Samples,
+            EvaluationUnitField::SilentBlocks => PyEvaluationUnitField::SilentBlocks,
+            EvaluationUnitField::Normalization => PyEvaluationUnitField::Normalization,
+            EvaluationUnitField::Filename => PyEvaluationUnitField::Filename,
+            EvaluationUnitField::ReadError => PyEvaluationUnitField::ReadError,
+            EvaluationUnitField::SkipError => PyEvaluationUnitField::SkipError,
+            EvaluationUnitField::JitterGenericError => PyEvaluationUnitField::JitterGenericError,
+            EvaluationUnitField::JitterEdgeError => PyEvaluationUnitField::JitterEdgeError,
+            EvaluationUnitField::JitterAtomError => PyEvaluationUnitField::JitterAtomError,
+            EvaluationUnitField::DriftError => PyEvaluationUnitField::DriftError,
+            EvaluationUnitField::DroppedError => PyEvaluationUnitField::DroppedError,
+            EvaluationUnitField::DuplicatedError => PyEvaluationUnitField::DuplicatedError,
+            EvaluationUnitField::InconsistentErrorSectors => {
+                PyEvaluationUnitField::InconsistentErrorSectors
+            }
+            EvaluationUnitField::DamagedSector => PyEvaluationUnitField::DamagedSector,
+            EvaluationUnitField::Abort => PyEvaluationUnitField::Abort,
+        }
+    }
+}
+
+#[pyclass(name = "EvaluationUnitClass")]
+#[derive(Clone, PartialEq)]
+pub enum PyEvaluationUnitClass {
+    Critical,
+    Bad,
+    Neutral,
+    Good,
+    Perfect,
+}
+
+#[pymethods]
+impl PyEvaluationUnitClass {
+    #[getter]
+    fn name(&self) -> &str {
+        match self {
+            PyEvaluationUnitClass::Critical => "Critical",
+            PyEvaluationUnitClass::Bad => "Bad",
+            PyEvaluationUnitClass::Neutral => "Neutral",
+            PyEvaluationUnitClass::Good => "Good",
+            PyEvaluationUnitClass::Perfect => "Perfect",
+        }
+    }
+
+    #[getter]
+    fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
+        // Convert to original Rust enum and serialize with pythonize
+        let rust_enum = match self {
+            PyEvaluationUnitClass::Critical => EvaluationUnitClass::Critical,
+            PyEvaluationUnitClass::Bad => EvaluationUnitClass::Bad,
+            PyEvaluationUnitClass::Neutral => EvaluationUnitClass::Neutral,
+            PyEvaluationUnitClass::Good => EvaluationUnitClass::Good,
+            PyEvaluationUnitClass::Perfect => EvaluationUnitClass::Perfect,
+        };
+        Ok(pythonize(py, &rust_enum)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
+            .unbind())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<EvaluationUnitClass.{}>", self.name())
+    }
+}
+
+impl From<&EvaluationUnitClass> for PyEvaluationUnitClass {
+    fn from(class: &EvaluationUnitClass) -> Self {
+        match class {
+            EvaluationUnitClass::Critical => PyEvaluationUnitClass::Critical,
+            EvaluationUnitClass::Bad => PyEvaluationUnitClass::Bad,
+            EvaluationUnitClass::Neutral => PyEvaluationUnitClass::Neutral,
+            EvaluationUnitClass::Good => PyEvaluationUnitClass::Good,
+            EvaluationUnitClass::Perfect => PyEvaluationUnitClass::Perfect,
+        }
+    }
+}
+
+#[pyclass(name = "EvaluationUnitData", frozen)]
+#[derive(Clone)]
+pub struct PyEvaluationUnitData {
+    inner: Shared<EvaluationUnitData>,
+}
+
+impl From<Shared<EvaluationUnitData>> for PyEvaluationUnitData {
+    fn from(inner: Shared<EvaluationUnitData>) -> Self {
+        PyEvaluationUnitData { inner }
+    }
+}
+
+#[pymethods]
+impl PyEvaluationUnitData {
+    #[getter]
+    fn scope(&self) -> PyEvaluationUnitScope {
+        PyEvaluationUnitScope::from(&self.inner.scope)
+    }
+
+    #[getter]
+    fn field(&self) -> PyEvaluationUnitField {
+        PyEvaluationUnitField::from(&self.inner.field)
+    }
+
+    #[getter]
+    fn message(&self) -> &str {
+        &self.inner.message
+    }
+
+    #[getter]
+    fn classification(&self) -> PyEvaluationUnitClass {
+        PyEvaluationUnitClass::from(&self.inner.class)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<EvaluationUnitData field={} classification={}>",
+            self.field().name(),
+            self.classification().name()
+        )
+    }
+}
+
+#[pyclass(name = "EvaluationUnit", frozen)]
+#[derive(Clone)]
+pub struct PyEvaluationUnit {
+    inner: Shared<EvaluationUnit>,
+    // The ripper of the evaluated log, to look up remediations with
+    ripper: Option<Ripper>,
+}
+
+/// One row of `deductions()`: field, message, points, class and scope.
+type Deduction<'a> = (
+    PyEvaluationUnitField,
+    &'a str,
+    Option<i64>,
+    PyEvaluationUnitClass,
+    PyEvaluationUnitScope,
+);
+
+fn deductions<'a>(units: impl IntoIterator<Item = &'a EvaluationUnit>) -> Vec<Deduction<'a>> {
+    scores::by_impact(units)
+        .into_iter()
+        .map(|unit| {
+            (
+                PyEvaluationUnitField::from(&unit.data.field),
+                unit.data.message.as_str(),
+                scores::parse(&unit.unit_score),
+                PyEvaluationUnitClass::from(&unit.data.class),
+                PyEvaluationUnitScope::from(&unit.data.scope),
+            )
+        })
+        .collect()
+}
+
+#[pymethods]
+impl PyEvaluationUnit {
+    #[getter]
+    fn unit_score(&self) -> &str {
+        &self.inner.unit_score
+    }
+
+    /// The unit's score as a number, or None if it is not numeric. This is
+    /// what the evaluator charged for the unit, not what it cost the final
+    /// score.
+    #[getter]
+    fn points(&self) -> Option<i64> {
+        scores::parse(&self.inner.unit_score)
+    }
+
+    /// `points` with its sign: negative for a deduction.
+    #[getter]
+    fn impact(&self) -> Option<i64> {
+        self.points().map(|points| -points)
+    }
+
+    #[getter]
+    fn data(&self) -> PyEvaluationUnitData {
+        PyEvaluationUnitData::from(self.inner.map(|unit| &unit.data))
+    }
+
+    /// How to avoid this deduction with the log's ripper, or None if there
+    /// is nothing the user can change.
+    #[getter]
+    fn remediation(&self) -> Option<PyRemediation> {
+        let ripper = self.ripper.as_ref()?;
+        remediation::lookup(&self.inner.data.field, ripper).map(|inner| PyRemediation { inner })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<EvaluationUnit score='{}'>", self.inner.unit_score)
+    }
+}
+
+#[pyclass(name = "Remediation", frozen)]
+#[derive(Clone)]
+pub struct PyRemediation {
+    inner: &'static Remediation,
+}
+
+#[pymethods]
+impl PyRemediation {
+    #[getter]
+    fn setting(&self) -> Option<&'static str> {
+        self.inner.setting
+    }
+
+    #[getter]
+    fn value(&self) -> Option<&'static str> {
+        self.inner.value
+    }
+
+    #[getter]
+    fn advice(&self) -> &'static str {
+        self.inner.advice
+    }
+
+    #[getter]
+    fn rerip(&self) -> bool {
+        self.inner.rerip
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Remediation advice='{}' rerip={}>",
+            self.inner.advice,
+            if self.inner.rerip { "True" } else { "False" }
+        )
+    }
+}
+
+#[pyclass(name = "Evaluation", frozen)]
+#[derive(Clone)]
+pub struct PyEvaluation {
+    inner: Shared<Evaluation>,
+    ripper: Option<Ripper>,
+    // The CUETools DB result of the evaluated log, where known
+    log_data: LogEntry,
+}
+
+impl PyEvaluation {
+    fn unit(&self, inner: Shared<EvaluationUnit>) -> PyEvaluationUnit {
+        PyEvaluationUnit {
+            inner,
+            ripper: self.ripper.clone(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyEvaluation {
+    #[getter]
+    fn score(&self) -> &str {
+        &self.inner.score
+    }
+
+    #[getter]
+    fn numeric_score(&self) -> Option<i64> {
+        scores::parse(&self.inner.score)
+    }
+
+    #[getter]
+    fn max_score(&self) -> i64 {
+        scores::MAX_SCORE
+    }
+
+    /// `max_score` minus the score, which need not equal the sum of the
+    /// units' points.
+    #[getter]
+    fn deducted(&self) -> Option<i64> {
+        self.numeric_score().map(|score| scores::MAX_SCORE - score)
+    }
+
+    /// Units as (field, message, points, class, scope), largest deduction
+    /// first.
+    fn deductions(&self) -> Vec<Deduction<'_>> {
+        deductions(&self.inner.evaluation_units)
+    }
+
+    /// Track-scoped units grouped by track number, in track order. Units
+    /// for a track the evaluator did not number are keyed by None.
+    fn by_track(&self) -> BTreeMap<Option<u8>, PyTrackEvaluation> {
+        let mut tracks: BTreeMap<Option<u8>, PyTrackEvaluation> = BTreeMap::new();
+        for unit in self.inner.map_each(|eval| &eval.evaluation_units) {
+            if let EvaluationUnitScope::Track(track) = unit.data.scope {
+                tracks
+                    .entry(track)
+                    .or_insert_with(|| PyTrackEvaluation {
+                        track,
+                        units: Vec::new(),
+                    })
+                    .units
+                    .push(self.unit(unit));
+            }
+        }
+        tracks
+    }
+
+    /// Units that apply to the whole release rather than a track.
+    fn release_units(&self) -> Vec<PyEvaluationUnit> {
+        self.inner
+            .map_each(|eval| &eval.evaluation_units)
+            .into_iter()
+            .filter(|unit| matches!(unit.data.scope, EvaluationUnitScope::Release))
+            .map(|unit| self.unit(unit))
+            .collect()
+    }
+
+    #[getter]
+    fn evaluation_units(&self) -> Vec<PyEvaluationUnit> {
+        self.inner
+            .map_each(|eval| &eval.evaluation_units)
+            .into_iter()
+            .map(|unit| self.unit(unit))
+            .collect()
+    }
+
+    /// The CUETools DB result of the evaluated log, if it has one.
+    #[getter]
+    fn ctdb(&self) -> Option<PyCtdbResult> {
+        self.log_data.ctdb().map(PyCtdbResult::from)
+    }
+
+    #[getter]
+    fn ctdb_verified(&self) -> bool {
+        self.log_data.ctdb().is_some_and(|ctdb| ctdb.verified())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<Evaluation score='{}' units={}>",
+            self.inner.score,
+            self.inner.evaluation_units.len()
+        )
+    }
+}
+
+/// The units of one track within an evaluation, with a score computed from
+/// them alone. cambia-core scores the log as a whole; this is what the
+/// track would score if its units were the only ones.
+#[pyclass(name = "TrackEvaluation", frozen)]
+#[derive(Clone)]
+pub struct PyTrackEvaluation {
+    track: Option<u8>,
+    units: Vec<PyEvaluationUnit>,
+}
+
+#[pymethods]
+impl PyTrackEvaluation {
+    #[getter]
+    fn track(&self) -> Option<u8> {
+        self.track
+    }
+
+    #[getter]
+    fn evaluation_units(&self) -> Vec<PyEvaluationUnit> {
+        self.units.clone()
+    }
+
+    /// Points deducted by this track's units; non-numeric scores count as 0.
+    #[getter]
+    fn deducted(&self) -> i64 {
+        self.units
+            .iter()
+            .filter_map(|unit| scores::parse(&unit.inner.unit_score))
+            .sum()
+    }
+
+    /// `MAX_SCORE` minus `deducted`, clamped at zero.
+    #[getter]
+    fn score(&self) -> i64 {
+        (scores::MAX_SCORE - self.deducted()).max(0)
+    }
+
+    fn deductions(&self) -> Vec<Deduction<'_>> {
+        deductions(self.units.iter().map(|unit| &*unit.inner))
+    }
+
+    fn __repr__(&self) -> String {
+        let track = self
+            .track
+            .map_or_else(|| "None".to_string(), |num| num.to_string());
+        format!(
+            "<TrackEvaluation track={} score={} units={}>",
+            track,
+            self.score(),
+            self.units.len()
+        )
+    }
+}
+
+#[pyclass(name = "EvaluationCombined", frozen)]
+#[derive(Clone)]
+pub struct PyEvaluationCombined {
+    inner: Shared<EvaluationCombined>,
+    // The logs that were evaluated, one per evaluation
+    parsed: Shared<ParsedLogCombined>,
+    // Their CUETools DB results, where known
+    log_data: Shared<LogData>,
+}
+
+#[pymethods]
+impl PyEvaluationCombined {
+    #[getter]
+    fn evaluator(&self) -> PyEvaluatorType {
+        PyEvaluatorType::from(&self.inner.evaluator)
+    }
+
+    #[getter]
+    fn combined_score(&self) -> &str {
+        &self.inner.combined_score
+    }
+
+    #[getter]
+    fn numeric_score(&self) -> Option<i64> {
+        scores::parse(&self.inner.combined_score)
+    }
+
+    #[getter]
+    fn max_score(&self) -> i64 {
+        scores::MAX_SCORE
+    }
+
+    /// `max_score` minus the score, which need not equal the sum of the
+    /// units' points.
+    #[getter]
+    fn deducted(&self) -> Option<i64> {
+        self.numeric_score().map(|score| scores::MAX_SCORE - score)
+    }
+
+    /// Units as (field, message, points, class, scope), largest deduction
+    /// first, in one list per log in `evaluations` order.
+    fn deductions(&self) -> Vec<Vec<Deduction<'_>>> {
+        self.inner
+            .evaluations
+            .iter()
+            .map(|evaluation| deductions(&evaluation.evaluation_units))
+            .collect()
+    }
+
+    #[getter]
+    fn evaluations(&self) -> Vec<PyEvaluation> {
+        self.inner
+            .map_each(|combined| &combined.evaluations)
+            .into_iter()
+            .zip(0..)
+            .map(|(inner, index)| PyEvaluation {
+                inner,
+                ripper: self
+                    .parsed
+                    .parsed_logs
+                    .get(index)
+                    .map(|log| log.ripper.clone()),
+                log_data: LogEntry::new(self.log_data.clone(), self.parsed.clone(), index),
+            })
+            .collect()
+    }
+
+    /// Whether every evaluated log has a verified CUETools DB result.
+    #[getter]
+    fn ctdb_verified(&self) -> bool {
+        !self.inner.evaluations.is_empty()
+            && (0..self.inner.evaluations.len()).all(|index| {
+                self.log_data
+                    .ctdb(&self.parsed)
+                    .get(index)
+                    .and_then(Option::as_ref)
+                    .is_some_and(Ctdb::verified)
+            })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<EvaluationCombined evaluator={} score='{}'>",
+            self.evaluator().name(),
+            self.inner.combined_score
+        )
+    }
+}
+
+// ============= Main Response Classes =============
+
+/// The root of a parse result. It owns the cambia-core response; every
+/// object reached from it shares that response and is built on access.
+#[pyclass(name = "CambiaResponse", frozen)]
+#[derive(Clone)]
+pub struct PyCambiaResponse {
+    inner: Shared<CambiaResponse>,
+    // CUETools DB results and disc info per log, which cambia-core does
+    // not parse
+    log_data: Shared<LogData>,
+}
+
+#[pymethods]
+impl PyCambiaResponse {
+    #[getter]
+    fn id<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.inner.id)
+    }
+
     #[getter]
-    fn name(&self) -> &str {
-        match self {
-            PyEvaluationUnitField::Encoding => "Encoding",
-            PyEvaluationUnitField::RipperVersion => "RipperVersion",
-            PyEvaluationUnitField::Drive => "Drive",
-            PyEvaluationUnitField::Ripper => "Ripper",
-            PyEvaluationUnitField::Offset => "Offset",
-            PyEvaluationUnitField::Cache => "Cache",
-            PyEvaluationUnitField::TestAndCopy => "TestAndCopy",
-            PyEvaluationUnitField::Encoder => "Encoder",
-            PyEvaluationUnitField::Checksum => "Checksum",
-            PyEvaluationUnitField::MediaType => "MediaType",
-            PyEvaluationUnitField::ReadMode => "ReadMode",
-            PyEvaluationUnitField::MaxRetryCount => "MaxRetryCount",
-            PyEvaluationUnitField::AccurateStream => "AccurateStream",
-            PyEvaluationUnitField::C2 => "C2",
-            PyEvaluationUnitField::SilentSamples => "SilentSamples",
-            PyEvaluationUnitField::NullSamples => "NullSamples",
-            PyEvaluationUnitField::Gap => "Gap",
-            PyEvaluationUnitField::Tag => "Tag",
-            PyEvaluationUnitField::Gain => "Gain",
-            PyEvaluationUnitField::RangeSplit => "RangeSplit",
-            PyEvaluationUnitField::Samples => "Samples",
-            PyEvaluationUnitField::SilentBlocks => "SilentBlocks",
-            PyEvaluationUnitField::Normalization => "Normalization",
-            PyEvaluationUnitField::Filename => "Filename",
-            PyEvaluationUnitField::ReadError => "ReadError",
-            PyEvaluationUnitField::SkipError => "SkipError",
-            PyEvaluationUnitField::JitterGenericError => "JitterGenericError",
-            PyEvaluationUnitField::JitterEdgeError => "JitterEdgeError",
-            PyEvaluationUnitField::JitterAtomError => "JitterAtomError",
-            PyEvaluationUnitField::DriftError => "DriftError",
-            PyEvaluationUnitField::DroppedError => "DroppedError",
-            PyEvaluationUnitField::DuplicatedError => "DuplicatedError",
-            PyEvaluationUnitField::InconsistentErrorSectors => "InconsistentErrorSectors",
-            PyEvaluationUnitField::DamagedSector => "DamagedSector",
-            PyEvaluationUnitField::Abort => "Abort",
+    fn parsed(&self) -> PyParsedLogCombined {
+        PyParsedLogCombined {
+            inner: self.inner.map(|response| &response.parsed),
+            log_data: self.log_data.clone(),
         }
     }
 
     #[getter]
-    fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
-        // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyEvaluationUnitField::Encoding => EvaluationUnitField::Encoding,
-            PyEvaluationUnitField::RipperVersion => EvaluationUnitField::RipperVersion,
-            PyEvaluationUnitField::Drive => EvaluationUnitField::Drive,
-            PyEvaluationUnitField::Ripper => EvaluationUnitField::Ripper,
-            PyEvaluationUnitField::Offset => EvaluationUnitField::Offset,
-            PyEvaluationUnitField::Cache => EvaluationUnitField::Cache,
-            PyEvaluationUnitField::TestAndCopy => EvaluationUnitField::TestAndCopy,
-            PyEvaluationUnitField::Encoder => EvaluationUnitField::Encoder,
-            PyEvaluationUnitField::Checksum => EvaluationUnitField::Checksum,
-            PyEvaluationUnitField::MediaType => EvaluationUnitField::MediaType,
-            PyEvaluationUnitField::ReadMode => EvaluationUnitField::ReadMode,
-            PyEvaluationUnitField::MaxRetryCount => EvaluationUnitField::MaxRetryCount,
-            PyEvaluationUnitField::AccurateStream => EvaluationUnitField::AccurateStream,
-            PyEvaluationUnitField::C2 => EvaluationUnitField::C2,
-            PyEvaluationUnitField::SilentSamples => EvaluationUnitField::SilentSamples,
-            PyEvaluationUnitField::NullSamples => EvaluationUnitField::NullSamples,
-            PyEvaluationUnitField::Gap => EvaluationUnitField::Gap,
-            PyEvaluationUnitField::Tag => EvaluationUnitField::Tag,
-            PyEvaluationUnitField::Gain => EvaluationUnitField::Gain,
-            PyEvaluationUnitField::RangeSplit => EvaluationUnitField::RangeSplit,
-            PyEvaluationUnitField::Samples => EvaluationUnitField::Samples,
-            PyEvaluationUnitField::SilentBlocks => EvaluationUnitField::SilentBlocks,
-            PyEvaluationUnitField::Normalization => EvaluationUnitField::Normalization,
-            PyEvaluationUnitField::Filename => EvaluationUnitField::Filename,
-            PyEvaluationUnitField::ReadError => EvaluationUnitField::ReadError,
-            PyEvaluationUnitField::SkipError => EvaluationUnitField::SkipError,
-            PyEvaluationUnitField::JitterGenericError => EvaluationUnitField::JitterGenericError,
-            PyEvaluationUnitField::JitterEdgeError => EvaluationUnitField::JitterEdgeError,
-            PyEvaluationUnitField::JitterAtomError => EvaluationUnitField::JitterAtomError,
-            PyEvaluationUnitField::DriftError => EvaluationUnitField::DriftError,
-            PyEvaluationUnitField::DroppedError => EvaluationUnitField::DroppedError,
-            PyEvaluationUnitField::DuplicatedError => EvaluationUnitField::DuplicatedError,
-            PyEvaluationUnitField::InconsistentErrorSectors => {
-                EvaluationUnitField::InconsistentErrorSectors
-            }
-            PyEvaluationUnitField::DamagedSector => EvaluationUnitField::DamagedSector,
-            PyEvaluationUnitField::Abort => EvaluationUnitField::Abort,
-        };
-        Ok(pythonize(py, &rust_enum)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
-            .unbind())
+    fn evaluation_combined(&self) -> Vec<PyEvaluationCombined> {
+        self.inner
+            .map_each(|response| &response.evaluation_combined)
+            .into_iter()
+            .map(|inner| PyEvaluationCombined {
+                inner,
+                parsed: self.inner.map(|response| &response.parsed),
+                log_data: self.log_data.clone(),
+            })
+            .collect()
     }
 
     fn __repr__(&self) -> String {
-        format!("<EvaluationUnitField.{}>", self.name())
+        format!(
+            "<CambiaResponse evaluations={}>",
+            self.inner.evaluation_combined.len()
+        )
     }
 }
 
-impl From<&EvaluationUnitField> for PyEvaluationUnitField {
-    fn from(field: &EvaluationUnitField) -> Self {
-        match field {
-            EvaluationUnitField::Encoding => PyEvaluationUnitField::Encoding,
-            EvaluationUnitField::RipperVersion => PyEvaluationUnitField::RipperVersion,
-            EvaluationUnitField::Drive => PyEvaluationUnitField::Drive,
-            EvaluationUnitField::Ripper => PyEvaluationUnitField::Ripper,
-            EvaluationUnitField::Offset => PyEvaluationUnitField::Offset,
-            EvaluationUnitField::Cache => PyEvaluationUnitField::Cache,
-            EvaluationUnitField::TestAndCopy => PyEvaluationUnitField::TestAndCopy,
-            EvaluationUnitField::Encoder => PyEvaluationUnitField::Encoder,
-            EvaluationUnitField::Checksum => PyEvaluationUnitField::Checksum,
-            EvaluationUnitField::MediaType => PyEvaluationUnitField::MediaType,
-            EvaluationUnitField::ReadMode => PyEvaluationUnitField::ReadMode,
-            EvaluationUnitField::MaxRetryCount => PyEvaluationUnitField::MaxRetryCount,
-            EvaluationUnitField::AccurateStream => PyEvaluationUnitField::AccurateStream,
-            EvaluationUnitField::C2 => PyEvaluationUnitField::C2,
-            EvaluationUnitField::SilentSamples => PyEvaluationUnitField::SilentSamples,
-            EvaluationUnitField::NullSamples => PyEvaluationUnitField::NullSamples,
-            EvaluationUnitField::Gap => PyEvaluationUnitField::Gap,
-            EvaluationUnitField::Tag => PyEvaluationUnitField::Tag,
-            EvaluationUnitField::Gain => PyEvaluationUnitField::Gain,
-            EvaluationUnitField::RangeSplit => PyEvaluationUnitField::RangeSplit,
-            EvaluationUnitField::Samples => PyEvaluationUnitField::Samples,
-            EvaluationUnitField::SilentBlocks => PyEvaluationUnitField::SilentBlocks,
-            EvaluationUnitField::Normalization => PyEvaluationUnitField::Normalization,
-            EvaluationUnitField::Filename => PyEvaluationUnitField::Filename,
-            EvaluationUnitField::ReadError => PyEvaluationUnitField::ReadError,
-            EvaluationUnitField::SkipError => PyEvaluationUnitField::SkipError,
-            EvaluationUnitField::JitterGenericError => PyEvaluationUnitField::JitterGenericError,
-            EvaluationUnitField::JitterEdgeError => PyEvaluationUnitField::JitterEdgeError,
-            EvaluationUnitField::JitterAtomError => PyEvaluationUnitField::JitterAtomError,
-            EvaluationUnitField::DriftError => PyEvaluationUnitField::DriftError,
-            EvaluationUnitField::DroppedError => PyEvaluationUnitField::DroppedError,
-            EvaluationUnitField::DuplicatedError => PyEvaluationUnitField::DuplicatedError,
-            EvaluationUnitField::InconsistentErrorSectors => {
-                PyEvaluationUnitField::InconsistentErrorSectors
-            }
-            EvaluationUnitField::DamagedSector => PyEvaluationUnitField::DamagedSector,
-            EvaluationUnitField::Abort => PyEvaluationUnitField::Abort,
+impl PyCambiaResponse {
+    pub fn from_response(response: CambiaResponse) -> Self {
+        Self::with_log_data(
+            response,
+            Shared::new(LogData::known(Vec::new(), Vec::new())),
+        )
+    }
+
+    /// A response with what is read from the text of its logs beside it.
+    pub fn with_log_data(response: CambiaResponse, log_data: Shared<LogData>) -> Self {
+        PyCambiaResponse {
+            inner: Shared::new(response),
+            log_data,
         }
     }
 }
 
-#[pyclass(name = "EvaluationUnitClass")]
-#[derive(Clone, PartialEq)]
-pub enum PyEvaluationUnitClass {
-    Critical,
-    Bad,
-    Neutral,
-    Good,
-    Perfect,
+// ============= Archive Classes =============
+
+/// Scores of a log before and after a change, from `compare()`.
+#[pyclass(name = "ScoreComparison", frozen)]
+#[derive(Clone)]
+pub struct PyScoreComparison {
+    original: PyCambiaResponse,
+    modified: PyCambiaResponse,
+    delta: Option<i64>,
+}
+
+impl PyScoreComparison {
+    pub fn new(original: CambiaResponse, modified: CambiaResponse) -> Self {
+        let score = |response: &CambiaResponse| {
+            response
+                .evaluation_combined
+                .first()
+                .and_then(|evaluation| scores::parse(&evaluation.combined_score))
+        };
+        PyScoreComparison {
+            delta: score(&modified).zip(score(&original)).map(|(m, o)| m - o),
+            original: PyCambiaResponse::from_response(original),
+            modified: PyCambiaResponse::from_response(modified),
+        }
+    }
 }
 
 #[pymethods]
-impl PyEvaluationUnitClass {
+impl PyScoreComparison {
     #[getter]
-    fn name(&self) -> &str {
-        match self {
-            PyEvaluationUnitClass::Critical => "Critical",
-            PyEvaluationUnitClass::Bad => "Bad",
-            PyEvaluationUnitClass::Neutral => "Neutral",
-            PyEvaluationUnitClass::Good => "Good",
-            PyEvaluationUnitClass::Perfect => "Perfect",
-        }
+    fn original(&self) -> PyCambiaResponse {
+        self.original.clone()
     }
 
     #[getter]
-    fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
-        // Convert to original Rust enum and serialize with pythonize
-        let rust_enum = match self {
-            PyEvaluationUnitClass::Critical => EvaluationUnitClass::Critical,
-            PyEvaluationUnitClass::Bad => EvaluationUnitClass::Bad,
-            PyEvaluationUnitClass::Neutral => EvaluationUnitClass::Neutral,
-            PyEvaluationUnitClass::Good => EvaluationUnitClass::Good,
-            PyEvaluationUnitClass::Perfect => EvaluationUnitClass::Perfect,
-        };
-        Ok(pythonize(py, &rust_enum)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?
-            .unbind())
+    fn modified(&self) -> PyCambiaResponse {
+        self.modified.clone()
     }
 
-    fn __repr__(&self) -> String {
-        format!("<EvaluationUnitClass.{}>", self.name())
+    /// Modified score minus original score, or None if either is not
+    /// numeric.
+    #[getter]
+    fn delta(&self) -> Option<i64> {
+        self.delta
     }
-}
 
-impl From<&EvaluationUnitClass> for PyEvaluationUnitClass {
-    fn from(class: &EvaluationUnitClass) -> Self {
-        match class {
-            EvaluationUnitClass::Critical => PyEvaluationUnitClass::Critical,
-            EvaluationUnitClass::Bad => PyEvaluationUnitClass::Bad,
-            EvaluationUnitClass::Neutral => PyEvaluationUnitClass::Neutral,
-            EvaluationUnitClass::Good => PyEvaluationUnitClass::Good,
-            EvaluationUnitClass::Perfect => PyEvaluationUnitClass::Perfect,
+    fn __repr__(&self) -> String {
+        match self.delta {
+            Some(delta) => format!("<ScoreComparison delta={:+}>", delta),
+            None => "<ScoreComparison delta=None>".to_string(),
         }
     }
 }
 
-#[pyclass(name = "EvaluationUnitData")]
+#[pyclass(name = "ArchiveEntry")]
 #[derive(Clone)]
-pub struct PyEvaluationUnitData {
+pub struct PyArchiveEntry {
     #[pyo3(get)]
-    pub scope: PyEvaluationUnitScope,
+    pub path: String,
     #[pyo3(get)]
-    pub field: PyEvaluationUnitField,
+    pub response: Option<PyCambiaResponse>,
     #[pyo3(get)]
-    pub message: String,
-    #[pyo3(get, name = "classification")]
-    pub classification: PyEvaluationUnitClass,
+    pub error: Option<String>,
 }
 
-impl From<&EvaluationUnitData> for PyEvaluationUnitData {
-    fn from(data: &EvaluationUnitData) -> Self {
-        PyEvaluationUnitData {
-            scope: PyEvaluationUnitScope::from(&data.scope),
-            field: PyEvaluationUnitField::from(&data.field),
-            message: data.message.clone(),
-            classification: PyEvaluationUnitClass::from(&data.class),
+impl PyArchiveEntry {
+    pub fn from_result(path: String, result: Result<PyCambiaResponse, String>) -> Self {
+        match result {
+            Ok(response) => PyArchiveEntry {
+                path,
+                response: Some(response),
+                error: None,
+            },
+            Err(error) => PyArchiveEntry {
+                path,
+                response: None,
+                error: Some(error),
+            },
         }
     }
 }
 
 #[pymethods]
-impl PyEvaluationUnitData {
+impl PyArchiveEntry {
     fn __repr__(&self) -> String {
         format!(
-            "<EvaluationUnitData field={} classification={}>",
-            self.field.name(),
-            self.classification.name()
+            "<ArchiveEntry path='{}' parsed={}>",
+            self.path,
+            self.response.is_some()
         )
     }
 }
 
-#[pyclass(name = "EvaluationUnit")]
+// ============= Encoding Classes =============
+
+/// An encoding a log could be in. `score` is its share of the votes of the
+/// regional guesses in `encoding::detect`, a heuristic rather than a
+/// probability reported by the detector.
+#[pyclass(name = "EncodingCandidate")]
 #[derive(Clone)]
-pub struct PyEvaluationUnit {
+pub struct PyEncodingCandidate {
     #[pyo3(get)]
-    pub unit_score: String,
+    pub encoding: String,
     #[pyo3(get)]
-    pub data: PyEvaluationUnitData,
-}
-
-impl PyEvaluationUnit {
-    pub fn from_unit(unit: &EvaluationUnit) -> Self {
-        PyEvaluationUnit {
-            unit_score: unit.unit_score.clone(),
-            data: PyEvaluationUnitData::from(&unit.data),
-        }
-    }
+    pub score: f64,
 }
 
 #[pymethods]
-impl PyEvaluationUnit {
+impl PyEncodingCandidate {
     fn __repr__(&self) -> String {
-        format!("<EvaluationUnit score='{}'>", self.unit_score)
+        format!(
+            "<EncodingCandidate encoding='{}' score={:.2}>",
+            self.encoding, self.score
+        )
     }
 }
 
-#[pyclass(name = "Evaluation")]
+#[pyclass(name = "EncodingDetection")]
 #[derive(Clone)]
-pub struct PyEvaluation {
+pub struct PyEncodingDetection {
     #[pyo3(get)]
-    pub score: String,
+    pub bom: bool,
     #[pyo3(get)]
-    pub evaluation_units: Vec<PyEvaluationUnit>,
+    pub candidates: Vec<PyEncodingCandidate>,
 }
 
-impl PyEvaluation {
-    pub fn from_evaluation(eval: &Evaluation) -> Self {
-        let units = eval
-            .evaluation_units
-            .iter()
-            .map(|u| PyEvaluationUnit::from_unit(u))
-            .collect();
-
-        PyEvaluation {
-            score: eval.score.clone(),
-            evaluation_units: units,
+impl PyEncodingDetection {
+    pub fn from_detection(detection: &Detection) -> Self {
+        PyEncodingDetection {
+            bom: detection.bom,
+            candidates: detection
+                .candidates
+                .iter()
+                .map(|(encoding, score)| PyEncodingCandidate {
+                    encoding: encoding.name().to_string(),
+                    score: *score,
+                })
+                .collect(),
         }
     }
 }
 
 #[pymethods]
-impl PyEvaluation {
+impl PyEncodingDetection {
     fn __repr__(&self) -> String {
         format!(
-            "<Evaluation score='{}' units={}>",
-            self.score,
-            self.evaluation_units.len()
+            "<EncodingDetection bom={} candidates={}>",
+            self.bom,
+            self.candidates.len()
         )
     }
 }
 
-#[pyclass(name = "EvaluationCombined")]
+// ============= Sniff Classes =============
+
+#[pyclass(name = "SniffResult")]
 #[derive(Clone)]
-pub struct PyEvaluationCombined {
+pub struct PySniffResult {
+    #[pyo3(get)]
+    pub ripper: Option<PyRipper>,
+    #[pyo3(get)]
+    pub ripper_version: Option<String>,
     #[pyo3(get)]
-    pub evaluator: PyEvaluatorType,
+    pub language: Option<String>,
     #[pyo3(get)]
-    pub combined_score: String,
+    pub encoding: String,
+    #[pyo3(get)]
+    pub combined: bool,
     #[pyo3(get)]
-    pub evaluations: Vec<PyEvaluation>,
+    pub log_count: usize,
+    #[pyo3(get)]
+    pub has_checksum: bool,
 }
 
-impl PyEvaluationCombined {
-    pub fn from_combined(combined: &EvaluationCombined) -> Self {
-        let evaluations = combined
-            .evaluations
-            .iter()
-            .map(|e| PyEvaluation::from_evaluation(e))
-            .collect();
-
-        PyEvaluationCombined {
-            evaluator: PyEvaluatorType::from(&combined.evaluator),
-            combined_score: combined.combined_score.clone(),
-            evaluations,
+impl PySniffResult {
+    pub fn from_sniff(sniff: &Sniff) -> Self {
+        PySniffResult {
+            ripper: sniff.header.as_ref().map(|h| PyRipper::from(&h.ripper)),
+            ripper_version: sniff.header.as_ref().and_then(|h| h.version.clone()),
+            language: sniff
+                .header
+                .as_ref()
+                .and_then(|h| h.language.map(str::to_string)),
+            encoding: sniff.encoding.to_string(),
+            combined: sniff.log_count > 1,
+            log_count: sniff.log_count,
+            has_checksum: sniff.has_checksum,
         }
     }
 }
 
 #[pymethods]
-impl PyEvaluationCombined {
+impl PySniffResult {
     fn __repr__(&self) -> String {
+        let ripper = self.ripper.as_ref().map_or("None", |r| r.name());
         format!(
-            "<EvaluationCombined evaluator={} score='{}'>",
-            self.evaluator.name(),
-            self.combined_score
+            "<SniffResult ripper={} version={:?} log_count={}>",
+            ripper,
+            self.ripper_version.as_deref().unwrap_or(""),
+            self.log_count
         )
     }
 }
 
-// ============= Main Response Classes =============
+// ============= Translation Classes =============
 
-#[pyclass(name = "CambiaResponse")]
+#[pyclass(name = "TranslatedLine")]
 #[derive(Clone)]
-pub struct PyCambiaResponse {
-    pub id: Vec<u8>,
+pub struct PyTranslatedLine {
     #[pyo3(get)]
-    pub parsed: PyParsedLogCombined,
+    pub number: usize,
     #[pyo3(get)]
-    pub evaluation_combined: Vec<PyEvaluationCombined>,
+    pub original: String,
+    #[pyo3(get)]
+    pub text: String,
+    #[pyo3(get)]
+    pub recognized: bool,
 }
 
 #[pymethods]
-impl PyCambiaResponse {
-    #[getter]
-    fn id<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
-        PyBytes::new(py, &self.id)
-    }
-
+impl PyTranslatedLine {
     fn __repr__(&self) -> String {
         format!(
-            "<CambiaResponse evaluations={}>",
-            self.evaluation_combined.len()
+            "<TranslatedLine number={} recognized={}>",
+            self.number, self.recognized
         )
     }
 }
 
-impl PyCambiaResponse {
-    pub fn from_response(response: &CambiaResponse) -> Self {
-        let evaluation_combined = response
-            .evaluation_combined
-            .iter()
-            .map(|e| PyEvaluationCombined::from_combined(e))
-            .collect();
+#[pyclass(name = "TranslatedLog")]
+#[derive(Clone)]
+pub struct PyTranslatedLog {
+    #[pyo3(get)]
+    pub text: String,
+    #[pyo3(get)]
+    pub source_language: Option<String>,
+    #[pyo3(get)]
+    pub target: String,
+    #[pyo3(get)]
+    pub lines: Vec<PyTranslatedLine>,
+}
 
-        PyCambiaResponse {
-            id: response.id.clone(),
-            parsed: PyParsedLogCombined::from_combined(&response.parsed),
-            evaluation_combined,
+impl PyTranslatedLog {
+    pub fn from_translation(translation: &Translation, target: &str) -> Self {
+        PyTranslatedLog {
+            text: translation.text(),
+            source_language: translation.source_language.map(str::to_string),
+            target: target.to_string(),
+            lines: translation
+                .lines
+                .iter()
+                .enumerate()
+                .map(|(index, line)| PyTranslatedLine {
+                    number: index + 1,
+                    original: line.original.clone(),
+                    text: line.text.clone(),
+                    recognized: line.recognized,
+                })
+                .collect(),
         }
     }
 }
 
+#[pymethods]
+impl PyTranslatedLog {
+    /// Lines that no language table recognized, as kept in `text`.
+    #[getter]
+    fn untranslated(&self) -> Vec<PyTranslatedLine> {
+        self.lines
+            .iter()
+            .filter(|line| !line.recognized)
+            .cloned()
+            .collect()
+    }
+
+    /// Whether every line was translated or needed no translation.
+    #[getter]
+    fn complete(&self) -> bool {
+        self.lines.iter().all(|line| line.recognized)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<TranslatedLog source_language={:?} target='{}' lines={} complete={}>",
+            self.source_language.as_deref().unwrap_or(""),
+            self.target,
+            self.lines.len(),
+            if self.complete() { "True" } else { "False" }
+        )
+    }
+}
+
 pub fn register_classes(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Enums
     m.add_class::<PyRipper>()?;
@@ -1530,8 +4706,16 @@ pub fn register_classes(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyEvaluationUnitScope>()?;
     m.add_class::<PyEvaluationUnitField>()?;
     m.add_class::<PyEvaluationUnitClass>()?;
+    m.add_class::<PyTrackType>()?;
+    m.add_class::<PyDiscLayout>()?;
+    m.add_class::<PyAccurateRipOffsetKind>()?;
+    m.add_class::<PyAccurateRipTotalKind>()?;
+    m.add_class::<PyCtdbStatus>()?;
+    m.add_class::<PyErrorKind>()?;
+    m.add_class::<PyHtoaExtraction>()?;
 
     // Data classes
+    m.add_class::<PyMsf>()?;
     m.add_class::<PyTocEntry>()?;
     m.add_class::<PyTocHash>()?;
     m.add_class::<PyTocRaw>()?;
@@ -1542,15 +4726,43 @@ pub fn register_classes(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyAccurateRipUnit>()?;
     m.add_class::<PyTestAndCopy>()?;
     m.add_class::<PyTrackErrorRange>()?;
+    m.add_class::<PyDiscErrorRange>()?;
     m.add_class::<PyTrackErrorData>()?;
     m.add_class::<PyTrackError>()?;
     m.add_class::<PyTrackEntry>()?;
     m.add_class::<PyParsedLog>()?;
     m.add_class::<PyParsedLogCombined>()?;
+    m.add_class::<PyErrorSpan>()?;
+    m.add_class::<PyErrorMap>()?;
+    m.add_class::<PyRangeTrack>()?;
+    m.add_class::<PyRangeSplit>()?;
+    m.add_class::<PyCoverageIssue>()?;
+    m.add_class::<PyCoverage>()?;
+    m.add_class::<PyHtoaInfo>()?;
+    m.add_class::<PyAccurateRipSummary>()?;
+    m.add_class::<PyAccurateRipGroup>()?;
+    m.add_class::<PyAccurateRipOutlier>()?;
+    m.add_class::<PyAccurateRipOutcome>()?;
+    m.add_class::<PyAccurateRipConsistency>()?;
+    m.add_class::<PyCtdbTrack>()?;
+    m.add_class::<PyCtdbResult>()?;
+    m.add_class::<PyIsrc>()?;
+    m.add_class::<PyMcn>()?;
+    m.add_class::<PyTrackInfo>()?;
+    m.add_class::<PyDiscInfo>()?;
     m.add_class::<PyEvaluationUnitData>()?;
     m.add_class::<PyEvaluationUnit>()?;
     m.add_class::<PyEvaluation>()?;
+    m.add_class::<PyTrackEvaluation>()?;
+    m.add_class::<PyRemediation>()?;
     m.add_class::<PyEvaluationCombined>()?;
     m.add_class::<PyCambiaResponse>()?;
+    m.add_class::<PyScoreComparison>()?;
+    m.add_class::<PyArchiveEntry>()?;
+    m.add_class::<PyEncodingCandidate>()?;
+    m.add_class::<PyEncodingDetection>()?;
+    m.add_class::<PySniffResult>()?;
+    m.add_class::<PyTranslatedLine>()?;
+    m.add_class::<PyTranslatedLog>()?;
     Ok(())
 }
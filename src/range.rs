@@ -0,0 +1,174 @@
+// Splitting the pseudo-track of a range rip into per-track entries
+use cambia_core::integrity::Integrity;
+use cambia_core::parser::ParsedLog;
+use cambia_core::toc::TocEntry;
+use cambia_core::track::{TestAndCopy, TrackEntry, TrackErrorRange};
+use pyo3::PyResult;
+
+use crate::build;
+use crate::error_kind::{self, ErrorKind};
+use crate::position;
+
+/// A TOC track of a split log and where it lies on the disc.
+pub struct Span {
+    pub track: u8,
+    pub start_sector: u32,
+    pub end_sector: u32,
+    /// Whether the entry was made from the range rather than logged.
+    pub derived: bool,
+}
+
+pub struct Split {
+    pub log: ParsedLog,
+    pub spans: Vec<Span>,
+    /// The pseudo-track the entries were made from.
+    pub range: Option<TrackEntry>,
+    /// Errors per kind that could not be given to a track: counted without
+    /// a position, or outside every TOC track.
+    pub unplaced: Vec<(ErrorKind, u32)>,
+}
+
+/// Overlap of an absolute sector range with a TOC entry, relative to the
+/// start of the entry.
+fn clip(start: u32, end: u32, entry: &TocEntry) -> Option<(u32, u32)> {
+    let low = start.max(entry.start_sector);
+    let high = end.min(entry.end_sector);
+    (low <= high).then(|| (low - entry.start_sector, high - low + 1))
+}
+
+/// Range rips count error positions from the start of the range, which is
+/// the start of the first TOC track.
+fn range_start(entries: &[TocEntry]) -> u32 {
+    entries.first().map_or(0, |entry| entry.start_sector)
+}
+
+fn derive(range: &TrackEntry, entry: &TocEntry, num: u8, base: u32) -> PyResult<TrackEntry> {
+    // A matching test and copy over the whole range matches on every part
+    // of it; a mismatch cannot be pinned to a track.
+    let integrity = match range.test_and_copy.integrity {
+        Integrity::Match => Integrity::Match,
+        _ => Integrity::Unknown,
+    };
+    let mut errors = build::no_errors();
+    for (kind, data) in error_kind::each(&range.errors) {
+        let target = kind.data_mut(&mut errors);
+        for error in &data.ranges {
+            let (start, length) = position::range_sectors(error)?;
+            let start = base.saturating_add(start);
+            let end = start.saturating_add(length.max(1) - 1);
+            if let Some((offset, clipped)) = clip(start, end, entry) {
+                target.ranges.push(TrackErrorRange {
+                    start: build::sectors_to_time(offset)?,
+                    length: build::sectors_to_time(if length == 0 { 0 } else { clipped })?,
+                });
+                target.count += 1;
+            }
+        }
+    }
+    Ok(TrackEntry {
+        num,
+        is_range: false,
+        aborted: range.aborted,
+        filenames: range.filenames.clone(),
+        peak_level: None,
+        pregap_length: None,
+        extraction_speed: None,
+        gain: None,
+        preemphasis: range.preemphasis,
+        test_and_copy: TestAndCopy {
+            test_hash: String::new(),
+            copy_hash: String::new(),
+            integrity,
+        },
+        errors,
+        ar_info: Vec::new(),
+    })
+}
+
+/// Replace the range pseudo-track of a log with one entry per TOC track
+/// that has no entry of its own.
+///
+/// Error ranges go to the tracks they fall in, split where they cross a
+/// track boundary. AccurateRip results go to tracks in TOC order when
+/// there is one per TOC track, as in EAC's range summary. Peak level,
+/// speed and CRCs describe the whole range and stay on it. Logs without a
+/// range keep their tracks.
+pub fn split(log: &ParsedLog) -> PyResult<Split> {
+    let entries = &log.toc.raw.entries;
+    let range = log.tracks.iter().find(|track| track.is_range).cloned();
+    let logged = |num: u32| {
+        log.tracks
+            .iter()
+            .any(|track| !track.is_range && u32::from(track.num) == num)
+    };
+
+    let mut tracks: Vec<TrackEntry> = log
+        .tracks
+        .iter()
+        .filter(|track| !track.is_range)
+        .cloned()
+        .collect();
+    let mut spans = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let Ok(num) = u8::try_from(entry.track) else {
+            continue;
+        };
+        let derived = match &range {
+            Some(range) if !logged(entry.track) => {
+                let mut track = derive(range, entry, num, range_start(entries))?;
+                if range.ar_info.len() == entries.len() {
+                    track.ar_info.push(range.ar_info[index].clone());
+                }
+                tracks.push(track);
+                true
+            }
+            _ if logged(entry.track) => false,
+            _ => continue,
+        };
+        spans.push(Span {
+            track: num,
+            start_sector: entry.start_sector,
+            end_sector: entry.end_sector,
+            derived,
+        });
+    }
+    tracks.sort_by_key(|track| track.num);
+
+    let unplaced = match &range {
+        Some(range) => unplaced(range, entries)?,
+        None => Vec::new(),
+    };
+    let mut log = log.clone();
+    log.tracks = tracks;
+    Ok(Split {
+        log,
+        spans,
+        range,
+        unplaced,
+    })
+}
+
+fn unplaced(range: &TrackEntry, entries: &[TocEntry]) -> PyResult<Vec<(ErrorKind, u32)>> {
+    let base = range_start(entries);
+    let mut unplaced = Vec::new();
+    for (kind, data) in error_kind::each(&range.errors) {
+        let mut outside = 0;
+        for error in &data.ranges {
+            let (start, length) = position::range_sectors(error)?;
+            let start = base.saturating_add(start);
+            let end = start.saturating_add(length.max(1) - 1);
+            if !entries
+                .iter()
+                .any(|entry| clip(start, end, entry).is_some())
+            {
+                outside += 1;
+            }
+        }
+        let positionless = (data.count as usize).saturating_sub(data.ranges.len());
+        let count = (outside + positionless) as u32;
+        if count > 0 {
+            unplaced.push((kind, count));
+        }
+    }
+    Ok(unplaced)
+}
@@ -0,0 +1,233 @@
+// How to fix the settings behind each evaluation deduction, per ripper
+use cambia_core::evaluate::EvaluationUnitField;
+use cambia_core::extract::Ripper;
+
+use crate::error_kind::ErrorKind;
+
+/// Instructions for avoiding one kind of deduction on the next rip.
+pub struct Remediation {
+    /// The setting or menu path to change, if the fix is a setting.
+    pub setting: Option<&'static str>,
+    /// The value the setting should have.
+    pub value: Option<&'static str>,
+    /// A one-sentence instruction for the user.
+    pub advice: &'static str,
+    /// Whether the existing rip cannot be trusted and the disc has to be
+    /// ripped again, as opposed to only changing the setting for next time.
+    pub rerip: bool,
+}
+
+const fn setting(
+    setting: &'static str,
+    value: &'static str,
+    advice: &'static str,
+    rerip: bool,
+) -> Remediation {
+    Remediation {
+        setting: Some(setting),
+        value: Some(value),
+        advice,
+        rerip,
+    }
+}
+
+const fn advice(advice: &'static str, rerip: bool) -> Remediation {
+    Remediation {
+        setting: None,
+        value: None,
+        advice,
+        rerip,
+    }
+}
+
+static EAC_CACHE: Remediation = setting(
+    "Drive Options > Drive > Drive caches audio data",
+    "Checked",
+    "Enable cache defeating so that re-reads come from the disc, not the drive cache.",
+    true,
+);
+static EAC_ACCURATE_STREAM: Remediation = setting(
+    "Drive Options > Drive > Drive has 'Accurate Stream' feature",
+    "Yes",
+    "Enable Accurate Stream if the drive supports it.",
+    true,
+);
+static EAC_C2: Remediation = setting(
+    "Drive Options > Drive > Make use of C2 error information",
+    "No",
+    "Disable C2 pointers; few drives report them reliably.",
+    true,
+);
+static EAC_READ_MODE: Remediation = setting(
+    "Drive Options > Extraction Method",
+    "Secure mode",
+    "Rip in secure mode so that every sector is verified.",
+    true,
+);
+static EAC_RETRY: Remediation = setting(
+    "Drive Options > Extraction Method > Error recovery quality",
+    "High",
+    "Use the highest error recovery quality.",
+    true,
+);
+static EAC_TEST_AND_COPY: Remediation = setting(
+    "Action > Test & Copy Selected Tracks",
+    "Test & Copy",
+    "Rip with Test & Copy so that the test and copy CRCs can be compared.",
+    true,
+);
+static EAC_OFFSET: Remediation = setting(
+    "Drive Options > Offset / Speed > Use read sample offset correction",
+    "The drive's offset from the AccurateRip database",
+    "Set the read offset for the drive, using Detect Read Features to look it up.",
+    true,
+);
+static EAC_GAP: Remediation = setting(
+    "Action > Append Gaps To Previous Tracks",
+    "Appended to previous track",
+    "Detect gaps (Action > Detect Gaps) and append them to the previous track.",
+    true,
+);
+static EAC_RANGE: Remediation = setting(
+    "Action > Copy Selected Tracks",
+    "Uncompressed or Compressed per-track copy",
+    "Rip each track to its own file instead of the disc as one range.",
+    true,
+);
+static EAC_NULL_SAMPLES: Remediation = setting(
+    "EAC Options > Extraction > Use null samples in CRC calculations",
+    "Yes",
+    "Include null samples in CRC calculations.",
+    false,
+);
+static EAC_SILENT_SAMPLES: Remediation = setting(
+    "EAC Options > Extraction > Fill up missing offset samples with silence",
+    "Yes",
+    "Fill missing offset samples with silence.",
+    true,
+);
+static EAC_SILENT_BLOCKS: Remediation = setting(
+    "EAC Options > Extraction > Delete leading and trailing silent blocks",
+    "No",
+    "Keep leading and trailing silence.",
+    true,
+);
+static EAC_NORMALIZATION: Remediation = setting(
+    "EAC Options > Normalize > Normalize to",
+    "No",
+    "Disable normalization; it alters the audio.",
+    true,
+);
+static EAC_TAG: Remediation = setting(
+    "Compression Options > ID3 Tag > Use ID3 tag",
+    "No",
+    "Disable ID3 tags for FLAC output.",
+    false,
+);
+static EAC_CHECKSUM: Remediation = setting(
+    "EAC Options > Tools > Append checksum to status report",
+    "Yes",
+    "Enable the log checksum so that the log can be verified.",
+    false,
+);
+static EAC_VERSION: Remediation = setting(
+    "Exact Audio Copy version",
+    "0.99 or later",
+    "Update EAC and rip again.",
+    true,
+);
+
+static XLD_READ_MODE: Remediation = setting(
+    "Preferences > CD Rip > Ripper Mode",
+    "XLD Secure Ripper",
+    "Use the XLD Secure Ripper mode.",
+    true,
+);
+static XLD_C2: Remediation = setting(
+    "Preferences > CD Rip > Use C2 Error Pointers",
+    "Off",
+    "Disable C2 pointers; few drives report them reliably.",
+    true,
+);
+static XLD_TEST_AND_COPY: Remediation = setting(
+    "Preferences > CD Rip > Test before copy",
+    "On",
+    "Enable test before copy so that the test and copy CRCs can be compared.",
+    true,
+);
+static XLD_OFFSET: Remediation = setting(
+    "Preferences > CD Rip > Read offset correction value",
+    "The drive's offset from the AccurateRip database",
+    "Set the read offset for the drive.",
+    true,
+);
+static XLD_GAP: Remediation = advice(
+    "Let XLD detect pre-gaps before ripping and append them to the previous track.",
+    true,
+);
+
+static WHIPPER_CACHE: Remediation = setting(
+    "whipper drive analyze",
+    "defeats_cache = True",
+    "Analyze the drive so that whipper knows how to defeat its cache.",
+    true,
+);
+static WHIPPER_OFFSET: Remediation = setting(
+    "whipper offset find, or read_offset in whipper.conf",
+    "The drive's offset from the AccurateRip database",
+    "Find and save the drive's read offset.",
+    true,
+);
+static WHIPPER_VERSION: Remediation = setting(
+    "whipper version",
+    "0.7.3 or later",
+    "Update whipper and rip again.",
+    true,
+);
+
+static RIPPER: Remediation = advice("Rip with EAC, XLD or whipper.", true);
+static READ_ERRORS: Remediation = advice(
+    "Clean the disc, or try another drive, and rip the affected tracks again.",
+    true,
+);
+static ABORT: Remediation = advice("Let the rip run to completion.", true);
+
+/// Look up how to fix a deduction for a log from the given ripper.
+///
+/// Returns None for deductions with no fix on the user's side, such as
+/// an unknown drive or a CD-R, and for rippers without an entry.
+pub fn lookup(field: &EvaluationUnitField, ripper: &Ripper) -> Option<&'static Remediation> {
+    use EvaluationUnitField as Field;
+
+    let remediation = match (ripper, field) {
+        (Ripper::EAC, Field::Cache) => &EAC_CACHE,
+        (Ripper::EAC, Field::AccurateStream) => &EAC_ACCURATE_STREAM,
+        (Ripper::EAC, Field::C2) => &EAC_C2,
+        (Ripper::EAC, Field::ReadMode) => &EAC_READ_MODE,
+        (Ripper::EAC, Field::MaxRetryCount) => &EAC_RETRY,
+        (Ripper::EAC, Field::TestAndCopy) => &EAC_TEST_AND_COPY,
+        (Ripper::EAC, Field::Offset) => &EAC_OFFSET,
+        (Ripper::EAC, Field::Gap) => &EAC_GAP,
+        (Ripper::EAC, Field::RangeSplit) => &EAC_RANGE,
+        (Ripper::EAC, Field::NullSamples) => &EAC_NULL_SAMPLES,
+        (Ripper::EAC, Field::SilentSamples) => &EAC_SILENT_SAMPLES,
+        (Ripper::EAC, Field::SilentBlocks) => &EAC_SILENT_BLOCKS,
+        (Ripper::EAC, Field::Normalization) => &EAC_NORMALIZATION,
+        (Ripper::EAC, Field::Tag) => &EAC_TAG,
+        (Ripper::EAC, Field::Checksum) => &EAC_CHECKSUM,
+        (Ripper::EAC, Field::RipperVersion) => &EAC_VERSION,
+        (Ripper::XLD, Field::ReadMode | Field::Cache) => &XLD_READ_MODE,
+        (Ripper::XLD, Field::C2) => &XLD_C2,
+        (Ripper::XLD, Field::TestAndCopy) => &XLD_TEST_AND_COPY,
+        (Ripper::XLD, Field::Offset) => &XLD_OFFSET,
+        (Ripper::XLD, Field::Gap) => &XLD_GAP,
+        (Ripper::Whipper, Field::Cache) => &WHIPPER_CACHE,
+        (Ripper::Whipper, Field::Offset) => &WHIPPER_OFFSET,
+        (Ripper::Whipper, Field::RipperVersion) => &WHIPPER_VERSION,
+        (_, Field::Ripper) => &RIPPER,
+        (_, Field::Abort) => &ABORT,
+        (_, field) if ErrorKind::from_field(field).is_some() => &READ_ERRORS,
+        _ => return None,
+    };
+    Some(remediation)
+}
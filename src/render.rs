@@ -0,0 +1,698 @@
+// Writing a parsed log back out as the text a ripper would have logged
+use std::fmt::Write;
+
+use cambia_core::extract::{Gap, MediaType, Quartet, ReadMode, Ripper};
+use cambia_core::parser::ParsedLog;
+use cambia_core::toc::TocEntry;
+use cambia_core::track::{
+    AccurateRipConfidenceTotal, AccurateRipStatus, AccurateRipUnit, TrackEntry,
+};
+use cambia_core::util::Time;
+use pyo3::prelude::*;
+
+use crate::build::SECTORS_PER_SECOND;
+use crate::error_kind::{self, ErrorKind};
+use crate::position;
+use crate::py_classes::time_to_duration;
+
+/// Languages `render` can write, by code. Only English: `translate`'s tables
+/// map the localized wording of the bundled logs to English and do not
+/// cover enough of EAC's translations to write a localized log.
+pub const LANGUAGES: [&str; 1] = ["en"];
+
+/// Written where the checksum would be, so that a regenerated log is never
+/// mistaken for an original one.
+fn marker() -> String {
+    format!(
+        "Regenerated by pycambia {} from parsed data. This is not an original log and has no checksum.",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Write `log` as a log of the given ripper.
+///
+/// Only what `ParsedLog` holds is written, so lines the parsers do not read,
+/// such as dates and track quality, are left out or given fixed values.
+/// Peak levels are written as stored, without converting between the
+/// rippers' scales.
+pub fn render(log: &ParsedLog, ripper: &Ripper, language: &str) -> PyResult<String> {
+    if !LANGUAGES.contains(&language) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unsupported language '{}'; expected one of: {}",
+            language,
+            LANGUAGES.join(", ")
+        )));
+    }
+    // A version of another ripper would not parse as this one's
+    let same_ripper = std::mem::discriminant(&log.ripper) == std::mem::discriminant(ripper);
+    let version =
+        (same_ripper && !log.ripper_version.is_empty()).then_some(log.ripper_version.as_str());
+    let text = match ripper {
+        Ripper::EAC => eac(log, version.unwrap_or("1.6")),
+        Ripper::XLD => xld(log, version.unwrap_or("20230627 (156.2)")),
+        Ripper::Whipper => whipper(log, version.unwrap_or("0.10.0")),
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Cannot write {:?} logs; expected EAC, XLD or Whipper",
+                ripper
+            )))
+        }
+    };
+    text
+}
+
+fn seconds(time: &Time) -> PyResult<f64> {
+    Ok(time_to_duration(time)?.as_secs_f64())
+}
+
+/// `m:ss.ff` with frames, as in EAC's TOC.
+fn eac_msf(sectors: u32) -> String {
+    format!(
+        "{}:{:02}.{:02}",
+        sectors / (60 * SECTORS_PER_SECOND),
+        sectors / SECTORS_PER_SECOND % 60,
+        sectors % SECTORS_PER_SECOND
+    )
+}
+
+/// `mm:ss:ff` with frames, as in XLD's and whipper's TOC.
+fn msf(sectors: u32) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        sectors / (60 * SECTORS_PER_SECOND),
+        sectors / SECTORS_PER_SECOND % 60,
+        sectors % SECTORS_PER_SECOND
+    )
+}
+
+fn time_sectors(time: &Time) -> PyResult<u32> {
+    Ok((seconds(time)? * f64::from(SECTORS_PER_SECOND)).round() as u32)
+}
+
+/// `h:mm:ss`, as in EAC's suspicious positions.
+fn hms(secs: f64) -> String {
+    let secs = secs as u64;
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+fn entry_sectors(entry: &TocEntry) -> u32 {
+    position::span(entry.start_sector, entry.end_sector)
+}
+
+fn quartet(value: &Quartet, yes: &'static str, no: &'static str) -> Option<&'static str> {
+    match value {
+        Quartet::True => Some(yes),
+        Quartet::False => Some(no),
+        Quartet::Unknown | Quartet::Unsupported => None,
+    }
+}
+
+fn has_errors(track: &TrackEntry) -> bool {
+    error_kind::each(&track.errors).any(|(_, data)| data.count > 0)
+}
+
+/// The label of an error kind in EAC's track statistics.
+fn eac_label(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Read => "Read error",
+        ErrorKind::Skip => "Skipped (treated as error)",
+        ErrorKind::JitterGeneric => "Jitter error (maybe fixed)",
+        ErrorKind::JitterEdge => "Edge jitter error (maybe fixed)",
+        ErrorKind::JitterAtom => "Atom jitter error (maybe fixed)",
+        ErrorKind::Drift => "Drift error (maybe fixed)",
+        ErrorKind::Dropped => "Dropped bytes error (maybe fixed)",
+        ErrorKind::Duplicated => "Duplicated bytes error (maybe fixed)",
+        ErrorKind::DamagedSectors => "Damaged sector count",
+        ErrorKind::InconsistentErrorSectors => "Inconsistency in error sectors",
+        ErrorKind::MissingSamples => "Missing samples",
+    }
+}
+
+fn total(unit: &AccurateRipUnit) -> Option<u32> {
+    match unit.confidence.as_ref()?.total.as_ref()? {
+        AccurateRipConfidenceTotal::All(n) | AccurateRipConfidenceTotal::Version(n) => Some(*n),
+    }
+}
+
+fn matching(unit: &AccurateRipUnit) -> u32 {
+    unit.confidence
+        .as_ref()
+        .and_then(|confidence| confidence.matching)
+        .unwrap_or(0)
+}
+
+/// The AccurateRip summary line shared by EAC and whipper.
+fn ar_summary(log: &ParsedLog) -> &'static str {
+    let statuses: Vec<&AccurateRipStatus> = log
+        .tracks
+        .iter()
+        .flat_map(|track| track.ar_info.first())
+        .map(|unit| &unit.status)
+        .collect();
+    if statuses.is_empty()
+        || statuses
+            .iter()
+            .all(|s| matches!(s, AccurateRipStatus::NotFound))
+    {
+        "None of the tracks are present in the AccurateRip database"
+    } else if statuses.len() == log.tracks.len()
+        && statuses
+            .iter()
+            .all(|s| matches!(s, AccurateRipStatus::Match))
+    {
+        "All tracks accurately ripped"
+    } else {
+        "Some tracks could not be verified as accurate"
+    }
+}
+
+fn health(log: &ParsedLog) -> &'static str {
+    if log.tracks.iter().any(has_errors) {
+        "There were errors"
+    } else {
+        "No errors occurred"
+    }
+}
+
+fn eac(log: &ParsedLog, version: &str) -> PyResult<String> {
+    let mut out = String::new();
+    let release = &log.release_info;
+    let _ = writeln!(out, "Exact Audio Copy V{}\n", version);
+    let _ = writeln!(out, "EAC extraction logfile from 1. January 2000, 0:00\n");
+    let _ = writeln!(out, "{} / {}\n", release.artist, release.title);
+    let _ = writeln!(out, "Used drive  : {}   Adapter: 0  ID: 0\n", log.drive);
+
+    let read_mode = match log.read_mode {
+        ReadMode::Secure => Some("Secure"),
+        ReadMode::Paranoid => Some("Paranoid"),
+        ReadMode::Fast => Some("Fast"),
+        ReadMode::Burst => Some("Burst"),
+        ReadMode::Unknown => None,
+    };
+    if let Some(mode) = read_mode {
+        let _ = writeln!(out, "Read mode               : {}", mode);
+    }
+    for (label, value) in [
+        ("Utilize accurate stream", &log.accurate_stream),
+        ("Defeat audio cache     ", &log.defeat_audio_cache),
+        ("Make use of C2 pointers", &log.use_c2),
+    ] {
+        if let Some(value) = quartet(value, "Yes", "No") {
+            let _ = writeln!(out, "{} : {}", label, value);
+        }
+    }
+    out.push('\n');
+
+    if let Some(offset) = log.read_offset {
+        let _ = writeln!(
+            out,
+            "Read offset correction                      : {}",
+            offset
+        );
+    }
+    for (label, value) in [
+        ("Overread into Lead-In and Lead-Out         ", &log.overread),
+        (
+            "Fill up missing offset samples with silence",
+            &log.fill_silence,
+        ),
+        (
+            "Delete leading and trailing silent blocks  ",
+            &log.delete_silence,
+        ),
+        (
+            "Null samples used in CRC calculations      ",
+            &log.use_null_samples,
+        ),
+    ] {
+        if let Some(value) = quartet(value, "Yes", "No") {
+            let _ = writeln!(out, "{} : {}", label, value);
+        }
+    }
+    if matches!(log.normalize, Quartet::True) {
+        let _ = writeln!(out, "Normalize to                                : 100 %");
+    }
+    let gap = match log.gap_handling {
+        Gap::Append | Gap::AppendNoHtoa => Some("Appended to previous track"),
+        Gap::AppendUndetected => Some("Not detected, thus appended to previous track"),
+        Gap::Prepend => Some("Appended to next track"),
+        Gap::Discard => Some("Left out"),
+        Gap::Unknown | Gap::Inapplicable => None,
+    };
+    if let Some(gap) = gap {
+        let _ = writeln!(out, "Gap handling                                : {}", gap);
+    }
+    out.push('\n');
+
+    let _ = writeln!(
+        out,
+        "Used output format              : User Defined Encoder"
+    );
+    if let Some(id3) = quartet(&log.id3_enabled, "Yes", "No") {
+        let _ = writeln!(out, "Add ID3 tag                     : {}", id3);
+    }
+    if let Some((compressor, options)) = log.audio_encoder.split_first() {
+        let _ = writeln!(out, "Command line compressor         : {}", compressor);
+        let _ = writeln!(
+            out,
+            "Additional command line options : {}",
+            options.join(" ")
+        );
+    }
+    out.push_str("\n\n");
+
+    let _ = writeln!(out, "TOC of the extracted CD\n");
+    let _ = writeln!(
+        out,
+        "     Track |   Start  |  Length  | Start sector | End sector "
+    );
+    let _ = writeln!(
+        out,
+        "    ---------------------------------------------------------"
+    );
+    for entry in &log.toc.raw.entries {
+        let _ = writeln!(
+            out,
+            "{:>9}  | {:>8} | {:>8} |{:>10}    |{:>9}   ",
+            entry.track,
+            eac_msf(entry.start_sector),
+            eac_msf(entry_sectors(entry)),
+            entry.start_sector,
+            entry.end_sector
+        );
+    }
+    out.push_str("\n\n");
+
+    for track in &log.tracks {
+        if track.is_range {
+            let _ = writeln!(out, "Range status and errors\n\nSelected range\n");
+        } else {
+            let _ = writeln!(out, "Track {:>2}\n", track.num);
+        }
+        for filename in &track.filenames {
+            let _ = writeln!(out, "     Filename {}\n", filename);
+        }
+        if let Some(pregap) = &track.pregap_length {
+            let sectors = time_sectors(pregap)?;
+            let _ = writeln!(
+                out,
+                "     Pre-gap length  0:{:02}:{:02}.{:02}\n",
+                sectors / (60 * SECTORS_PER_SECOND),
+                sectors / SECTORS_PER_SECOND % 60,
+                sectors % SECTORS_PER_SECOND
+            );
+        }
+        for range in &track.errors.read.ranges {
+            let start = seconds(&range.start)?;
+            let end = start + seconds(&range.length)?;
+            if end - start < 1.0 {
+                let _ = writeln!(out, "     Suspicious position {}", hms(start));
+            } else {
+                let _ = writeln!(
+                    out,
+                    "     Suspicious position {} - {}",
+                    hms(start),
+                    hms(end)
+                );
+            }
+        }
+        if track.errors.missing_samples.count > 0 {
+            let _ = writeln!(out, "     Missing samples");
+        }
+        if !track.errors.read.ranges.is_empty() || track.errors.missing_samples.count > 0 {
+            out.push('\n');
+        }
+        if let Some(peak) = track.peak_level {
+            let _ = writeln!(out, "     Peak level {:.1} %", peak);
+        }
+        if let Some(speed) = track.extraction_speed {
+            let _ = writeln!(out, "     Extraction speed {:.1} X", speed);
+        }
+        let test_and_copy = &track.test_and_copy;
+        if !test_and_copy.test_hash.is_empty() {
+            let _ = writeln!(out, "     Test CRC {}", test_and_copy.test_hash);
+        }
+        if !test_and_copy.copy_hash.is_empty() {
+            let _ = writeln!(out, "     Copy CRC {}", test_and_copy.copy_hash);
+        }
+        for unit in &track.ar_info {
+            let version = unit.version.unwrap_or(2);
+            let line = match unit.status {
+                AccurateRipStatus::Match => format!(
+                    "Accurately ripped (confidence {})  [{}]  (AR v{})",
+                    matching(unit),
+                    unit.sign,
+                    version
+                ),
+                AccurateRipStatus::Offsetted => format!(
+                    "Accurately ripped with different offset (confidence {})  [{}]  (AR v{})",
+                    matching(unit),
+                    unit.sign,
+                    version
+                ),
+                AccurateRipStatus::Mismatch => format!(
+                    "Cannot be verified as accurate (confidence {})  [{}]  (AR v{})",
+                    total(unit).unwrap_or(0),
+                    unit.sign,
+                    version
+                ),
+                AccurateRipStatus::NotFound => {
+                    "Track not present in AccurateRip database".to_string()
+                }
+                AccurateRipStatus::Disabled => continue,
+            };
+            let _ = writeln!(out, "     {}", line);
+        }
+        let status = if track.aborted {
+            "Copy aborted"
+        } else if has_errors(track) {
+            "Copy finished"
+        } else {
+            "Copy OK"
+        };
+        let _ = writeln!(out, "     {}\n", status);
+    }
+
+    let _ = writeln!(out, "\n{}\n", ar_summary(log));
+    let _ = writeln!(out, "{}\n", health(log));
+    let _ = writeln!(out, "End of status report\n\n");
+    out.push_str(&marker());
+    out.push('\n');
+    Ok(out)
+}
+
+fn xld(log: &ParsedLog, version: &str) -> PyResult<String> {
+    let mut out = String::new();
+    let release = &log.release_info;
+    let _ = writeln!(out, "X Lossless Decoder version {}\n", version);
+    let _ = writeln!(
+        out,
+        "XLD extraction logfile from 2000-01-01 00:00:00 +0000\n"
+    );
+    let _ = writeln!(out, "{} / {}\n", release.artist, release.title);
+    let _ = writeln!(out, "Used drive : {}", log.drive);
+    let media = match log.media_type {
+        MediaType::Pressed => Some("Pressed CD"),
+        MediaType::CDR => Some("CD-Recordable"),
+        MediaType::Other | MediaType::Unknown => None,
+    };
+    if let Some(media) = media {
+        let _ = writeln!(out, "Media type : {}", media);
+    }
+    out.push('\n');
+
+    let paranoia = matches!(log.read_mode, ReadMode::Paranoid);
+    let mode = match log.read_mode {
+        ReadMode::Secure => Some("XLD Secure Ripper"),
+        ReadMode::Paranoid => Some("CDParanoia III 10.2"),
+        ReadMode::Fast | ReadMode::Burst => Some("Burst"),
+        ReadMode::Unknown => None,
+    };
+    if let Some(mode) = mode {
+        let _ = writeln!(out, "Ripper mode             : {}", mode);
+    }
+    if let Some(cache) = quartet(&log.defeat_audio_cache, "OK", "NO") {
+        let _ = writeln!(out, "Disable audio cache     : {}", cache);
+    }
+    if let Some(c2) = quartet(&log.use_c2, "YES", "NO") {
+        let _ = writeln!(out, "Make use of C2 pointers : {}", c2);
+    }
+    if let Some(offset) = log.read_offset {
+        let _ = writeln!(out, "Read offset correction  : {}", offset);
+    }
+    let gap = match log.gap_handling {
+        Gap::Append => Some("Analyzed, Appended"),
+        Gap::AppendNoHtoa => Some("Analyzed, Appended (except HTOA)"),
+        Gap::AppendUndetected => Some("Not analyzed"),
+        _ => None,
+    };
+    if let Some(gap) = gap {
+        let _ = writeln!(out, "Gap status              : {}", gap);
+    }
+    out.push('\n');
+
+    let _ = writeln!(out, "TOC of the extracted CD");
+    let _ = writeln!(
+        out,
+        "     Track |   Start  |  Length  | Start sector | End sector "
+    );
+    let _ = writeln!(
+        out,
+        "    ---------------------------------------------------------"
+    );
+    for entry in &log.toc.raw.entries {
+        let _ = writeln!(
+            out,
+            "{:>9}  | {:>8} | {:>8} |{:>10}    |{:>9}   ",
+            entry.track,
+            msf(entry.start_sector),
+            msf(entry_sectors(entry)),
+            entry.start_sector,
+            entry.end_sector
+        );
+    }
+    out.push('\n');
+
+    if log.tracks.iter().any(|track| !track.ar_info.is_empty()) {
+        let _ = writeln!(out, "AccurateRip Summary");
+        for track in &log.tracks {
+            let Some(unit) = track.ar_info.first() else {
+                continue;
+            };
+            let result = match unit.status {
+                AccurateRipStatus::Match | AccurateRipStatus::Offsetted => format!(
+                    "OK (v{}, confidence {}/{})",
+                    unit.version.unwrap_or(2),
+                    matching(unit),
+                    total(unit).unwrap_or_else(|| matching(unit))
+                ),
+                AccurateRipStatus::Mismatch => {
+                    format!("NG (total {} submissions)", total(unit).unwrap_or(0))
+                }
+                AccurateRipStatus::NotFound => "Not Found".to_string(),
+                AccurateRipStatus::Disabled => continue,
+            };
+            let _ = writeln!(out, "    Track {:02} : {}", track.num, result);
+        }
+        out.push('\n');
+    }
+
+    for track in &log.tracks {
+        if track.is_range {
+            let _ = writeln!(out, "All Tracks");
+        } else {
+            let _ = writeln!(out, "Track {:02}", track.num);
+        }
+        for filename in &track.filenames {
+            let _ = writeln!(out, "    Filename : {}", filename);
+        }
+        if let Some(pregap) = &track.pregap_length {
+            let _ = writeln!(out, "    Pre-gap length : {}", msf(time_sectors(pregap)?));
+        }
+        out.push('\n');
+        if let Some(gain) = track.gain {
+            let _ = writeln!(out, "    Track gain               : {:.2} dB", gain);
+        }
+        if let Some(peak) = track.peak_level {
+            let _ = writeln!(out, "    Peak                     : {:.6}", peak);
+        }
+        let test_and_copy = &track.test_and_copy;
+        if !test_and_copy.test_hash.is_empty() {
+            let _ = writeln!(
+                out,
+                "    CRC32 hash (test run)    : {}",
+                test_and_copy.test_hash
+            );
+        }
+        if !test_and_copy.copy_hash.is_empty() {
+            let _ = writeln!(
+                out,
+                "    CRC32 hash               : {}",
+                test_and_copy.copy_hash
+            );
+        }
+        for unit in &track.ar_info {
+            if !unit.sign.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "    AccurateRip v{} signature : {}",
+                    unit.version.unwrap_or(2),
+                    unit.sign
+                );
+            }
+        }
+        if let Some(unit) = track.ar_info.first() {
+            let result = match unit.status {
+                AccurateRipStatus::Match | AccurateRipStatus::Offsetted => format!(
+                    "Accurately ripped (v{}, confidence {}/{})",
+                    unit.version.unwrap_or(2),
+                    matching(unit),
+                    total(unit).unwrap_or_else(|| matching(unit))
+                ),
+                AccurateRipStatus::Mismatch => "Rip may not be accurate.".to_string(),
+                AccurateRipStatus::NotFound => {
+                    "Track not present in AccurateRip database.".to_string()
+                }
+                AccurateRipStatus::Disabled => String::new(),
+            };
+            if !result.is_empty() {
+                let _ = writeln!(out, "        ->{}", result);
+            }
+        }
+        let _ = writeln!(out, "    Statistics");
+        let kinds: Vec<ErrorKind> = if paranoia {
+            ErrorKind::ALL
+                .into_iter()
+                .filter(|kind| {
+                    !matches!(
+                        kind,
+                        ErrorKind::JitterGeneric
+                            | ErrorKind::DamagedSectors
+                            | ErrorKind::MissingSamples
+                    )
+                })
+                .collect()
+        } else {
+            vec![
+                ErrorKind::Read,
+                ErrorKind::JitterGeneric,
+                ErrorKind::DamagedSectors,
+            ]
+        };
+        for kind in kinds {
+            let count = kind.data(&track.errors).count;
+            let _ = writeln!(out, "        {:<36} : {}", eac_label(kind), count);
+        }
+        out.push('\n');
+    }
+
+    if log.tracks.iter().any(has_errors) {
+        let _ = writeln!(out, "Some inconsistencies found\n");
+    } else {
+        let _ = writeln!(out, "No errors occurred\n");
+    }
+    let _ = writeln!(out, "End of status report\n");
+    out.push_str(&marker());
+    out.push('\n');
+    Ok(out)
+}
+
+fn whipper(log: &ParsedLog, version: &str) -> PyResult<String> {
+    let mut out = String::new();
+    let _ = writeln!(out, "Log created by: whipper {} (internal logger)", version);
+    let _ = writeln!(out, "Log creation date: 2000-01-01T00:00:00Z\n");
+
+    let _ = writeln!(out, "Ripping phase information:");
+    let _ = writeln!(out, "  Drive: {}", log.drive);
+    let _ = writeln!(out, "  Extraction engine: cdparanoia III 10.2");
+    if let Some(cache) = quartet(&log.defeat_audio_cache, "true", "false") {
+        let _ = writeln!(out, "  Defeat audio cache: {}", cache);
+    }
+    if let Some(offset) = log.read_offset {
+        let _ = writeln!(out, "  Read offset correction: {}", offset);
+    }
+    if let Some(overread) = quartet(&log.overread, "true", "false") {
+        let _ = writeln!(out, "  Overread into lead-out: {}", overread);
+    }
+    match log.media_type {
+        MediaType::Pressed => out.push_str("  CD-R detected: false\n"),
+        MediaType::CDR => out.push_str("  CD-R detected: true\n"),
+        MediaType::Other | MediaType::Unknown => {}
+    }
+    out.push('\n');
+
+    let release = &log.release_info;
+    let _ = writeln!(out, "CD metadata:");
+    let _ = writeln!(out, "  Release:");
+    let _ = writeln!(out, "    Artist: {}", release.artist);
+    let _ = writeln!(out, "    Title: {}", release.title);
+    if !log.toc.freedb.hash.is_empty() {
+        let _ = writeln!(out, "  CDDB Disc ID: {}", log.toc.freedb.hash);
+    }
+    if !log.toc.mbz.hash.is_empty() {
+        let _ = writeln!(out, "  MusicBrainz Disc ID: {}", log.toc.mbz.hash);
+    }
+    if !log.toc.mbz.url.is_empty() {
+        let _ = writeln!(out, "  MusicBrainz lookup URL: {}", log.toc.mbz.url);
+    }
+    out.push('\n');
+
+    let _ = writeln!(out, "TOC:");
+    for entry in &log.toc.raw.entries {
+        let _ = writeln!(out, "  {}:", entry.track);
+        let _ = writeln!(out, "    Start: {}", msf(entry.start_sector));
+        let _ = writeln!(out, "    Length: {}", msf(entry_sectors(entry)));
+        let _ = writeln!(out, "    Start sector: {}", entry.start_sector);
+        let _ = writeln!(out, "    End sector: {}\n", entry.end_sector);
+    }
+
+    let _ = writeln!(out, "Tracks:");
+    for track in &log.tracks {
+        let _ = writeln!(out, "  {}:", track.num);
+        for filename in &track.filenames {
+            let _ = writeln!(out, "    Filename: {}", filename);
+        }
+        if let Some(pregap) = &track.pregap_length {
+            let _ = writeln!(out, "    Pre-gap length: {}", msf(time_sectors(pregap)?));
+        }
+        if let Some(peak) = track.peak_level {
+            let _ = writeln!(out, "    Peak level: {:.6}", peak);
+        }
+        match track.preemphasis {
+            Some(true) => out.push_str("    Pre-emphasis: Yes\n"),
+            Some(false) => out.push_str("    Pre-emphasis: No\n"),
+            None => {}
+        }
+        if let Some(speed) = track.extraction_speed {
+            let _ = writeln!(out, "    Extraction speed: {:.1} X", speed);
+        }
+        let test_and_copy = &track.test_and_copy;
+        if !test_and_copy.test_hash.is_empty() {
+            let _ = writeln!(out, "    Test CRC: {}", test_and_copy.test_hash);
+        }
+        if !test_and_copy.copy_hash.is_empty() {
+            let _ = writeln!(out, "    Copy CRC: {}", test_and_copy.copy_hash);
+        }
+        for unit in &track.ar_info {
+            if matches!(unit.status, AccurateRipStatus::Disabled) {
+                continue;
+            }
+            let _ = writeln!(out, "    AccurateRip v{}:", unit.version.unwrap_or(2));
+            match unit.status {
+                AccurateRipStatus::Match | AccurateRipStatus::Offsetted => {
+                    let _ = writeln!(out, "      Result: Found, exact match");
+                    let _ = writeln!(out, "      Confidence: {}", matching(unit));
+                    let _ = writeln!(out, "      Local CRC: {}", unit.sign);
+                    let _ = writeln!(out, "      Remote CRC: {}", unit.sign);
+                }
+                AccurateRipStatus::Mismatch => {
+                    let _ = writeln!(out, "      Result: Found, NO exact match");
+                    let _ = writeln!(out, "      Confidence: {}", total(unit).unwrap_or(0));
+                    let _ = writeln!(out, "      Local CRC: {}", unit.sign);
+                }
+                _ => {
+                    let _ = writeln!(
+                        out,
+                        "      Result: Track not present in AccurateRip database"
+                    );
+                }
+            }
+        }
+        let status = if track.aborted {
+            "Copy aborted"
+        } else if has_errors(track) {
+            "Copy finished"
+        } else {
+            "Copy OK"
+        };
+        let _ = writeln!(out, "    Status: {}\n", status);
+    }
+
+    let _ = writeln!(out, "Conclusive status report:");
+    let _ = writeln!(out, "  AccurateRip summary: {}", ar_summary(log));
+    let _ = writeln!(out, "  Health status: {}", health(log));
+    let _ = writeln!(out, "  EOF: End of status report\n");
+    // Keep the file valid YAML
+    let _ = writeln!(out, "# {}", marker());
+    Ok(out)
+}
@@ -0,0 +1,232 @@
+// Keyword changes applied to a copy of a parsed log, for what-if evaluation
+use cambia_core::extract::{Gap, MediaType, Quartet, ReadMode};
+use cambia_core::parser::ParsedLog;
+use cambia_core::track::{AccurateRipStatus, AccurateRipUnit, TrackEntry};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::error_kind::ErrorKind;
+use crate::py_classes::{
+    PyAccurateRipStatus, PyErrorKind, PyGap, PyMediaType, PyQuartet, PyReadMode,
+};
+
+fn unexpected(func: &str, key: &str) -> PyErr {
+    PyTypeError::new_err(format!(
+        "{}() got an unexpected keyword argument '{}'",
+        func, key
+    ))
+}
+
+fn invalid(key: &str, value: &Bound<'_, PyAny>, expected: &str) -> PyErr {
+    let type_name = value
+        .get_type()
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_default();
+    PyTypeError::new_err(format!("{} must be {}, not {}", key, expected, type_name))
+}
+
+/// Read a setting given either as a `Quartet` or as a plain bool.
+fn quartet(key: &str, value: &Bound<'_, PyAny>) -> PyResult<Quartet> {
+    if let Ok(flag) = value.extract::<bool>() {
+        return Ok(if flag { Quartet::True } else { Quartet::False });
+    }
+    value
+        .extract::<PyQuartet>()
+        .map(|q| Quartet::from(&q))
+        .map_err(|_| invalid(key, value, "Quartet or bool"))
+}
+
+pub fn quartet_field<'a>(log: &'a mut ParsedLog, key: &str) -> Option<&'a mut Quartet> {
+    match key {
+        "accurate_stream" => Some(&mut log.accurate_stream),
+        "defeat_audio_cache" => Some(&mut log.defeat_audio_cache),
+        "use_c2" => Some(&mut log.use_c2),
+        "overread" => Some(&mut log.overread),
+        "fill_silence" => Some(&mut log.fill_silence),
+        "delete_silence" => Some(&mut log.delete_silence),
+        "use_null_samples" => Some(&mut log.use_null_samples),
+        "test_and_copy" => Some(&mut log.test_and_copy),
+        "normalize" => Some(&mut log.normalize),
+        "id3_enabled" => Some(&mut log.id3_enabled),
+        _ => None,
+    }
+}
+
+/// Apply `ParsedLog.replace()` keywords to a log.
+///
+/// Settings take the same types as the matching `ParsedLog` attributes,
+/// with bools accepted for `Quartet` settings. `tracks` maps track numbers
+/// to per-track changes; see `apply_track`. `func` names the caller in
+/// errors for unknown keywords.
+pub fn apply(log: &mut ParsedLog, changes: &Bound<'_, PyDict>, func: &str) -> PyResult<()> {
+    for (key, value) in changes.iter() {
+        let key: String = key.extract()?;
+        let value = &value;
+        match key.as_str() {
+            "ripper_version" => {
+                log.ripper_version = value.extract().map_err(|_| invalid(&key, value, "str"))?
+            }
+            "language" => {
+                log.language = value.extract().map_err(|_| invalid(&key, value, "str"))?
+            }
+            "drive" => log.drive = value.extract().map_err(|_| invalid(&key, value, "str"))?,
+            "read_offset" => {
+                log.read_offset = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "int or None"))?
+            }
+            "combined_rw_offset" => {
+                log.combined_rw_offset = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "int or None"))?
+            }
+            "media_type" => {
+                let media = value
+                    .extract::<PyMediaType>()
+                    .map_err(|_| invalid(&key, value, "MediaType"))?;
+                log.media_type = MediaType::from(&media);
+            }
+            "read_mode" => {
+                let mode = value
+                    .extract::<PyReadMode>()
+                    .map_err(|_| invalid(&key, value, "ReadMode"))?;
+                log.read_mode = ReadMode::from(&mode);
+            }
+            "gap_handling" => {
+                let gap = value
+                    .extract::<PyGap>()
+                    .map_err(|_| invalid(&key, value, "Gap"))?;
+                log.gap_handling = Gap::from(&gap);
+            }
+            "audio_encoder" => {
+                log.audio_encoder = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "list[str]"))?
+            }
+            "tracks" => {
+                let tracks = value
+                    .cast::<PyDict>()
+                    .map_err(|_| invalid(&key, value, "dict[int, dict]"))?;
+                for (num, track_changes) in tracks.iter() {
+                    let num: u8 = num
+                        .extract()
+                        .map_err(|_| invalid("track number", &num, "int"))?;
+                    let track = log
+                        .tracks
+                        .iter_mut()
+                        .find(|track| track.num == num)
+                        .ok_or_else(|| {
+                            PyValueError::new_err(format!("no track {} in this log", num))
+                        })?;
+                    let track_changes = track_changes.cast::<PyDict>().map_err(|_| {
+                        invalid(&format!("tracks[{}]", num), &track_changes, "dict")
+                    })?;
+                    apply_track(track, track_changes)?;
+                }
+            }
+            _ => match quartet_field(log, &key) {
+                Some(field) => *field = quartet(&key, value)?,
+                None => return Err(unexpected(func, &key)),
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Apply the changes for one track.
+///
+/// Besides the plain `TrackEntry` attributes, `ar_status` sets the status
+/// of every AccurateRip result of the track (adding one if there is none),
+/// and `errors` maps error kinds, as `ErrorKind` or by name such as
+/// `"read"`, to a new count. A count of zero also drops the error's ranges.
+fn apply_track(track: &mut TrackEntry, changes: &Bound<'_, PyDict>) -> PyResult<()> {
+    for (key, value) in changes.iter() {
+        let key: String = key.extract()?;
+        let value = &value;
+        match key.as_str() {
+            "is_range" => {
+                track.is_range = value.extract().map_err(|_| invalid(&key, value, "bool"))?
+            }
+            "aborted" => {
+                track.aborted = value.extract().map_err(|_| invalid(&key, value, "bool"))?
+            }
+            "filenames" => {
+                track.filenames = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "list[str]"))?
+            }
+            "peak_level" => {
+                track.peak_level = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "float or None"))?
+            }
+            "extraction_speed" => {
+                track.extraction_speed = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "float or None"))?
+            }
+            "gain" => {
+                track.gain = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "float or None"))?
+            }
+            "preemphasis" => {
+                track.preemphasis = value
+                    .extract()
+                    .map_err(|_| invalid(&key, value, "bool or None"))?
+            }
+            "ar_status" => {
+                let status = value
+                    .extract::<PyAccurateRipStatus>()
+                    .map_err(|_| invalid(&key, value, "AccurateRipStatus"))?;
+                let status = AccurateRipStatus::from(&status);
+                if track.ar_info.is_empty() {
+                    track.ar_info.push(AccurateRipUnit {
+                        status,
+                        confidence: None,
+                        sign: String::new(),
+                        version: None,
+                    });
+                } else {
+                    for unit in &mut track.ar_info {
+                        unit.status = status.clone();
+                    }
+                }
+            }
+            "errors" => {
+                let errors = value
+                    .cast::<PyDict>()
+                    .map_err(|_| invalid(&key, value, "dict[ErrorKind | str, int]"))?;
+                for (kind, count) in errors.iter() {
+                    let kind = match kind.extract::<PyErrorKind>() {
+                        Ok(kind) => ErrorKind::from(&kind),
+                        Err(_) => {
+                            let name: String = kind
+                                .extract()
+                                .map_err(|_| invalid("error kind", &kind, "ErrorKind or str"))?;
+                            ErrorKind::from_key(&name).ok_or_else(|| {
+                                PyValueError::new_err(format!("unknown error kind '{}'", name))
+                            })?
+                        }
+                    };
+                    let data = kind.data_mut(&mut track.errors);
+                    data.count = count
+                        .extract()
+                        .map_err(|_| invalid(kind.key(), &count, "int"))?;
+                    if data.count == 0 {
+                        data.ranges.clear();
+                    }
+                }
+            }
+            _ => {
+                return Err(PyTypeError::new_err(format!(
+                    "unknown change '{}' for track {}",
+                    key, track.num
+                )))
+            }
+        }
+    }
+    Ok(())
+}
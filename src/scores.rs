@@ -0,0 +1,23 @@
+// Numeric views of the scores cambia-core reports as strings
+use cambia_core::evaluate::EvaluationUnit;
+
+/// The score of a log without any deductions. Scores are not this minus the
+/// unit scores in general: a combined score is worked out from the scores of
+/// its logs, so the units of an aborted log need not count towards it.
+pub const MAX_SCORE: i64 = 100;
+
+/// Read a score string such as `"100"` or `"-11"`, or `None` if it is not
+/// an integer.
+pub fn parse(score: &str) -> Option<i64> {
+    score.trim().parse().ok()
+}
+
+/// Sort units by how much they cost, largest deduction first. Units whose
+/// score is not numeric go last; ties keep evaluator order.
+pub fn by_impact<'a>(
+    units: impl IntoIterator<Item = &'a EvaluationUnit>,
+) -> Vec<&'a EvaluationUnit> {
+    let mut units: Vec<_> = units.into_iter().collect();
+    units.sort_by_key(|unit| std::cmp::Reverse(parse(&unit.unit_score)));
+    units
+}
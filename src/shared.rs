@@ -0,0 +1,78 @@
+// Shared, read-only views into a parsed response for lazily built pyclasses
+use std::any::Any;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+/// A reference to a value owned by an `Arc`, usually a part of a larger
+/// response, that keeps the whole owner alive.
+///
+/// Python objects hold one of these instead of a converted copy, so child
+/// objects are only built when a getter asks for them and no strings or
+/// vectors are cloned along the way.
+pub struct Shared<T: 'static> {
+    owner: Arc<dyn Any + Send + Sync>,
+    ptr: NonNull<T>,
+}
+
+// SAFETY: a `Shared<T>` only hands out `&T`, and the owner is never mutated
+// after construction, so it is as thread-safe as a `&T` would be.
+unsafe impl<T: Sync> Send for Shared<T> {}
+unsafe impl<T: Sync> Sync for Shared<T> {}
+
+impl<T: Send + Sync + 'static> Shared<T> {
+    /// Take ownership of a standalone value.
+    pub fn new(value: T) -> Self {
+        let owner = Arc::new(value);
+        let ptr = NonNull::from(&*owner);
+        Shared { owner, ptr }
+    }
+}
+
+impl<T: 'static> Shared<T> {
+    /// Narrow the view to a part of the value, sharing the same owner.
+    pub fn map<U: 'static>(&self, f: impl FnOnce(&T) -> &U) -> Shared<U> {
+        Shared {
+            owner: Arc::clone(&self.owner),
+            ptr: NonNull::from(f(self)),
+        }
+    }
+
+    /// Like `map`, for an optional part of the value.
+    pub fn map_opt<U: 'static>(&self, f: impl FnOnce(&T) -> Option<&U>) -> Option<Shared<U>> {
+        f(self).map(|part| Shared {
+            owner: Arc::clone(&self.owner),
+            ptr: NonNull::from(part),
+        })
+    }
+
+    /// One view per element of a slice inside the value.
+    pub fn map_each<U: 'static>(&self, f: impl FnOnce(&T) -> &[U]) -> Vec<Shared<U>> {
+        f(self)
+            .iter()
+            .map(|part| Shared {
+                owner: Arc::clone(&self.owner),
+                ptr: NonNull::from(part),
+            })
+            .collect()
+    }
+}
+
+impl<T: 'static> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: `ptr` points into the allocation kept alive by `owner`,
+        // which is never mutated or moved out of.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: 'static> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Shared {
+            owner: Arc::clone(&self.owner),
+            ptr: self.ptr,
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// Cheap header-level log inspection, without parsing tracks or evaluating
+use cambia_core::extract::Ripper;
+use cambia_core::parser::ParsedLog;
+
+use crate::encoding;
+
+/// Localized second header line of an EAC log, mapped to its language.
+///
+/// Only the language depends on this table. EAC does not translate its first
+/// line, "Exact Audio Copy V...", and EAC 0.95 logs, which start with the
+/// localized line, are told by the drive line two lines below it, so logs in
+/// other languages are still identified and split, with no language.
+const EAC_LANGUAGES: &[(&str, &str)] = &[
+    ("EAC extraction logfile", "English"),
+    ("EAC Auslese-Logdatei", "German"),
+    ("Отчёт EAC об извлечении", "Russian"),
+    ("Archivo Log de extracciones", "Spanish"),
+    ("EAC extraheringsloggfil", "Swedish"),
+];
+
+/// First-line prefixes of the non-EAC rippers, with their ripper.
+const HEADERS: &[(&str, Ripper)] = &[
+    ("X Lossless Decoder version ", Ripper::XLD),
+    ("Log created by: whipper ", Ripper::Whipper),
+    ("Log created by: morituri ", Ripper::Morituri),
+    ("Logfile created by: morituri ", Ripper::Morituri),
+    ("CUERipper v", Ripper::CueRipper),
+];
+
+/// The header of one log, as identified from its first lines.
+#[derive(Clone)]
+pub struct LogHeader {
+    pub ripper: Ripper,
+    pub version: Option<String>,
+    pub language: Option<&'static str>,
+}
+
+/// One log out of a possibly combined log file.
+pub struct SubLog<'a> {
+    pub header: LogHeader,
+    pub text: &'a str,
+}
+
+/// Summary of a log file produced by `sniff`.
+pub struct Sniff {
+    pub encoding: &'static str,
+    pub header: Option<LogHeader>,
+    pub log_count: usize,
+    pub has_checksum: bool,
+}
+
+fn clean(line: &str) -> &str {
+    line.trim_start_matches('\u{feff}').trim()
+}
+
+pub fn eac_language(line: &str) -> Option<&'static str> {
+    EAC_LANGUAGES
+        .iter()
+        .find(|(prefix, _)| line.starts_with(prefix))
+        .map(|(_, language)| *language)
+}
+
+/// Version token following a header prefix, e.g. "1.3" from
+/// "Exact Audio Copy V1.3 from 2. September 2016".
+fn version_after(line: &str, prefix: &str) -> Option<String> {
+    let rest = line[prefix.len()..].trim();
+    let rest = rest.split(" from ").next().unwrap_or(rest);
+    let rest = rest.split(" (internal logger)").next().unwrap_or(rest);
+    let rest = rest.split(" Copyright").next().unwrap_or(rest);
+    (!rest.is_empty()).then(|| rest.trim().to_string())
+}
+
+/// Whether a line is the drive line of an EAC header, as in "Used drive  :
+/// PLEXTOR CD-R   PX-W5224A   Adapter: 1  ID: 0". EAC translates the label
+/// but not the adapter and ID.
+fn eac_drive_line(line: &str) -> bool {
+    line.split_once(" Adapter: ")
+        .and_then(|(_, rest)| rest.split_once(" ID: "))
+        .is_some_and(|(adapter, id)| {
+            adapter.trim().parse::<u32>().is_ok() && id.trim().parse::<u32>().is_ok()
+        })
+}
+
+/// Identify a log starting at `line`, given the non-empty lines after it.
+///
+/// EAC 0.95 logs start directly with the extraction logfile line, which in
+/// later EAC versions and in CUERipper logs is the second header line, so
+/// `in_header` tells whether the line is still in the header of a log
+/// started by one of those, up to its drive line.
+fn header_at(line: &str, following: &[&str], in_header: bool) -> Option<LogHeader> {
+    if line.starts_with("Exact Audio Copy V") {
+        return Some(LogHeader {
+            ripper: Ripper::EAC,
+            version: version_after(line, "Exact Audio Copy V"),
+            language: following.first().and_then(|next| eac_language(next)),
+        });
+    }
+    if let Some((prefix, ripper)) = HEADERS.iter().find(|(prefix, _)| line.starts_with(prefix)) {
+        return Some(LogHeader {
+            ripper: ripper.clone(),
+            version: version_after(line, prefix),
+            language: Some("English"),
+        });
+    }
+    // The localized line, the release line, then the drive line
+    let eac_095 = line.starts_with("EAC extraction logfile from")
+        || following.get(1).is_some_and(|drive| eac_drive_line(drive));
+    (!in_header && eac_095).then(|| LogHeader {
+        ripper: Ripper::EAC,
+        version: None,
+        language: eac_language(line),
+    })
+}
+
+/// Split decoded log text into the logs it contains, in order.
+///
+/// Text before the first recognized header is ignored.
+pub fn split(text: &str) -> Vec<SubLog<'_>> {
+    let lines: Vec<(usize, &str)> = text
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, clean(line)))
+        })
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+
+    let mut starts: Vec<(usize, LogHeader)> = Vec::new();
+    // Lines left of the header of the last log, where no log starts
+    let mut header_left: usize = 0;
+    for (i, (offset, line)) in lines.iter().enumerate() {
+        let following = lines[i + 1..]
+            .iter()
+            .take(2)
+            .map(|(_, l)| *l)
+            .collect::<Vec<_>>();
+        match header_at(line, &following, header_left > 0) {
+            Some(header) => {
+                header_left = match header.ripper {
+                    Ripper::EAC if header.version.is_some() => 3,
+                    Ripper::CueRipper => 3,
+                    _ => 0,
+                };
+                starts.push((*offset, header));
+            }
+            None => header_left = header_left.saturating_sub(1),
+        }
+    }
+
+    let ends = starts
+        .iter()
+        .skip(1)
+        .map(|(offset, _)| *offset)
+        .chain(std::iter::once(text.len()))
+        .collect::<Vec<_>>();
+    starts
+        .into_iter()
+        .zip(ends)
+        .map(|((start, header), end)| SubLog {
+            header,
+            text: &text[start..end],
+        })
+        .collect()
+}
+
+/// Whether cambia-core could have read a log of `parsed` from one that
+/// starts like `sniffed`. morituri logs are in the whipper format.
+fn same_format(parsed: &Ripper, sniffed: &Ripper) -> bool {
+    let whipper = |ripper: &Ripper| matches!(ripper, Ripper::Whipper | Ripper::Morituri);
+    parsed == sniffed || (whipper(parsed) && whipper(sniffed))
+}
+
+/// The sub-log of `logs` each parsed log was read from, by index.
+///
+/// A log with a logged checksum is matched to the sub-log containing that
+/// checksum; EAC's are 64 hex digits, and anything under 32 characters is
+/// too likely to turn up elsewhere to go by. Any other log takes the next
+/// sub-log after the last match whose header is of its ripper, or None if
+/// there is none.
+pub fn sources(parsed: &[ParsedLog], logs: &[SubLog]) -> Vec<Option<usize>> {
+    let mut next = 0;
+    parsed
+        .iter()
+        .map(|log| {
+            let checksum = log.checksum.log.trim();
+            let by_checksum = (checksum.len() >= 32)
+                .then(|| (next..logs.len()).find(|&i| logs[i].text.contains(checksum)))
+                .flatten();
+            let found = by_checksum.or_else(|| {
+                (next..logs.len()).find(|&i| same_format(&log.ripper, &logs[i].header.ripper))
+            });
+            if let Some(index) = found {
+                next = index + 1;
+            }
+            found
+        })
+        .collect()
+}
+
+/// Whether a log carries a ripper checksum or signature block.
+pub fn has_checksum(text: &str) -> bool {
+    text.lines().map(clean).any(|line| {
+        // EAC writes "==== <localized label> <64 hex digits> ====".
+        let eac = line.starts_with("==== ")
+            && line.ends_with(" ====")
+            && line
+                .split_whitespace()
+                .any(|word| word.len() == 64 && word.bytes().all(|b| b.is_ascii_hexdigit()));
+        eac || line == "-----BEGIN XLD SIGNATURE-----" || line.starts_with("SHA-256 hash:")
+    })
+}
+
+/// Identify a log from its headers alone.
+pub fn sniff(raw: &[u8]) -> Sniff {
+    let (text, encoding) = encoding::decode(raw);
+    let logs = split(&text);
+    Sniff {
+        encoding: encoding.name(),
+        header: logs.first().map(|log| log.header.clone()),
+        log_count: logs.len(),
+        has_checksum: logs.iter().any(|log| has_checksum(log.text)),
+    }
+}
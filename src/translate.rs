@@ -0,0 +1,590 @@
+// Line-by-line translation of localized EAC logs into English
+use crate::{encoding, sniff};
+
+/// Prepended to lines that no table recognized; the original text follows.
+pub const UNTRANSLATED: &str = "[untranslated] ";
+
+/// What one EAC translation writes, in the localized form found in logs.
+///
+/// Lines are matched in this order: whole `lines`, the `header` line, a
+/// `labels` entry before the first colon, line `prefixes`, then `phrases`
+/// anywhere in the line.
+struct Table {
+    language: &'static str,
+    /// Localized "EAC extraction logfile from", followed by the rip date.
+    header: &'static str,
+    months: &'static [(&'static str, &'static str)],
+    /// Setting labels written as "Label : value".
+    labels: &'static [(&'static str, &'static str)],
+    /// Setting values, matched case-insensitively.
+    values: &'static [(&'static str, &'static str)],
+    lines: &'static [(&'static str, &'static str)],
+    /// Line starts followed by data that stays as it is.
+    prefixes: &'static [(&'static str, &'static str)],
+    phrases: &'static [(&'static str, &'static str)],
+}
+
+const TOC_HEADER: &str = "Track |   Start  |  Length  | Start sector | End sector";
+
+const RUSSIAN: Table = Table {
+    language: "Russian",
+    header: "Отчёт EAC об извлечении, выполненном ",
+    months: &[
+        ("января", "January"),
+        ("февраля", "February"),
+        ("марта", "March"),
+        ("апреля", "April"),
+        ("мая", "May"),
+        ("июня", "June"),
+        ("июля", "July"),
+        ("августа", "August"),
+        ("сентября", "September"),
+        ("октября", "October"),
+        ("ноября", "November"),
+        ("декабря", "December"),
+    ],
+    labels: &[
+        ("Дисковод", "Used drive"),
+        ("Режим чтения", "Read mode"),
+        ("Использование точного потока", "Utilize accurate stream"),
+        ("Отключение кэша аудио", "Defeat audio cache"),
+        ("Использование указателей C2", "Make use of C2 pointers"),
+        ("Коррекция смещения при чтении", "Read offset correction"),
+        (
+            "Комбинированное смещение чтения/записи",
+            "Combined read/write offset correction",
+        ),
+        (
+            "Способность читать области Lead-in и Lead-out",
+            "Overread into Lead-In and Lead-Out",
+        ),
+        (
+            "Заполнение пропущенных сэмплов тишиной",
+            "Fill up missing offset samples with silence",
+        ),
+        (
+            "Удаление блоков с тишиной в начале и конце",
+            "Delete leading and trailing silent blocks",
+        ),
+        (
+            "При вычислениях CRC использовались нулевые сэмплы",
+            "Null samples used in CRC calculations",
+        ),
+        ("Интерфейс", "Used interface"),
+        ("Обработка зазоров", "Gap handling"),
+        ("Выходной формат", "Used output format"),
+        ("Выбранный битрейт", "Selected bitrate"),
+        ("Качество", "Quality"),
+        ("Добавление ID3-тега", "Add ID3 tag"),
+        ("Добавление ID3-тэга", "Add ID3 tag"),
+        ("Утилита сжатия", "Command line compressor"),
+        (
+            "Дополнительные параметры",
+            "Additional command line options",
+        ),
+    ],
+    values: &[
+        ("Достоверность", "Secure"),
+        ("Да", "Yes"),
+        ("Нет", "No"),
+        ("Высокий", "High"),
+        ("Пользовательский кодировщик", "User Defined Encoder"),
+        (
+            "Встроенный Win32-интерфейс для Win NT/2000",
+            "Native Win32 interface for Win NT & 2000",
+        ),
+        (
+            "Установленный внешний ASPI-интерфейс",
+            "Installed external ASPI interface",
+        ),
+        (
+            "Добавлено к предыдущему треку",
+            "Appended to previous track",
+        ),
+    ],
+    lines: &[
+        ("TOC извлечённого CD", "TOC of the extracted CD"),
+        (
+            "Трек |   Старт  | Длительность | Начальный сектор | Конечный сектор",
+            TOC_HEADER,
+        ),
+        (
+            "Характеристики диапазона извлечения и сообщения об ошибках",
+            "Range status and errors",
+        ),
+        ("Выбранный диапазон", "Selected range"),
+        ("Копирование... OK", "Copy OK"),
+        ("Ошибок не произошло", "No errors occurred"),
+        ("AccurateRip: сводка", "AccurateRip summary"),
+        ("Все треки извлечены точно", "All tracks accurately ripped"),
+        (
+            "Ни один трек не прошёл проверки на точность",
+            "No tracks could be verified as accurate",
+        ),
+        (
+            "В вашей базе может быть иной вариант штамповки диска",
+            "You may have a different pressing from the one(s) in the database",
+        ),
+        ("Конец отчёта", "End of status report"),
+    ],
+    prefixes: &[
+        ("Трек ", "Track "),
+        ("Имя файла ", "Filename "),
+        ("Длина предзазора ", "Pre-gap length "),
+        ("Пиковый уровень ", "Peak level "),
+        ("Скорость извлечения ", "Extraction speed "),
+        ("Качество диапазона ", "Range quality "),
+        ("Качество трека ", "Track quality "),
+        ("CRC теста ", "Test CRC "),
+        ("CRC копии ", "Copy CRC "),
+        ("==== Контрольная сумма отчёта ", "==== Log checksum "),
+    ],
+    phrases: &[
+        (
+            ": извлечено точно (доверие ",
+            "accurately ripped (confidence ",
+        ),
+        (
+            "точное извлечение (доверие ",
+            "accurately ripped (confidence ",
+        ),
+        (
+            "точность не определена (достоверность ",
+            "cannot be verified as accurate (confidence ",
+        ),
+        ("AccurateRip вернул ", "AccurateRip returned "),
+    ],
+};
+
+const SPANISH: Table = Table {
+    language: "Spanish",
+    header: "Archivo Log de extracciones desde ",
+    months: &[
+        ("Enero", "January"),
+        ("Febrero", "February"),
+        ("Marzo", "March"),
+        ("Abril", "April"),
+        ("Mayo", "May"),
+        ("Junio", "June"),
+        ("Julio", "July"),
+        ("Agosto", "August"),
+        ("Septiembre", "September"),
+        ("Octubre", "October"),
+        ("Noviembre", "November"),
+        ("Diciembre", "December"),
+    ],
+    labels: &[
+        ("Usar unidad", "Used drive"),
+        ("Modo de Lectura", "Read mode"),
+        ("Utilizar Corriente Exacta", "Utilize accurate stream"),
+        ("Utilizar corriente precisa", "Utilize accurate stream"),
+        ("Descartar Audio caché", "Defeat audio cache"),
+        ("Caché de audio por defecto", "Defeat audio cache"),
+        ("Utilizar los punteros C2", "Make use of C2 pointers"),
+        (
+            "Corrección de Desplazamiento de Lectura",
+            "Read offset correction",
+        ),
+        (
+            "Sobreleer tanto en Lead-In como en Lead-Out",
+            "Overread into Lead-In and Lead-Out",
+        ),
+        (
+            "Rellenar las muestras faltantes con silencios",
+            "Fill up missing offset samples with silence",
+        ),
+        (
+            "Eliminar silencios inicial y final",
+            "Delete leading and trailing silent blocks",
+        ),
+        (
+            "Se han usado muestras nulas en los cálculos CRC",
+            "Null samples used in CRC calculations",
+        ),
+        (
+            "Muestras nulas usadas en los calculos de CRC",
+            "Null samples used in CRC calculations",
+        ),
+        ("Interfaz usada", "Used interface"),
+        ("Gestión de Gaps", "Gap handling"),
+        ("Manejo de huecos", "Gap handling"),
+        ("Formato de Salida utilizado", "Used output format"),
+        ("Bitrate seleccionado", "Selected bitrate"),
+        ("Velocidad de bits seleccionada", "Selected bitrate"),
+        ("Calidad", "Quality"),
+        ("Añadir Etiqueta ID3", "Add ID3 tag"),
+        ("Añadir ID3 tag", "Add ID3 tag"),
+        ("Compresor de linea de comandos", "Command line compressor"),
+        ("linea de comandos del compresor", "Command line compressor"),
+        (
+            "Opciones adicionales en línea de comandos",
+            "Additional command line options",
+        ),
+    ],
+    values: &[
+        ("Seguro", "Secure"),
+        ("Sí", "Yes"),
+        ("No", "No"),
+        ("Alta", "High"),
+        ("Compresor definido por el usuario", "User Defined Encoder"),
+        (
+            "Interfaz ASPI externo instalado",
+            "Installed external ASPI interface",
+        ),
+        (
+            "Interfaz propio de Win32 para Windowns NT y 2000",
+            "Native Win32 interface for Win NT & 2000",
+        ),
+        ("Añadido a la pista previa", "Appended to previous track"),
+        (
+            "No se detectó, por lo que anexa a la pista anterior",
+            "Not detected, thus appended to previous track",
+        ),
+    ],
+    lines: &[
+        ("TOC del CD extraido", "TOC of the extracted CD"),
+        ("TOC extraídos del CD", "TOC of the extracted CD"),
+        (
+            "Pista |  Inicio  | Duración | Sector inicial | Sector final",
+            TOC_HEADER,
+        ),
+        (
+            "Pista |  Inicio  | Longitud | Inicio del sector | Fin del sector",
+            TOC_HEADER,
+        ),
+        (
+            "Pista no presente en la base de datos AccurateRip",
+            "Track not present in AccurateRip database",
+        ),
+        ("Copia OK", "Copy OK"),
+        (
+            "Ninguna de las pistas está presente en la base de datos AccurateRip",
+            "None of the tracks are present in the AccurateRip database",
+        ),
+        (
+            "Todas las pistas extraidas con precisión",
+            "All tracks accurately ripped",
+        ),
+        ("Sin Errores", "No errors occurred"),
+        ("Final del Informe", "End of status report"),
+    ],
+    prefixes: &[
+        ("Pista ", "Track "),
+        ("Nombre de Archivo ", "Filename "),
+        ("Longitud Pre-gap ", "Pre-gap length "),
+        ("Nivel Pico ", "Peak level "),
+        ("Velocidad de extracción ", "Extraction speed "),
+        ("Calidad de Pista ", "Track quality "),
+        ("Copiar CRC ", "Copy CRC "),
+    ],
+    phrases: &[(
+        "Extraida con precisión (confidencia ",
+        "Accurately ripped (confidence ",
+    )],
+};
+
+const SWEDISH: Table = Table {
+    language: "Swedish",
+    header: "EAC extraheringsloggfil från ",
+    months: &[
+        ("januari", "January"),
+        ("februari", "February"),
+        ("mars", "March"),
+        ("april", "April"),
+        ("maj", "May"),
+        ("juni", "June"),
+        ("juli", "July"),
+        ("augusti", "August"),
+        ("september", "September"),
+        ("oktober", "October"),
+        ("november", "November"),
+        ("december", "December"),
+    ],
+    labels: &[
+        ("Använd enhet", "Used drive"),
+        ("Läsmetod", "Read mode"),
+        ("Läs-offset-korrigering", "Read offset correction"),
+        (
+            "Läs in i Lead-In och Lead-Out",
+            "Overread into Lead-In and Lead-Out",
+        ),
+        (
+            "Fyll upp saknade offset-samplingar med tystnad",
+            "Fill up missing offset samples with silence",
+        ),
+        (
+            "Radera inledande och eftersläpande tysta block",
+            "Delete leading and trailing silent blocks",
+        ),
+        (
+            "Nollsamplingar används i CRC-beräkningarna",
+            "Null samples used in CRC calculations",
+        ),
+        ("Använt gränssnitt", "Used interface"),
+        ("Gap-hantering", "Gap handling"),
+        ("Använt output format", "Used output format"),
+        ("Vald bit-hastighet", "Selected bitrate"),
+        ("Kvalité", "Quality"),
+        ("Lägg till ID3 tag", "Add ID3 tag"),
+        ("Kommandolinjekomprimerare", "Command line compressor"),
+        (
+            "Ytterligare kommandoradsinställningar",
+            "Additional command line options",
+        ),
+    ],
+    values: &[
+        ("Ja", "Yes"),
+        ("Nej", "No"),
+        ("Hög", "High"),
+        ("Användardefinierad Kodare", "User Defined Encoder"),
+        (
+            "Native Win32 gränssnitt för Win NT & 2000",
+            "Native Win32 interface for Win NT & 2000",
+        ),
+        ("Lägg till föregående spår", "Appended to previous track"),
+    ],
+    lines: &[
+        ("TOC på den extraherade CD'n", "TOC of the extracted CD"),
+        (
+            "Spår |   Start  |   Längd  | Startsektor | Slutsektor",
+            TOC_HEADER,
+        ),
+        ("Kopia OK", "Copy OK"),
+        ("Kopia färdig", "Copy finished"),
+        (
+            "Några spår kunde inte verifieras som korrekta",
+            "Some tracks could not be verified as accurate",
+        ),
+        ("Inga fel uppstod", "No errors occurred"),
+        ("Slut på status rapport", "End of status report"),
+    ],
+    prefixes: &[
+        ("Spår ", "Track "),
+        ("Filnamn ", "Filename "),
+        ("För-gap längd ", "Pre-gap length "),
+        ("Peak-nivå ", "Peak level "),
+        ("Extraheringshastighet ", "Extraction speed "),
+        ("Testar CRC ", "Test CRC "),
+        ("Kopiera CRC ", "Copy CRC "),
+        ("Timing-problem ", "Timing problem "),
+    ],
+    phrases: &[
+        (
+            "Korrekt kopierat (konfidens ",
+            "Accurately ripped (confidence ",
+        ),
+        (
+            "Kan inte verifieras som korrekt (Konfidens ",
+            "Cannot be verified as accurate (confidence ",
+        ),
+        ("AccurateRip återsände ", "AccurateRip returned "),
+        (
+            " spåret(en) kunde inte verifieras som korrekt",
+            " track(s) could not be verified as accurate",
+        ),
+        (
+            " spåret(en) korrekt kopierat",
+            " track(s) accurately ripped",
+        ),
+    ],
+};
+
+/// The languages with a table. Each table holds the wording of one EAC
+/// translation as it appears in the logs under `tests/logs`, not EAC's full
+/// language file, so lines those logs do not have may go untranslated. EAC
+/// ships more translations than these.
+const TABLES: [&Table; 3] = [&RUSSIAN, &SPANISH, &SWEDISH];
+
+/// Lines every EAC translation leaves in English.
+const ENGLISH_PREFIXES: [&str; 4] = [
+    "Exact Audio Copy V",
+    "---- CUETools DB Plugin",
+    "[CTDB TOCID:",
+    "Submit result:",
+];
+
+/// One line of a translated log.
+pub struct Line {
+    pub original: String,
+    pub text: String,
+    pub recognized: bool,
+}
+
+pub struct Translation {
+    pub source_language: Option<&'static str>,
+    pub lines: Vec<Line>,
+}
+
+impl Translation {
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| format!("{}\n", line.text))
+            .collect()
+    }
+
+    /// The 1-based numbers of the lines no table recognized.
+    pub fn untranslated(&self) -> Vec<usize> {
+        self.lines
+            .iter()
+            .zip(1..)
+            .filter(|(line, _)| !line.recognized)
+            .map(|(_, number)| number)
+            .collect()
+    }
+}
+
+fn table(language: &str) -> Option<&'static Table> {
+    TABLES.iter().copied().find(|t| t.language == language)
+}
+
+/// The languages `translate_text` accepts, English first.
+fn languages() -> Vec<&'static str> {
+    std::iter::once("English")
+        .chain(TABLES.iter().map(|table| table.language))
+        .collect()
+}
+
+fn lookup(entries: &[(&str, &'static str)], text: &str) -> Option<&'static str> {
+    entries
+        .iter()
+        .find(|(from, _)| *from == text)
+        .map(|(_, to)| *to)
+}
+
+fn value(table: &Table, value: &str) -> String {
+    let lower = value.to_lowercase();
+    table
+        .values
+        .iter()
+        .find(|(from, _)| from.to_lowercase() == lower)
+        .map_or_else(|| value.to_string(), |(_, to)| to.to_string())
+}
+
+fn phrases(table: &Table, line: &str) -> Option<String> {
+    let mut out = line.to_string();
+    for (from, to) in table.phrases {
+        out = out.replacen(from, to, 1);
+    }
+    (out != line).then_some(out)
+}
+
+/// Translate one trimmed line, or None if no table entry matches.
+fn translate_line(table: &Table, line: &str) -> Option<String> {
+    if let Some(to) = lookup(table.lines, line) {
+        return Some(to.to_string());
+    }
+    if let Some(date) = line.strip_prefix(table.header) {
+        let date = table
+            .months
+            .iter()
+            .fold(date.to_string(), |date, (from, to)| {
+                date.replacen(from, to, 1)
+            });
+        return Some(format!("EAC extraction logfile from {}", date));
+    }
+    // Some translations repeat the colon ("Modo de Lectura   :   : Seguro").
+    if let Some((label, rest)) = line.split_once(':') {
+        if let Some(to) = lookup(table.labels, label.trim()) {
+            let rest = rest.trim_start_matches([':', ' ']);
+            return Some(format!("{} : {}", to, value(table, rest)));
+        }
+    }
+    if let Some((from, to)) = table.prefixes.iter().find(|(p, _)| line.starts_with(p)) {
+        let line = format!("{}{}", to, &line[from.len()..]);
+        return Some(phrases(table, &line).unwrap_or(line));
+    }
+    phrases(table, line)
+}
+
+/// Translate a decoded log into English, one output line per input line.
+///
+/// Each line is translated with the table of the log it belongs to, so
+/// combined files may mix languages. Lines with no letters, the release
+/// line after the header and the English CTDB plugin output are kept as
+/// they are; any other line no table knows is kept with `UNTRANSLATED`
+/// in front. EAC logs in a language without a table are rejected rather
+/// than returned with every line marked.
+pub fn translate_text(text: &str) -> Result<Translation, String> {
+    let logs = sniff::split(text);
+    if logs.is_empty() {
+        return Err("Could not identify the log's language from its headers".to_string());
+    }
+    let supported = |language: &str| language == "English" || table(language).is_some();
+    if !logs
+        .iter()
+        .all(|log| log.header.language.is_some_and(supported))
+    {
+        return Err(format!(
+            "Unsupported language: only {} EAC logs can be translated",
+            languages().join(", ")
+        ));
+    }
+    let source_language = logs.iter().find_map(|log| log.header.language);
+    let starts = logs
+        .iter()
+        .map(|log| (log.text.as_ptr() as usize - text.as_ptr() as usize, log))
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    let mut release_next = false;
+    let mut in_ctdb = false;
+    for raw in text.split_inclusive('\n') {
+        let original = raw.trim_end_matches(['\n', '\r']);
+        let start = offset;
+        offset += raw.len();
+
+        let language = starts
+            .iter()
+            .rev()
+            .find(|(at, _)| *at <= start)
+            .or(starts.first())
+            .and_then(|(_, log)| log.header.language);
+        let line = original.trim_start_matches('\u{feff}');
+        let trimmed = line.trim();
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        let translated = match language {
+            Some("English") => Some(line.to_string()),
+            Some(language) => table(language)
+                .and_then(|table| translate_line(table, trimmed))
+                .map(|text| format!("{}{}", indent, text)),
+            None => None,
+        };
+        let neutral = !trimmed.chars().any(char::is_alphabetic)
+            || release_next
+            || in_ctdb
+            || ENGLISH_PREFIXES.iter().any(|p| trimmed.starts_with(p));
+
+        if trimmed.starts_with("---- CUETools DB Plugin") {
+            in_ctdb = true;
+        }
+        if sniff::eac_language(trimmed).is_some() {
+            release_next = true;
+            in_ctdb = false;
+        } else if !trimmed.is_empty() {
+            release_next = false;
+        }
+
+        let (text, recognized) = match translated {
+            Some(text) => (text, true),
+            None if neutral => (line.to_string(), true),
+            None => (format!("{}{}", UNTRANSLATED, line), false),
+        };
+        lines.push(Line {
+            original: original.to_string(),
+            text,
+            recognized,
+        });
+    }
+    Ok(Translation {
+        source_language,
+        lines,
+    })
+}
+
+pub fn translate(raw: &[u8]) -> Result<Translation, String> {
+    let (text, _) = encoding::decode(raw);
+    translate_text(&text)
+}
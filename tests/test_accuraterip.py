@@ -0,0 +1,237 @@
+"""Test cases for typed AccurateRip results and their disc analysis."""
+
+from pathlib import Path
+
+import pytest
+
+import cambia
+
+
+def unit(
+    status: cambia.AccurateRipStatus,
+    version: int,
+    matching: int | None = None,
+    offset: int | None = None,
+) -> cambia.AccurateRipUnit:
+    """Build an AccurateRip result.
+
+    Args:
+        status: The status of the result.
+        version: The AccurateRip version.
+        matching: Matching submissions, out of 10.
+        offset: The different offset of a match.
+
+    Returns:
+        The result.
+    """
+    confidence = None
+    if matching is not None:
+        confidence = cambia.AccurateRipConfidence(matching, 10, offset)
+    return cambia.AccurateRipUnit(status, confidence=confidence, version=version)
+
+
+MATCH = cambia.AccurateRipStatus.Match
+MISMATCH = cambia.AccurateRipStatus.Mismatch
+OFFSETTED = cambia.AccurateRipStatus.Offsetted
+NOT_FOUND = cambia.AccurateRipStatus.NotFound
+OUTCOME = cambia.AccurateRipOutcome
+
+
+def summary(*tracks: list[cambia.AccurateRipUnit]) -> cambia.AccurateRipSummary:
+    """Summarize a log with one track per list of results.
+
+    Args:
+        tracks: The results of tracks 1, 2 and so on.
+
+    Returns:
+        The summary of the log.
+    """
+    entries = [
+        cambia.TrackEntry(num, ar_info=units)
+        for num, units in enumerate(tracks, start=1)
+    ]
+    return cambia.ParsedLog(cambia.Ripper.EAC, tracks=entries).accuraterip
+
+
+def test_typed_confidence() -> None:
+    """Test that offsets and totals are typed."""
+    same = cambia.AccurateRipConfidence(3, 4)
+    assert same.offset_kind == cambia.AccurateRipOffsetKind.Same
+    assert same.offset_value is None
+    assert same.total_kind == cambia.AccurateRipTotalKind.All
+
+    different = cambia.AccurateRipConfidence(
+        3, 4, -259, total_kind=cambia.AccurateRipTotalKind.Version
+    )
+    assert different.offset_kind == cambia.AccurateRipOffsetKind.Different
+    assert different.offset_value == -259
+    assert different.offset == "Different (-259)"
+    assert different.total == 4
+    assert different.total_kind == cambia.AccurateRipTotalKind.Version
+
+    unlogged = cambia.AccurateRipConfidence(
+        3, offset_kind=cambia.AccurateRipOffsetKind.Different
+    )
+    assert unlogged.offset == "Different"
+    assert unlogged.offset_value is None
+    assert unlogged.total_kind is None
+
+    with pytest.raises(ValueError, match="offset_kind Same"):
+        cambia.AccurateRipConfidence(
+            3, 4, 6, offset_kind=cambia.AccurateRipOffsetKind.Same
+        )
+
+
+def test_all_matched() -> None:
+    """Test a disc whose tracks all matched in both versions."""
+    ar = summary(
+        [unit(MATCH, 1, 5), unit(MATCH, 2, 8)],
+        [unit(MATCH, 1, 2), unit(MATCH, 2, 3)],
+    )
+
+    assert ar.tracks == [1, 2]
+    assert ar.all_tracks_matched
+    assert ar.min_confidence == 3
+    assert ar.versions_agree
+    assert ar.different_offset == []
+
+
+def test_conflicts_and_offsets() -> None:
+    """Test version conflicts, offset matches and unmatched tracks."""
+    ar = summary(
+        [unit(MISMATCH, 1, 0), unit(MATCH, 2, 4)],
+        [unit(OFFSETTED, 2, 6, offset=-259)],
+        [unit(NOT_FOUND, 2)],
+        [cambia.AccurateRipUnit(cambia.AccurateRipStatus.Disabled)],
+    )
+
+    assert ar.tracks == [1, 2, 3]
+    assert ar.matched == [1, 2]
+    assert ar.unmatched == [3]
+    assert not ar.all_tracks_matched
+    assert ar.min_confidence == 0
+    assert ar.version_conflicts == [1]
+    assert not ar.versions_agree
+    assert ar.different_offset == [2]
+    assert ar.offsets == [-259]
+
+
+def test_no_results() -> None:
+    """Test a log without AccurateRip results."""
+    ar = summary([], [])
+
+    assert ar.tracks == []
+    assert not ar.all_tracks_matched
+    assert ar.min_confidence is None
+
+
+def test_consistency_outliers() -> None:
+    """Test that tracks contradicting the majority are flagged."""
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[
+            cambia.TrackEntry(1, ar_info=[unit(MATCH, 2, 5)]),
+            cambia.TrackEntry(2, ar_info=[unit(MATCH, 2, 5)]),
+            cambia.TrackEntry(3, ar_info=[unit(MISMATCH, 2)]),
+            cambia.TrackEntry(4, ar_info=[unit(OFFSETTED, 2, 2, offset=667)]),
+            cambia.TrackEntry(5, ar_info=[unit(MATCH, 2, 4)]),
+            cambia.TrackEntry(6),
+        ],
+    )
+
+    consistency = log.accuraterip_consistency()
+
+    assert [(g.outcome, g.tracks) for g in consistency.groups] == [
+        (OUTCOME.Match, [1, 2, 5]),
+        (OUTCOME.Offset, [4]),
+        (OUTCOME.Mismatch, [3]),
+    ]
+    assert consistency.majority.tracks == [1, 2, 5]
+    assert consistency.totals == {10: [1, 2, 4, 5]}
+    assert consistency.totals_agree
+    assert [o.track for o in consistency.outliers] == [3, 4]
+    assert consistency.outliers[0].message == (
+        "did not match (majority of 3 tracks: matched)"
+    )
+    assert consistency.outliers[1].offset == 667
+    assert consistency.unchecked == [6]
+    assert not consistency.verified
+
+
+def test_consistency_one_pressing() -> None:
+    """Test that a disc matched at one other pressing is verified."""
+    log = cambia.ParsedLog(
+        cambia.Ripper.XLD,
+        tracks=[
+            cambia.TrackEntry(num, ar_info=[unit(OFFSETTED, 2, 3, offset=-259)])
+            for num in (1, 2)
+        ],
+    )
+
+    consistency = log.accuraterip_consistency()
+
+    assert consistency.verified
+    assert consistency.outliers == []
+    assert consistency.majority.outcome == OUTCOME.Offset
+    assert consistency.majority.offset == -259
+
+
+def test_consistency_totals_disagree() -> None:
+    """Test that tracks with different numbers of submissions are flagged."""
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[
+            cambia.TrackEntry(1, ar_info=[unit(MATCH, 2, 5)]),
+            cambia.TrackEntry(
+                2,
+                ar_info=[
+                    cambia.AccurateRipUnit(
+                        MATCH, confidence=cambia.AccurateRipConfidence(5, 40), version=2
+                    )
+                ],
+            ),
+        ],
+    )
+
+    consistency = log.accuraterip_consistency()
+
+    assert consistency.totals == {10: [1], 40: [2]}
+    assert not consistency.totals_agree
+    assert consistency.verified
+    assert "totals_agree=false" in repr(consistency)
+
+
+def test_consistency_logs(eac_logs_dir: Path) -> None:
+    """Test the consistency of bundled logs with AccurateRip issues."""
+    result = cambia.parse_log_file(eac_logs_dir / "inconsistent-accuraterip.log")
+    consistency = result.parsed.parsed_logs[0].accuraterip_consistency()
+    assert not consistency.verified
+    assert consistency.majority.tracks == [1, 2, 3, 4, 5, 6, 7, 9]
+    assert [(o.track, o.outcome) for o in consistency.outliers] == [(8, OUTCOME.Mismatch)]
+
+    result = cambia.parse_log_file(eac_logs_dir / "russian-range-rip-ar-issue.log")
+    consistency = result.parsed.parsed_logs[0].accuraterip_consistency()
+    assert consistency.outliers == []
+    assert all(group.outcome == OUTCOME.Match for group in consistency.groups)
+
+
+def test_range_rip_per_track() -> None:
+    """Test that the results of a range rip are checked per TOC track."""
+    entries = [(1, 0, 999), (2, 1000, 1999), (3, 2000, 2999)]
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[
+            cambia.TrackEntry(
+                1,
+                is_range=True,
+                ar_info=[unit(MATCH, 2, 6), unit(MATCH, 2, 7), unit(MISMATCH, 2)],
+            )
+        ],
+        toc=cambia.Toc(cambia.TocRaw([cambia.TocEntry(*e) for e in entries])),
+    )
+
+    assert log.accuraterip.tracks == [1, 2, 3]
+    assert log.accuraterip.min_confidence == 0
+    consistency = log.accuraterip_consistency()
+    assert consistency.totals == {10: [1, 2]}
+    assert [o.track for o in consistency.outliers] == [3]
@@ -0,0 +1,152 @@
+"""Test cases for parsing logs inside archives."""
+
+import gzip
+import io
+import tarfile
+import zipfile
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+def make_zip(files: dict[str, bytes]) -> bytes:
+    """Build an in-memory zip archive.
+
+    Args:
+        files: Mapping of archive path to file content.
+
+    Returns:
+        The zip archive as bytes.
+    """
+    buffer = io.BytesIO()
+    with zipfile.ZipFile(buffer, "w", zipfile.ZIP_DEFLATED) as archive:
+        for name, data in files.items():
+            archive.writestr(name, data)
+    return buffer.getvalue()
+
+
+def make_tar_gz(files: dict[str, bytes]) -> bytes:
+    """Build an in-memory gzip-compressed tar archive.
+
+    Args:
+        files: Mapping of archive path to file content.
+
+    Returns:
+        The tar.gz archive as bytes.
+    """
+    buffer = io.BytesIO()
+    with tarfile.open(fileobj=buffer, mode="w:gz") as archive:
+        for name, data in files.items():
+            info = tarfile.TarInfo(name)
+            info.size = len(data)
+            archive.addfile(info, io.BytesIO(data))
+    return buffer.getvalue()
+
+
+class TestParseArchive:
+    """Test parse_archive over the supported archive formats."""
+
+    def test_zip_finds_logs_by_extension_and_content(
+        self, eac_logs_dir: Path, xld_logs_dir: Path
+    ) -> None:
+        """Test that a zip yields .log files and sniffed logs, not other files."""
+        data = make_zip(
+            {
+                "rip/perf-hunid.log": (eac_logs_dir / "perf-hunid.log").read_bytes(),
+                "rip/htoa.txt": (xld_logs_dir / "htoa.log").read_bytes(),
+                "rip/cover.jpg": b"\xff\xd8\xff\xe0" * 64,
+            }
+        )
+
+        entries = cambia.parse_archive(data)
+
+        paths = [entry.path for entry in entries]
+        assert paths == ["rip/perf-hunid.log", "rip/htoa.txt"]
+        for entry in entries:
+            assert isinstance(entry, cambia.ArchiveEntry)
+            assert entry.error is None
+            assert isinstance(entry.response, cambia.CambiaResponse)
+        assert entries[0].response is not None
+        assert entries[0].response.parsed.parsed_logs[0].ripper == cambia.Ripper.EAC
+        assert entries[1].response is not None
+        assert entries[1].response.parsed.parsed_logs[0].ripper == cambia.Ripper.XLD
+
+    def test_tar_gz_from_path(self, whipper_logs_dir: Path, tmp_path: Path) -> None:
+        """Test parsing a tar.gz archive from a path."""
+        archive_path = tmp_path / "rip.tar.gz"
+        archive_path.write_bytes(
+            make_tar_gz(
+                {"whipper.log": (whipper_logs_dir / "whipper-good.log").read_bytes()}
+            )
+        )
+
+        entries = cambia.parse_archive(archive_path)
+
+        assert len(entries) == 1
+        assert entries[0].path == "whipper.log"
+        assert entries[0].response is not None
+        assert entries[0].response.parsed.parsed_logs[0].ripper == cambia.Ripper.Whipper
+
+    def test_unparseable_log_reports_error(self) -> None:
+        """Test that a .log entry that fails to parse carries an error."""
+        entries = cambia.parse_archive(make_zip({"notes.log": b"just some notes"}))
+
+        assert len(entries) == 1
+        assert entries[0].response is None
+        assert entries[0].error is not None
+        assert "Could not parse log" in entries[0].error
+
+    def test_unsupported_format(self) -> None:
+        """Test that non-archive input raises ValueError."""
+        with pytest.raises(ValueError, match="Unsupported archive format"):
+            _ = cambia.parse_archive(b"definitely not an archive")
+
+    def test_entry_size_limit(self) -> None:
+        """Test that an oversized entry is rejected as an archive bomb."""
+        data = make_zip({"bomb.log": b"\0" * (1024 * 1024)})
+        with pytest.raises(ValueError, match="size limit"):
+            _ = cambia.parse_archive(data, max_entry_size=64 * 1024)
+
+    def test_largest_limits(self, eac_logs_dir: Path) -> None:
+        """Test that the largest size limits are accepted as no limit."""
+        data = make_zip(
+            {"perf-hunid.log": (eac_logs_dir / "perf-hunid.log").read_bytes()}
+        )
+
+        entries = cambia.parse_archive(
+            data, max_entry_size=2**64 - 1, max_total_size=2**64 - 1
+        )
+
+        assert [entry.path for entry in entries] == ["perf-hunid.log"]
+        assert entries[0].error is None
+
+    def test_tar_gz_total_size_limit(self) -> None:
+        """Test that skipped tar.gz entries count against the total limit."""
+        data = make_tar_gz({"cover.bin": b"\0" * (1024 * 1024)})
+        with pytest.raises(ValueError, match="total size limit"):
+            _ = cambia.parse_archive(data, max_total_size=256 * 1024)
+
+    def test_entry_count_limit(self) -> None:
+        """Test that archives with too many entries are rejected."""
+        data = make_zip({f"file{i}.txt": b"" for i in range(10)})
+        with pytest.raises(ValueError, match="more than 5 entries"):
+            _ = cambia.parse_archive(data, max_entries=5)
+
+
+class TestGzipLogFile:
+    """Test parse_log_file with gzip-compressed input."""
+
+    def test_parse_log_gz(self, eac_logs_dir: Path, tmp_path: Path) -> None:
+        """Test that .log.gz files are decompressed before parsing."""
+        raw = (eac_logs_dir / "perf-hunid.log").read_bytes()
+        gz_path = tmp_path / "perf-hunid.log.gz"
+        gz_path.write_bytes(gzip.compress(raw))
+
+        compressed = cambia.parse_log_file(gz_path)
+        plain = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log")
+
+        assert (
+            compressed.evaluation_combined[0].combined_score
+            == plain.evaluation_combined[0].combined_score
+        )
@@ -0,0 +1,227 @@
+"""Test cases for building parsed data classes by hand."""
+
+from collections.abc import Callable
+from datetime import timedelta
+from pathlib import Path
+
+import cambia
+import pytest
+
+SETTINGS = (
+    "ripper_version",
+    "language",
+    "read_offset",
+    "combined_rw_offset",
+    "drive",
+    "media_type",
+    "accurate_stream",
+    "defeat_audio_cache",
+    "use_c2",
+    "overread",
+    "fill_silence",
+    "delete_silence",
+    "use_null_samples",
+    "test_and_copy",
+    "normalize",
+    "read_mode",
+    "gap_handling",
+    "id3_enabled",
+    "audio_encoder",
+)
+
+ERROR_KINDS = (
+    "read",
+    "skip",
+    "jitter_generic",
+    "jitter_edge",
+    "jitter_atom",
+    "drift",
+    "dropped",
+    "duplicated",
+    "damaged_sectors",
+    "inconsistent_err_sectors",
+    "missing_samples",
+)
+
+
+def copy_track(track: cambia.TrackEntry) -> cambia.TrackEntry:
+    """Rebuild a parsed track with the constructors.
+
+    Args:
+        track: The parsed track.
+
+    Returns:
+        A hand-built track with the same contents.
+    """
+    errors = {
+        kind: cambia.TrackErrorData(
+            getattr(track.errors, kind).count,
+            [
+                cambia.TrackErrorRange(r.start, r.length)
+                for r in getattr(track.errors, kind).ranges
+            ],
+        )
+        for kind in ERROR_KINDS
+    }
+    return cambia.TrackEntry(
+        track.num,
+        is_range=track.is_range,
+        aborted=track.aborted,
+        filenames=track.filenames,
+        peak_level=track.peak_level,
+        pregap_length=track.pregap_length,
+        extraction_speed=track.extraction_speed,
+        gain=track.gain,
+        preemphasis=track.preemphasis,
+        test_and_copy=cambia.TestAndCopy(
+            track.test_and_copy.test_hash,
+            track.test_and_copy.copy_hash,
+            track.test_and_copy.integrity,
+        ),
+        errors=cambia.TrackError(**errors),
+        ar_info=[
+            cambia.AccurateRipUnit(unit.status, sign=unit.sign, version=unit.version)
+            for unit in track.ar_info
+        ],
+    )
+
+
+def test_rebuilt_log_scores_the_same(eac_logs_dir: Path) -> None:
+    """Test that a log rebuilt from its attributes evaluates the same."""
+    raw = (eac_logs_dir / "perf-hunid.log").read_bytes()
+    result = cambia.parse_log_content(raw)
+    log = result.parsed.parsed_logs[0]
+
+    rebuilt = cambia.ParsedLog(
+        log.ripper,
+        tracks=[copy_track(track) for track in log.tracks],
+        toc=cambia.Toc(
+            cambia.TocRaw(
+                [
+                    cambia.TocEntry(e.track, e.start_sector, e.end_sector)
+                    for e in log.toc.raw.entries
+                ]
+            )
+        ),
+        release_info=cambia.ReleaseInfo(
+            log.release_info.artist, log.release_info.title
+        ),
+        checksum=cambia.Checksum(
+            log.checksum.calculated, log.checksum.log, log.checksum.integrity
+        ),
+        encoding=result.parsed.encoding,
+        **{name: getattr(log, name) for name in SETTINGS},
+    )
+
+    expected = result.evaluation_combined[0].combined_score
+    assert cambia.evaluate(rebuilt).evaluation_combined[0].combined_score == expected
+    assert len(rebuilt.tracks) == len(log.tracks)
+    assert rebuilt.toc.raw.entries[-1].end_sector == log.toc.raw.entries[-1].end_sector
+
+
+def test_unspecified_settings_are_unknown() -> None:
+    """Test that the constructor leaves settings that are not given Unknown."""
+    log = cambia.ParsedLog(cambia.Ripper.XLD, use_c2=False)
+
+    assert log.use_c2 == cambia.Quartet.FALSE
+    assert log.accurate_stream == cambia.Quartet.UNKNOWN
+    assert log.read_mode == cambia.ReadMode.Unknown
+    assert log.tracks == []
+    assert log.toc.raw.entries == []
+
+
+def test_secure_defaults() -> None:
+    """Test that ParsedLog.secure() fills in the recommended settings."""
+    log = cambia.ParsedLog.secure(cambia.Ripper.EAC, test_and_copy=False)
+
+    assert log.defeat_audio_cache == cambia.Quartet.TRUE
+    assert log.accurate_stream == cambia.Quartet.TRUE
+    assert log.use_c2 == cambia.Quartet.FALSE
+    assert log.normalize == cambia.Quartet.FALSE
+    assert log.read_mode == cambia.ReadMode.Secure
+    assert log.gap_handling == cambia.Gap.Append
+    assert log.test_and_copy == cambia.Quartet.FALSE
+
+
+def test_built_log_can_be_evaluated() -> None:
+    """Test that hand-built logs go through evaluate() and compare()."""
+    track = cambia.TrackEntry(
+        1,
+        ar_info=[cambia.AccurateRipUnit(cambia.AccurateRipStatus.Match)],
+    )
+    good = cambia.ParsedLog.secure(cambia.Ripper.EAC, tracks=[track], read_offset=6)
+    bad = good.replace(defeat_audio_cache=False)
+
+    combined = cambia.ParsedLogCombined([good, bad])
+    assert len(cambia.evaluate(combined).evaluation_combined[0].evaluations) == 2
+    assert cambia.compare(good, bad).delta is not None
+
+
+def test_derived_defaults() -> None:
+    """Test defaults that constructors work out from their other arguments."""
+    entry = cambia.TocEntry(2, 150, 224)
+    assert entry.start == timedelta(seconds=2)
+    assert entry.length == timedelta(seconds=1)
+
+    ranges = [cambia.TrackErrorRange(timedelta(seconds=1), timedelta(seconds=2))]
+    assert cambia.TrackErrorData(ranges=ranges).count == 1
+    assert cambia.TrackErrorData(5, ranges).count == 5
+
+    assert cambia.TestAndCopy("ab", "AB").integrity == cambia.Integrity.Match
+    assert cambia.TestAndCopy("ab", "CD").integrity == cambia.Integrity.Mismatch
+    assert cambia.TestAndCopy("ab").integrity == cambia.Integrity.Unknown
+    assert cambia.Checksum("ab", "cd").integrity == cambia.Integrity.Mismatch
+
+    assert cambia.AccurateRipConfidence(3, 4).offset == "Same"
+    assert cambia.AccurateRipConfidence(3, 4, 6).offset == "Different (6)"
+    assert cambia.TrackError().read.count == 0
+    assert cambia.Toc().mbz.hash == ""
+
+
+@pytest.mark.parametrize(
+    ("build", "match"),
+    [
+        (lambda: cambia.TocEntry(100, 0, 1), "above 99"),
+        (lambda: cambia.TocEntry(1, 10, 9), "before start_sector"),
+        (lambda: cambia.TocEntry(1, 0, 449850), "above 449849"),
+        (lambda: cambia.TocEntry(1, 2**32 - 150, 2**32 - 1), "above 449849"),
+        (
+            lambda: cambia.TocRaw(
+                [cambia.TocEntry(2, 0, 9), cambia.TocEntry(1, 10, 19)]
+            ),
+            "track order",
+        ),
+        (
+            lambda: cambia.TocRaw(
+                [cambia.TocEntry(1, 0, 9), cambia.TocEntry(2, 9, 19)]
+            ),
+            "inside track 1",
+        ),
+        (lambda: cambia.AccurateRipConfidence(5, 4), "above total"),
+        (
+            lambda: cambia.AccurateRipUnit(cambia.AccurateRipStatus.Match, version=3),
+            "1 or 2",
+        ),
+        (lambda: cambia.TrackEntry(1, peak_level=-1.0), "non-negative"),
+        (
+            lambda: cambia.ParsedLog(
+                cambia.Ripper.EAC,
+                tracks=[cambia.TrackEntry(1), cambia.TrackEntry(1)],
+            ),
+            "listed twice",
+        ),
+        (lambda: cambia.ParsedLogCombined([]), "must not be empty"),
+    ],
+)
+def test_constructors_validate(build: Callable[[], object], match: str) -> None:
+    """Test that impossible values raise ValueError."""
+    with pytest.raises(ValueError, match=match):
+        build()
+
+
+def test_unknown_setting() -> None:
+    """Test that unknown keywords raise TypeError naming the constructor."""
+    with pytest.raises(TypeError, match=r"ParsedLog\(\) got an unexpected"):
+        cambia.ParsedLog(cambia.Ripper.EAC, bogus=True)
+    with pytest.raises(TypeError, match=r"secure\(\) got an unexpected"):
+        cambia.ParsedLog.secure(cambia.Ripper.EAC, bogus=True)
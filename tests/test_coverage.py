@@ -0,0 +1,123 @@
+"""Test cases for lining up logged tracks against the TOC."""
+
+from datetime import timedelta
+from pathlib import Path
+
+import cambia
+
+
+def build_log(
+    tracks: list[cambia.TrackEntry], entries: list[tuple[int, int, int]]
+) -> cambia.ParsedLog:
+    """Build an EAC log with the given tracks and TOC.
+
+    Args:
+        tracks: The track entries of the log.
+        entries: (track, start sector, end sector) per TOC entry.
+
+    Returns:
+        The log.
+    """
+    return cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=tracks,
+        toc=cambia.Toc(cambia.TocRaw([cambia.TocEntry(*e) for e in entries])),
+    )
+
+
+TOC = [(1, 0, 999), (2, 1000, 1999), (3, 2000, 2999), (4, 3000, 3999)]
+
+
+def test_ripped_missing_aborted() -> None:
+    """Test that every TOC track is ripped, aborted or missing."""
+    log = build_log(
+        [
+            cambia.TrackEntry(1),
+            cambia.TrackEntry(2, aborted=True),
+            cambia.TrackEntry(4),
+            cambia.TrackEntry(7),
+        ],
+        TOC,
+    )
+
+    coverage = log.coverage()
+
+    assert coverage.ripped == [1, 4, 7]
+    assert coverage.aborted == [2]
+    assert coverage.missing == [3]
+    assert coverage.duplicated == []
+    assert coverage.not_in_toc == [7]
+    assert not coverage.complete
+    assert not coverage.range_rip
+
+
+def test_range_covers_toc() -> None:
+    """Test that a range rip covers every TOC track."""
+    log = build_log([cambia.TrackEntry(1, is_range=True)], TOC)
+
+    coverage = log.coverage()
+
+    assert coverage.ripped == [1, 2, 3, 4]
+    assert coverage.missing == []
+    assert coverage.range_rip
+    assert coverage.complete
+    assert coverage.htoa_extracted is None
+
+
+def test_data_track_and_htoa() -> None:
+    """Test that an Enhanced CD data track and hidden track are found."""
+    entries = [(1, 4592, 21749), (2, 21750, 41796), (3, 53197, 60000)]
+    tracks = [cambia.TrackEntry(1), cambia.TrackEntry(2)]
+
+    coverage = build_log(tracks, entries).coverage()
+
+    assert coverage.data_tracks == [3]
+    assert coverage.missing == []
+    assert coverage.htoa_sectors == 4592
+    assert coverage.htoa_present
+    assert coverage.htoa_extracted is False
+    assert coverage.consistent
+
+    extracted = build_log([cambia.TrackEntry(0), *tracks], entries).coverage()
+    assert extracted.htoa_extracted is True
+    assert extracted.not_in_toc == []
+
+
+def test_issues() -> None:
+    """Test that data that does not fit the TOC is reported."""
+    read = cambia.TrackErrorData(
+        ranges=[cambia.TrackErrorRange(timedelta(seconds=10), timedelta(seconds=5))]
+    )
+    log = build_log(
+        [
+            cambia.TrackEntry(1, pregap_length=timedelta(seconds=1)),
+            cambia.TrackEntry(2, errors=cambia.TrackError(read=read)),
+            cambia.TrackEntry(3, pregap_length=timedelta(seconds=30)),
+        ],
+        [(1, 0, 999), (2, 1000, 1999), (3, 2100, 2999)],
+    )
+
+    coverage = log.coverage()
+
+    assert not coverage.consistent
+    assert [issue.track for issue in coverage.issues] == [3, 1, 2, 3]
+    assert "starts at sector 2100" in coverage.issues[0].message
+    assert "expected 00:02:00" in coverage.issues[1].message
+    assert "read error at 00:10:00" in coverage.issues[2].message
+    assert "longer than track 2" in coverage.issues[3].message
+
+
+def test_logs(eac_logs_dir: Path) -> None:
+    """Test the coverage of bundled aborted and Enhanced CD logs."""
+    aborted = cambia.parse_log_file(eac_logs_dir / "hella-aborted.log")
+    coverage = aborted.parsed.parsed_logs[0].coverage()
+    assert coverage.aborted == [3, 10]
+    assert coverage.missing == []
+    assert coverage.consistent
+
+    enhanced = cambia.parse_log_file(eac_logs_dir / "data-track.log")
+    coverage = enhanced.parsed.parsed_logs[0].coverage()
+    assert coverage.data_tracks == [14]
+    assert coverage.complete
+    assert coverage.consistent
+    assert not coverage.htoa_present
@@ -0,0 +1,168 @@
+"""Test cases for reading CUETools DB results and using them in evaluations."""
+
+from pathlib import Path
+
+import pytest
+
+import cambia
+
+# The CUETools report CUERipper ends its logs with: the CTDB section, then
+# the AccurateRip one
+CUERIPPER_LOG = """CUERipper v2.1.6 Copyright (C) 2008-13 Grigory Chudov
+
+Used drive  : ASUS DRW-24B1ST   a   Adapter: 0  ID: 1
+
+[CTDB TOCID: W2i0vFu2P5s6mTLMgo5Pr4ykgIk-] found.
+        [ CTDBID ] Status
+        [4b5d6ea1] (03/05) Accurately ripped
+        [2c7e1f90] (02/05) No match
+Track | CTDB Status
+  1   | (5/5) Accurately ripped
+  2   | (3/5) Accurately ripped
+  3   | (5/5) Differs in 14 samples @00:20:43
+[AccurateRip ID: 0012fa5b-00a1c2d3-2b0c7e03] found.
+Track   [  CRC   |   V2   ] Status
+ 01     [5c4b1c2d|9a8e7f6d] (05/07) Accurately ripped
+ 02     [0a1b2c3d|4e5f6a7b] (07/07) Accurately ripped
+ 03     [1a2b3c4d|5e6f7a8b] (00/07) No match
+"""
+
+# XLD's CTDB summary, in the layout of its AccurateRip summary
+XLD_SUMMARY = """CTDB Summary (TOCID: Ia0V8hrU6QHuqLcAcDLTVl2bhGc-)
+    Track 01 : (4/4) Accurately ripped
+    Track 02 : (2/4) Accurately ripped
+    Track 03 : (4/4) Differs in 14 samples
+"""
+
+XLD_LOG_HEADER = "X Lossless Decoder version 20141129 (148.1)\n\n"
+
+
+def test_cueripper() -> None:
+    """Test the disc entries and track table of a CUERipper log."""
+    [ctdb] = cambia.read_ctdb(CUERIPPER_LOG)
+
+    assert ctdb.tocid == "W2i0vFu2P5s6mTLMgo5Pr4ykgIk-"
+    assert ctdb.status == cambia.CtdbStatus.Verified
+    assert (ctdb.confidence, ctdb.total) == (3, 5)
+    assert ctdb.verified
+    assert [(t.track, t.status, t.confidence) for t in ctdb.tracks] == [
+        (1, cambia.CtdbStatus.Verified, 5),
+        (2, cambia.CtdbStatus.Verified, 3),
+        (3, cambia.CtdbStatus.Differs, 5),
+    ]
+    assert ctdb.tracks[2].differing_samples == 14
+
+
+def test_xld(xld_logs_dir: Path) -> None:
+    """Test a CTDB summary in an XLD log, after its AccurateRip summary."""
+    text = (xld_logs_dir / "100-percent-new.log").read_text()
+    assert cambia.read_ctdb(text) == [None]
+
+    text = text.replace("All Tracks\n", XLD_SUMMARY + "\nAll Tracks\n", 1)
+    [ctdb] = cambia.read_ctdb(text)
+
+    assert ctdb.tocid == "Ia0V8hrU6QHuqLcAcDLTVl2bhGc-"
+    assert ctdb.status == cambia.CtdbStatus.Differs
+    assert (ctdb.confidence, ctdb.total) == (2, 4)
+    assert ctdb.differing_samples == 14
+    assert [(t.track, t.status) for t in ctdb.tracks] == [
+        (1, cambia.CtdbStatus.Verified),
+        (2, cambia.CtdbStatus.Verified),
+        (3, cambia.CtdbStatus.Differs),
+    ]
+
+    [ctdb] = cambia.read_ctdb(
+        XLD_LOG_HEADER + "CTDB Summary (TOCID: Ia0V8hrU6QHuqLcAcDLTVl2bhGc-)\n"
+        "    disk not present in database\n"
+    )
+    assert ctdb.status == cambia.CtdbStatus.NotFound
+
+
+def test_differing_samples_saturate() -> None:
+    """Test that the differing samples of the tracks add up without overflow."""
+    rows = "".join(
+        f"    Track {num:02} : (1/1) Differs in {2**32 - 1} samples\n" for num in (1, 2)
+    )
+    [ctdb] = cambia.read_ctdb(XLD_LOG_HEADER + "CTDB Summary\n" + rows)
+
+    assert ctdb.status == cambia.CtdbStatus.Differs
+    assert ctdb.differing_samples == 2**32 - 1
+
+
+def test_section_header_only(eac_logs_dir: Path) -> None:
+    """Test that a line merely mentioning CTDB does not start the section."""
+    text = (eac_logs_dir / "perf-hunid.log").read_text()
+    text = text.replace("Alice / CHOICE", "CTDB / No Match")
+    text = text[: text.index("---- CUETools DB Plugin")]
+
+    assert cambia.read_ctdb(text) == [None]
+
+
+def test_eac_logs(eac_logs_dir: Path) -> None:
+    """Test the CTDB plugin results of bundled EAC logs."""
+    log = eac_logs_dir / "inconsistent-accuraterip.log"
+    [ctdb] = cambia.read_ctdb(log.read_bytes())
+    assert ctdb.status == cambia.CtdbStatus.Differs
+    assert not ctdb.verified
+    [differs] = [t for t in ctdb.tracks if t.status != cambia.CtdbStatus.Verified]
+    assert (differs.track, differs.differing_samples) == (8, 12)
+
+    [ctdb] = cambia.read_ctdb((eac_logs_dir / "perf-hunid.log").read_bytes())
+    assert ctdb.status == cambia.CtdbStatus.NotFound
+    assert ctdb.tocid == "cljPK5E11RHTwep_jXNr7GNVxhA-"
+
+    first, second = cambia.read_ctdb((eac_logs_dir / "abort.log").read_bytes())
+    assert first is None
+    assert second.verified
+    assert (second.confidence, second.total) == (1, 1)
+
+    assert cambia.read_ctdb((eac_logs_dir / "1.3-good.log").read_bytes()) == [None]
+
+
+def test_parsed_logs(eac_logs_dir: Path) -> None:
+    """Test that each log of a combined file carries its own CTDB result."""
+    raw = (eac_logs_dir / "abort.log").read_bytes()
+
+    response = cambia.parse_log_content(raw)
+    first, second = response.parsed.parsed_logs
+    assert first.ctdb is None
+    assert second.ctdb is not None
+    assert second.ctdb.tocid == "nP0jqxhne.gdDK2arvj76XjCAOo-"
+    combined = response.evaluation_combined[0]
+    assert [e.ctdb_verified for e in combined.evaluations] == [False, True]
+    assert not combined.ctdb_verified
+
+    evaluated = cambia.evaluate(cambia.parse_only(raw)).evaluation_combined[0]
+    assert [e.ctdb_verified for e in evaluated.evaluations] == [False, True]
+    assert evaluated.numeric_score == combined.numeric_score
+
+
+def test_constructors() -> None:
+    """Test building results and their validation."""
+    track = cambia.CtdbTrack(1, cambia.CtdbStatus.Verified, confidence=2, total=3)
+    ctdb = cambia.CtdbResult(
+        cambia.CtdbStatus.NoMatch, confidence=0, total=3, tracks=[track]
+    )
+
+    assert not ctdb.verified
+    assert ctdb.tracks[0].total == 3
+    with pytest.raises(ValueError, match="above total"):
+        cambia.CtdbResult(cambia.CtdbStatus.Verified, confidence=4, total=3)
+
+
+def test_evaluation(eac_logs_dir: Path) -> None:
+    """Test that evaluations tell CTDB-verified rips from unverified ones."""
+    log = cambia.ParsedLog(cambia.Ripper.EAC)
+    verified = cambia.CtdbResult(cambia.CtdbStatus.Verified, confidence=5, total=5)
+
+    combined = cambia.evaluate(log, ctdb=[verified]).evaluation_combined[0]
+    assert combined.evaluations[0].ctdb.confidence == 5
+    assert combined.evaluations[0].ctdb_verified
+    assert combined.ctdb_verified
+
+    combined = cambia.evaluate(log).evaluation_combined[0]
+    assert combined.evaluations[0].ctdb is None
+    assert not combined.ctdb_verified
+
+    with pytest.raises(ValueError, match="2 CTDB results given for 1 logs"):
+        cambia.evaluate(log, ctdb=[verified, None])
@@ -0,0 +1,180 @@
+"""Test cases for reading ISRCs, catalog numbers and CD-Text from logs."""
+
+from pathlib import Path
+
+import cambia
+
+EAC_LOG = """Exact Audio Copy V1.3 from 2. September 2016
+
+EAC extraction logfile from 9. March 2017, 9:59
+
+Artist / Album
+
+Used drive  : Optiarc DVD RW AD-7940H   Adapter: 1  ID: 0
+Media Catalog Number : 0036000291452
+CD-Text Performer : Disc Artist
+CD-Text Title : Disc Title
+
+Track  1
+
+     Filename C:\\rips\\01.flac
+     ISRC : USRC17607839
+     CD-Text Title : First
+     CD-Text Performer : Someone
+     Title : Not CD-Text
+
+     Copy CRC 9C5B6A89
+
+Track  2
+
+     Filename C:\\rips\\02.flac
+     ISRC : US-RC1-76-07840
+
+     Copy CRC 1A2B3C4D
+
+No errors occurred
+
+End of status report
+"""
+
+XLD_LOG = """X Lossless Decoder version 20191004 (152.2)
+
+XLD extraction logfile from 2020-01-01 12:00:00 +0000
+
+Media catalog number : 0000000000000
+Catalog number : LABEL-001
+Barcode : 036000291452
+Title : Release Title
+
+Track 01
+    Filename : /rips/01.flac
+    ISRC : GBAYE0000351
+    CRC32 hash : E7395AB0
+
+Track 02
+    Filename : /rips/02.flac
+    ISRC : 1BAYE0000352
+
+No errors occurred
+"""
+
+
+def test_eac() -> None:
+    """Test that EAC disc and track identifiers and CD-Text are read."""
+    [info] = cambia.read_disc_info(EAC_LOG)
+
+    assert info.ripper == cambia.Ripper.EAC
+    assert info.mcn == cambia.Mcn("036000291452")
+    assert info.mcn.valid
+    assert info.mcn.upc == "036000291452"
+    assert (info.performer, info.title) == ("Disc Artist", "Disc Title")
+    assert [track.track for track in info.tracks] == [1, 2]
+    assert (info.tracks[0].title, info.tracks[0].performer) == ("First", "Someone")
+    assert info.tracks[1].title is None
+    assert info.isrcs == {
+        1: cambia.Isrc("USRC17607839"),
+        2: cambia.Isrc("USRC17607840"),
+    }
+    assert info.valid
+
+
+def test_xld_combined() -> None:
+    """Test an XLD log with a bad ISRC after an EAC log in one file."""
+    infos = cambia.read_disc_info((EAC_LOG + "\n" + XLD_LOG).encode())
+
+    assert [info.ripper for info in infos] == [cambia.Ripper.EAC, cambia.Ripper.XLD]
+    xld = infos[1]
+    assert xld.mcn is None
+    assert xld.performer is None
+    assert xld.title is None
+    assert [str(isrc) for isrc in xld.isrcs.values()] == [
+        "GB-AYE-00-00351",
+        "1BAYE0000352",
+    ]
+    assert not xld.isrcs[2].valid
+    assert not xld.valid
+
+
+def test_isrc() -> None:
+    """Test ISRC normalization and format validation."""
+    isrc = cambia.Isrc("us-rc1-76-07839")
+
+    assert isrc.code == "USRC17607839"
+    assert isrc.valid
+    assert (isrc.country, isrc.registrant, isrc.year, isrc.designation) == (
+        "US",
+        "RC1",
+        "76",
+        "07839",
+    )
+    assert str(isrc) == "US-RC1-76-07839"
+    assert len({isrc, cambia.Isrc("USRC17607839")}) == 1
+    for code in ["USRC1760783", "USRC1760783X", "U1RC17607839"]:
+        assert not cambia.Isrc(code).valid
+    assert cambia.Isrc("USRC1760783").country is None
+
+
+def test_mcn() -> None:
+    """Test EAN-13 and UPC-A check digits."""
+    assert cambia.Mcn("4006381333931").valid
+    assert cambia.Mcn("4006381333931").upc is None
+    assert not cambia.Mcn("4006381333932").valid
+    assert cambia.Mcn("0 36000 29145 2").valid
+    assert cambia.Mcn("036000291452").ean == "0036000291452"
+    assert not cambia.Mcn("0000000000000").valid
+    assert not cambia.Mcn("12345").valid
+    assert cambia.Mcn("12345").ean is None
+
+
+def test_mcn_hash() -> None:
+    """Test that MCNs hash alike when they compare equal."""
+    upc = cambia.Mcn("036000291452")
+    ean = cambia.Mcn("0036000291452")
+
+    assert upc == ean
+    assert hash(upc) == hash(ean)
+    assert len({upc, ean, cambia.Mcn("4006381333931")}) == 2
+
+
+def test_logs_without_identifiers(test_logs_dir: Path) -> None:
+    """Test that bundled logs read with their tracks and no identifiers."""
+    for path in [
+        test_logs_dir / "EAC" / "1.3-good.log",
+        test_logs_dir / "XLD" / "htoa.log",
+        test_logs_dir / "whipper" / "whipper-good.log",
+    ]:
+        [info] = cambia.read_disc_info(path.read_bytes())
+        assert info.tracks
+        assert info.mcn is None
+        assert info.isrcs == {}
+        assert info.performer is None
+        assert all(track.title is None for track in info.tracks)
+
+    # Release metadata such as whipper's "Artist:" and "Title:" is not CD-Text
+    for subdir in ["EAC", "EAC95", "XLD", "whipper"]:
+        for path in sorted((test_logs_dir / subdir).glob("*.log")):
+            for info in cambia.read_disc_info(path.read_bytes()):
+                assert info.mcn is None, path
+                assert (info.performer, info.title) == (None, None), path
+                assert info.isrcs == {}, path
+
+    [whipper] = cambia.read_disc_info(
+        (test_logs_dir / "whipper" / "whipper-good.log").read_bytes()
+    )
+    assert [track.track for track in whipper.tracks] == list(range(1, 13))
+
+
+def test_parsed_logs(eac_logs_dir: Path) -> None:
+    """Test that parsed logs carry the disc info of their part of the file."""
+    result = cambia.parse_log_file(eac_logs_dir / "abort.log")
+
+    logs = result.parsed.parsed_logs
+    assert len(logs) == 2
+    for log in logs:
+        assert isinstance(log.disc_info, cambia.DiscInfo)
+        assert log.disc_info.ripper == log.ripper
+        assert [t.track for t in log.disc_info.tracks] == [t.num for t in log.tracks]
+
+    parsed = cambia.parse_only((eac_logs_dir / "perf-hunid.log").read_bytes())
+    assert parsed.parsed_logs[0].disc_info is not None
+    assert cambia.ParsedLog(cambia.Ripper.EAC).disc_info is None
@@ -0,0 +1,119 @@
+"""Test cases for track types, disc layouts and computed disc IDs."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+# Tracks 1 to 6 of XLD/100-percent-new.log and the data track after them
+ENHANCED = [
+    (1, 0, 23684),
+    (2, 23685, 43196),
+    (3, 43197, 70374),
+    (4, 70375, 94061),
+    (5, 94062, 113619),
+    (6, 113620, 140516),
+    (7, 151917, 332849),
+]
+
+
+def build_toc(entries: list[tuple[int, int, int]], **ids: cambia.TocHash) -> cambia.Toc:
+    """Build a TOC from (track, start sector, end sector) tuples.
+
+    Args:
+        entries: The TOC entries.
+        **ids: Disc IDs to pass on to the TOC.
+
+    Returns:
+        The TOC.
+    """
+    raw = cambia.TocRaw([cambia.TocEntry(*entry) for entry in entries])
+    return cambia.Toc(raw, **ids)
+
+
+def test_enhanced() -> None:
+    """Test that a data track after a session gap makes an Enhanced CD."""
+    toc = build_toc(ENHANCED)
+
+    assert toc.disc_layout == cambia.DiscLayout.Enhanced
+    assert toc.raw.disc_layout == cambia.DiscLayout.Enhanced
+    entries = toc.raw.entries
+    assert [e.track_type for e in entries] == [cambia.TrackType.Audio] * 6 + [
+        cambia.TrackType.Data
+    ]
+    assert [e.session for e in entries] == [1] * 6 + [2]
+
+
+def test_plain_and_mixed_mode() -> None:
+    """Test that contiguous audio is plain and a leading data track is not."""
+    plain = build_toc([(1, 0, 999), (2, 1000, 1999)])
+    mixed = build_toc([(1, 0, 999), (2, 1150, 1999), (3, 2000, 2999)])
+
+    assert plain.disc_layout == cambia.DiscLayout.Plain
+    assert all(e.track_type == cambia.TrackType.Audio for e in plain.raw.entries)
+    assert mixed.disc_layout == cambia.DiscLayout.MixedMode
+    assert [e.track_type.name for e in mixed.raw.entries] == ["Data", "Audio", "Audio"]
+    assert cambia.TocEntry(1, 0, 9).track_type == cambia.TrackType.Audio
+
+
+def test_accuraterip_id_of_enhanced_cd() -> None:
+    """Test that the AccurateRip ID matches the one XLD logged."""
+    toc = build_toc(ENHANCED)
+
+    assert toc.accurip_tocid.hash == "000a579d-003c205a-53115607"
+    assert toc.accurip_tocid.url.endswith(
+        "/d/9/7/dBAR-006-000a579d-003c205a-53115607.bin"
+    )
+    assert toc.freedb.hash == "53115607"
+
+
+def test_ctdb_and_musicbrainz_ids() -> None:
+    """Test CTDB and MusicBrainz IDs against the ones logged for a disc."""
+    # EAC/perf-hunid.log
+    ctdb = build_toc([(1, 0, 23734), (2, 23735, 37023), (3, 37024, 57044)])
+    assert ctdb.ctdb_tocid.hash == "cljPK5E11RHTwep_jXNr7GNVxhA-"
+
+    # whipper/whipper-with-errors.log
+    starts = [60, 11675, 21869, 27216, 32204, 35768, 43697, 54549, 63603]
+    starts += [70448, 84529, 94057, 113624, 125342, 135229, 145253, 164201]
+    starts += [171641, 180231, 182026, 197549, 209415, 215246, 222451, 232524]
+    ends = [start - 1 for start in starts[1:]] + [258687]
+    mbz = build_toc(
+        [(n, s, e) for n, (s, e) in enumerate(zip(starts, ends, strict=True), 1)]
+    ).mbz
+    assert mbz.hash == "gRT5x9OvnIFqv8eeSohpfEIB2gs-"
+    assert "toc=1+25+258838+210+11825+" in mbz.url
+
+
+def test_parsed_enhanced_cd(eac_logs_dir: Path) -> None:
+    """Test that a parsed Enhanced CD gets IDs computed around its data track."""
+    result = cambia.parse_log_file(eac_logs_dir / "data-track.log")
+    toc = result.parsed.parsed_logs[0].toc
+    computed = cambia.Toc(toc.raw)
+
+    assert toc.disc_layout == cambia.DiscLayout.Enhanced
+    assert toc.raw.entries[-1].track_type == cambia.TrackType.Data
+    assert "/dBAR-013-" in toc.accurip_tocid.url
+    for name in ("freedb", "accurip_tocid", "ctdb_tocid", "mbz"):
+        assert getattr(toc, name).hash == getattr(computed, name).hash
+
+
+def test_ids_at_end_of_disc() -> None:
+    """Test IDs of a TOC up to the last sector, and that none goes past it."""
+    toc = build_toc([(1, 0, 999), (2, 1000, 449849)])
+
+    assert toc.freedb.hash != ""
+    assert toc.accurip_tocid.hash != ""
+    assert toc.ctdb_tocid.hash != ""
+    assert toc.mbz.hash != ""
+    with pytest.raises(ValueError, match="above 449849"):
+        build_toc([(1, 0, 999), (2, 1000, 2**32 - 1)])
+
+
+def test_given_ids_kept() -> None:
+    """Test that disc IDs passed to a TOC are not replaced."""
+    toc = build_toc(ENHANCED, mbz=cambia.TocHash("given"))
+
+    assert toc.mbz.hash == "given"
+    assert toc.ctdb_tocid.hash != ""
+    assert toc.gn.hash == ""
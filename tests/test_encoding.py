@@ -0,0 +1,69 @@
+"""Test cases for encoding override and detection."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+class TestEncodingOverride:
+    """Test the encoding keyword of the parse functions."""
+
+    def test_forced_encoding_is_reported(self, eac_logs_dir: Path) -> None:
+        """Test that a forced encoding is used and reported by the parsed log."""
+        log_file = eac_logs_dir / "bad-russian-099.log"
+        result = cambia.parse_log_file(log_file, encoding="cp1251")
+
+        assert result.parsed.encoding == "windows-1251"
+        assert result.parsed.parsed_logs[0].ripper == cambia.Ripper.EAC
+
+    def test_forced_encoding_matches_detection(self, eac_logs_dir: Path) -> None:
+        """Test that forcing the detected encoding gives the same score."""
+        raw = (eac_logs_dir / "mac-roman-charset.log").read_bytes()
+        detected = cambia.parse_log_content(raw)
+        forced = cambia.parse_log_content(raw, encoding="shift_jis")
+
+        assert forced.parsed.encoding == "Shift_JIS"
+        assert (
+            forced.evaluation_combined[0].combined_score
+            == detected.evaluation_combined[0].combined_score
+        )
+
+    def test_unknown_encoding(self, eac_logs_dir: Path) -> None:
+        """Test that an unknown encoding name raises LookupError."""
+        with pytest.raises(LookupError, match="unknown encoding"):
+            cambia.parse_log_file(eac_logs_dir / "perf-hunid.log", encoding="nope")
+
+
+class TestDetectEncoding:
+    """Test detect_encoding diagnostics."""
+
+    @pytest.mark.parametrize(
+        ("log_name", "expected"),
+        [
+            ("bad-russian-099.log", "windows-1251"),
+            ("spanish-099.log", "windows-1252"),
+            ("mac-roman-charset.log", "Shift_JIS"),
+            ("perf-hunid.log", "UTF-8"),
+        ],
+    )
+    def test_best_candidate(
+        self, eac_logs_dir: Path, log_name: str, expected: str
+    ) -> None:
+        """Test that the best scored candidate is the log's real encoding."""
+        detection = cambia.detect_encoding((eac_logs_dir / log_name).read_bytes())
+
+        assert not detection.bom
+        assert detection.candidates[0].encoding == expected
+        assert sum(c.score for c in detection.candidates) == pytest.approx(1.0)
+        scores = [c.score for c in detection.candidates]
+        assert scores == sorted(scores, reverse=True)
+
+    def test_bom(self, eac_logs_dir: Path) -> None:
+        """Test that a byte order mark is reported and authoritative."""
+        detection = cambia.detect_encoding((eac_logs_dir / "1.3-good.log").read_bytes())
+
+        assert detection.bom
+        assert len(detection.candidates) == 1
+        assert detection.candidates[0].encoding == "UTF-16LE"
+        assert detection.candidates[0].score == 1.0
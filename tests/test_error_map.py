@@ -0,0 +1,103 @@
+"""Test cases for the disc-wide error map and its SVG rendering."""
+
+import xml.etree.ElementTree as ET
+from datetime import timedelta
+
+import cambia
+
+SVG = "{http://www.w3.org/2000/svg}"
+
+
+def error_range(start: float, length: float) -> cambia.TrackErrorRange:
+    """Build an error range from seconds.
+
+    Args:
+        start: Start of the range in seconds from the start of the track.
+        length: Length of the range in seconds.
+
+    Returns:
+        The error range.
+    """
+    return cambia.TrackErrorRange(timedelta(seconds=start), timedelta(seconds=length))
+
+
+def build_log() -> cambia.ParsedLog:
+    """Build a two-track log with overlapping read and skip errors.
+
+    Returns:
+        The log.
+    """
+    errors = cambia.TrackError(
+        read=cambia.TrackErrorData(ranges=[error_range(1, 1), error_range(1.5, 1)]),
+        skip=cambia.TrackErrorData(ranges=[error_range(2, 0)]),
+    )
+    return cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[
+            cambia.TrackEntry(1, errors=errors, peak_level=98.0, extraction_speed=4.2),
+            cambia.TrackEntry(2, peak_level=50.0, extraction_speed=8.0),
+        ],
+        toc=cambia.Toc(
+            cambia.TocRaw([cambia.TocEntry(1, 0, 999), cambia.TocEntry(2, 1000, 9999)])
+        ),
+        release_info=cambia.ReleaseInfo("Artist & Co", "<Title>"),
+    )
+
+
+def test_intervals_are_merged() -> None:
+    """Test that overlapping ranges of one kind merge into one interval."""
+    error_map = build_log().error_map()
+
+    assert error_map.intervals == {
+        cambia.ErrorKind.Read: [(75, 187)],
+        cambia.ErrorKind.Skip: [(150, 150)],
+    }
+    assert (error_map.disc_start, error_map.disc_end) == (0, 9999)
+
+
+def test_timeline() -> None:
+    """Test that the timeline splits the disc where the error kinds change."""
+    error_map = build_log().error_map()
+
+    assert [(s.start_sector, s.end_sector, s.kinds) for s in error_map.timeline] == [
+        (75, 149, [cambia.ErrorKind.Read]),
+        (150, 150, [cambia.ErrorKind.Read, cambia.ErrorKind.Skip]),
+        (151, 187, [cambia.ErrorKind.Read]),
+    ]
+    assert error_map.error_sectors == 113
+    assert str(error_map.timeline[1].start_msf) == "00:02:00"
+
+
+def test_no_errors() -> None:
+    """Test that a log without errors has an empty map."""
+    error_map = cambia.ParsedLog(cambia.Ripper.EAC).error_map()
+
+    assert error_map.intervals == {}
+    assert error_map.timeline == []
+    assert error_map.disc_start is None
+
+
+def test_svg() -> None:
+    """Test that the SVG has the disc bar, tracks, error bands and legend."""
+    svg = build_log().render_error_map_svg(width=600)
+
+    root = ET.fromstring(svg)
+    assert root.tag == f"{SVG}svg"
+    assert root.get("width") == "600"
+    assert root.find(f"{SVG}title").text == "Error map of Artist & Co / <Title>"
+    fills = [rect.get("fill") for rect in root.iter(f"{SVG}rect")]
+    assert fills.count("#d62728") == 2  # read band and legend swatch
+    assert len(root.findall(f"{SVG}line")) == 2
+    texts = [text.text for text in root.iter(f"{SVG}text")]
+    assert "4.2x" in texts
+    assert "8.0x" in texts
+    assert "skip (1 sector)" in texts
+    assert "read (113 sectors)" in texts
+
+
+def test_svg_without_toc() -> None:
+    """Test that a log without a TOC still renders, with an explanation."""
+    svg = cambia.ParsedLog(cambia.Ripper.XLD).render_error_map_svg()
+
+    assert "No TOC" in svg
+    ET.fromstring(svg)
@@ -0,0 +1,100 @@
+"""Test cases for hidden track one audio detection."""
+
+from pathlib import Path
+
+import cambia
+
+
+def build_log(
+    tracks: list[cambia.TrackEntry], ripper: cambia.Ripper = cambia.Ripper.EAC
+) -> cambia.ParsedLog:
+    """Build a log of a disc with 4592 sectors of hidden audio.
+
+    Args:
+        tracks: The track entries of the log.
+        ripper: The ripper of the log.
+
+    Returns:
+        The log.
+    """
+    return cambia.ParsedLog(
+        ripper,
+        tracks=tracks,
+        toc=cambia.Toc(
+            cambia.TocRaw(
+                [cambia.TocEntry(1, 4592, 21749), cambia.TocEntry(2, 21750, 41796)]
+            )
+        ),
+    )
+
+
+def test_not_extracted() -> None:
+    """Test that a hidden track that was not ripped comes with guidance."""
+    htoa = build_log([cambia.TrackEntry(1), cambia.TrackEntry(2)]).htoa
+
+    assert htoa.present
+    assert htoa.length_sectors == 4592
+    assert str(htoa.length_msf) == "01:01:17"
+    assert htoa.extraction is None
+    assert htoa.entry is None
+    assert not htoa.verified
+    assert "sectors 0 to 4591" in htoa.guidance
+    assert "Copy Range" in htoa.guidance
+
+    xld = build_log([cambia.TrackEntry(1)], cambia.Ripper.XLD).htoa
+    assert "XLD" in xld.guidance
+
+
+def test_track_zero() -> None:
+    """Test that a track 0 entry counts as the hidden track."""
+    tested = build_log(
+        [cambia.TrackEntry(0, test_and_copy=cambia.TestAndCopy("AB", "AB"))]
+    ).htoa
+    copied = build_log(
+        [cambia.TrackEntry(0, test_and_copy=cambia.TestAndCopy("", "AB"))]
+    ).htoa
+
+    assert tested.extraction == cambia.HtoaExtraction.Track
+    assert "extraction=Track" in repr(tested)
+    assert tested.entry.num == 0
+    assert tested.verified
+    assert tested.guidance is None
+    assert copied.extraction == cambia.HtoaExtraction.Track
+    assert not copied.tested
+    assert "Test & Copy" in copied.guidance
+
+
+def test_range() -> None:
+    """Test that a range on a disc with a hidden track is reported."""
+    htoa = build_log([cambia.TrackEntry(1, is_range=True)]).htoa
+
+    assert htoa.extraction == cambia.HtoaExtraction.Range
+    assert htoa.entry.is_range
+
+
+def test_no_hidden_track() -> None:
+    """Test that a short lead-in before track one is not a hidden track."""
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[cambia.TrackEntry(1, is_range=True)],
+        toc=cambia.Toc(cambia.TocRaw([cambia.TocEntry(1, 33, 999)])),
+    )
+
+    assert not log.htoa.present
+    assert log.htoa.extraction is None
+    assert log.htoa.guidance is None
+
+
+def test_logs(eac_logs_dir: Path, xld_logs_dir: Path) -> None:
+    """Test the hidden tracks of the bundled HTOA logs."""
+    xld = cambia.parse_log_file(xld_logs_dir / "htoa.log").parsed.parsed_logs[0]
+    assert xld.htoa.present
+    assert xld.htoa.length_sectors == 4592
+    assert xld.htoa.extraction is None
+
+    path = eac_logs_dir / "htoa-not-ripped-twice.log"
+    eac = cambia.parse_log_file(path).parsed.parsed_logs[0]
+    assert eac.htoa.length_sectors == 45150
+    assert eac.htoa.extraction == cambia.HtoaExtraction.Range
+    assert not eac.htoa.tested
+    assert "Test & Copy" in eac.htoa.guidance
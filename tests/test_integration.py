@@ -1,6 +1,10 @@
 """Integration tests for cambia package."""
 
+import io
+import mmap
+from collections.abc import Callable
 from pathlib import Path
+from typing import Any
 
 import cambia
 import pytest
@@ -93,6 +97,44 @@ class TestFileOperations:
         assert isinstance(result, cambia.CambiaResponse)
         assert len(result.parsed.parsed_logs) > 0
 
+    @pytest.mark.parametrize(
+        "wrap",
+        [bytearray, memoryview, io.BytesIO],
+        ids=["bytearray", "memoryview", "BytesIO"],
+    )
+    def test_parse_content_with_bytes_like(
+        self, wrap: Callable[[bytes], Any], test_logs_dir: Path
+    ) -> None:
+        """Test parsing content from buffer-protocol and file-like objects."""
+        eac_log = test_logs_dir / "EAC" / "perf-hunid.log"
+        content_bytes = eac_log.read_bytes()
+
+        expected = cambia.parse_log_content(content_bytes)
+        result = cambia.parse_log_content(wrap(content_bytes))
+
+        assert (
+            result.evaluation_combined[0].combined_score
+            == expected.evaluation_combined[0].combined_score
+        )
+
+    def test_parse_content_with_file_and_mmap(self, test_logs_dir: Path) -> None:
+        """Test parsing content from an open binary file and an mmap."""
+        eac_log = test_logs_dir / "EAC" / "perf-hunid.log"
+
+        with eac_log.open("rb") as f:
+            from_file = cambia.parse_log_content(f)
+        with (
+            eac_log.open("rb") as f,
+            mmap.mmap(f.fileno(), 0, access=mmap.ACCESS_READ) as m,
+        ):
+            from_mmap = cambia.parse_log_content(m)
+
+        assert len(from_file.parsed.parsed_logs) > 0
+        assert (
+            from_mmap.evaluation_combined[0].combined_score
+            == from_file.evaluation_combined[0].combined_score
+        )
+
 
 class TestResponseStructure:
     """Test response data structure."""
@@ -112,6 +154,24 @@ class TestResponseStructure:
         assert isinstance(result.parsed, cambia.ParsedLogCombined)
         assert isinstance(result.evaluation_combined, list)
 
+    def test_nested_objects_outlive_response(self, eac_logs_dir: Path) -> None:
+        """Test that nested objects stay valid after the response is released."""
+        result = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log")
+        tracks = result.parsed.parsed_logs[0].tracks
+        toc = result.parsed.parsed_logs[0].toc
+        expected = [track.num for track in tracks]
+        del result
+
+        assert [track.num for track in tracks] == expected
+        assert len(toc.raw.entries) > 0
+
+    def test_results_are_read_only(self, eac_logs_dir: Path) -> None:
+        """Test that result attributes cannot be reassigned."""
+        result = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log")
+
+        with pytest.raises(AttributeError):
+            result.parsed.parsed_logs[0].drive = "other"  # type: ignore[misc]
+
     def test_parsed_log_structure(self, test_logs_dir: Path) -> None:
         """Test ParsedLogCombined structure."""
         eac_log = test_logs_dir / "EAC" / "perf-hunid.log"
@@ -204,6 +264,86 @@ class TestResponseStructure:
                 assert isinstance(unit.data.field, cambia.EvaluationUnitField)
                 assert isinstance(unit.data.classification, cambia.EvaluationUnitClass)
 
+    @pytest.mark.parametrize(
+        "log_path", ["EAC/shitty.log", "EAC/perf-hunid.log", "XLD/ripping-error.log"]
+    )
+    def test_evaluation_by_track(self, test_logs_dir: Path, log_path: str) -> None:
+        """Test that by_track() and release_units() partition the units."""
+        result = cambia.parse_log_file(test_logs_dir / log_path)
+
+        for evaluation in result.evaluation_combined[0].evaluations:
+            by_track = evaluation.by_track()
+            release = evaluation.release_units()
+
+            assert list(by_track) == sorted(by_track, key=lambda t: (t is not None, t))
+            for track, breakdown in by_track.items():
+                assert isinstance(breakdown, cambia.TrackEvaluation)
+                assert breakdown.track == track
+                assert breakdown.evaluation_units
+                points = sum(u.points or 0 for u in breakdown.evaluation_units)
+                assert breakdown.deducted == points
+                assert breakdown.score == max(100 - points, 0)
+                for unit in breakdown.evaluation_units:
+                    expected = cambia.EvaluationUnitScope.Track(track)
+                    assert unit.data.scope == expected
+                    assert unit.data.scope.track == track
+
+            for unit in release:
+                assert unit.data.scope == cambia.EvaluationUnitScope.Release()
+                assert unit.data.scope.track is None
+
+            grouped = sum(len(t.evaluation_units) for t in by_track.values())
+            assert grouped + len(release) == len(evaluation.evaluation_units)
+
+    def test_evaluation_by_track_points(self, eac_logs_dir: Path) -> None:
+        """Test the per-track deductions of a log with three bad tracks."""
+        result = cambia.parse_log_file(eac_logs_dir / "shitty.log")
+        [evaluation] = result.evaluation_combined[0].evaluations
+
+        by_track = evaluation.by_track()
+
+        assert list(by_track) == [4, 7, 10]
+        for breakdown in by_track.values():
+            assert breakdown.deducted == 50
+            assert breakdown.score == 50
+            assert sorted(
+                (message, points) for _, message, points, _, _ in breakdown.deductions()
+            ) == [("CRC mismatch", 30), ("Suspicious position(s) found", 20)]
+        assert evaluation.release_units() == []
+        assert sum(t.deducted for t in by_track.values()) == evaluation.deducted == 150
+
+    def test_evaluation_by_track_score_clamped(self, eac_logs_dir: Path) -> None:
+        """Test that a track losing more than 100 points scores 0."""
+        log = cambia.parse_log_file(eac_logs_dir / "shitty.log").parsed.parsed_logs[0]
+        kinds = ["read", "skip", "jitter_generic", "jitter_edge", "jitter_atom"]
+        kinds += ["drift", "dropped", "duplicated", "inconsistent_err_sectors"]
+        modified = log.replace(
+            tracks={4: {"aborted": True, "errors": dict.fromkeys(kinds, 5)}}
+        )
+        [evaluation] = cambia.evaluate(modified).evaluation_combined[0].evaluations
+
+        breakdown = evaluation.by_track()[4]
+
+        assert breakdown.deducted > 100
+        assert breakdown.score == 0
+        assert "score=0" in repr(breakdown)
+
+    def test_evaluation_unit_scope_hashable(self) -> None:
+        """Test that scopes compare and hash by value."""
+        scopes = {
+            cambia.EvaluationUnitScope.Release(),
+            cambia.EvaluationUnitScope.Release(),
+            cambia.EvaluationUnitScope.Track(1),
+            cambia.EvaluationUnitScope.Track(1),
+            cambia.EvaluationUnitScope.Track(2),
+            cambia.EvaluationUnitScope.Track(None),
+        }
+
+        assert len(scopes) == 4
+        track = cambia.EvaluationUnitScope.Track
+        assert track(1) != track(2)
+        assert track(1).track == 1
+
 
 class TestMultipleRippers:
     """Test parsing logs from different rippers."""
@@ -239,6 +379,48 @@ class TestMultipleRippers:
         result = cambia.parse_log_file(log_files[0])
         assert result.parsed.parsed_logs[0].ripper == expected_ripper
 
+    @pytest.mark.parametrize(
+        ("log_path", "ripper"),
+        [
+            ("EAC/perf-hunid.log", cambia.Ripper.EAC),
+            ("XLD/htoa.log", cambia.Ripper.XLD),
+            ("whipper/whipper-good.log", cambia.Ripper.Whipper),
+        ],
+    )
+    def test_forced_ripper_matches_detection(
+        self, log_path: str, ripper: cambia.Ripper, test_logs_dir: Path
+    ) -> None:
+        """Test that forcing the detected ripper gives the same result.
+
+        Args:
+            log_path: Log path relative to the test logs directory.
+            ripper: The log's ripper.
+            test_logs_dir: Path to test logs directory.
+        """
+        detected = cambia.parse_log_file(test_logs_dir / log_path)
+        forced = cambia.parse_log_file(test_logs_dir / log_path, ripper=ripper)
+
+        assert forced.parsed.parsed_logs[0].ripper == ripper
+        assert len(forced.parsed.parsed_logs[0].tracks) == len(
+            detected.parsed.parsed_logs[0].tracks
+        )
+        assert (
+            forced.evaluation_combined[0].combined_score
+            == detected.evaluation_combined[0].combined_score
+        )
+
+    def test_forced_ripper_unreadable(self) -> None:
+        """Test that a forced parser that cannot read the log names itself."""
+        with pytest.raises(ValueError, match="XLD parser"):
+            _ = cambia.parse_log_content(b"Just some text\n", ripper=cambia.Ripper.XLD)
+
+    def test_forced_ripper_without_parser(self, eac_logs_dir: Path) -> None:
+        """Test that forcing a ripper without a parser raises ValueError."""
+        with pytest.raises(ValueError, match="No parser is available"):
+            _ = cambia.parse_log_file(
+                eac_logs_dir / "perf-hunid.log", ripper=cambia.Ripper.DBPA
+            )
+
 
 class TestEdgeCases:
     """Test edge cases and error handling."""
@@ -255,6 +437,11 @@ class TestEdgeCases:
         with pytest.raises(ValueError):
             _ = cambia.parse_log_content(long_content)
 
+    def test_parse_content_with_unsupported_type(self) -> None:
+        """Test that unsupported content types raise TypeError."""
+        with pytest.raises(TypeError, match="content must be"):
+            _ = cambia.parse_log_content(12345)
+
     def test_parse_invalid_binary_content(self) -> None:
         """Test that invalid binary content raises ValueError."""
         binary_content = b"\x00\x01\x02\x03\x04\x05"
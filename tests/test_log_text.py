@@ -0,0 +1,111 @@
+"""Test cases for writing parsed logs back out as log text."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+ROUND_TRIP_CASES = [
+    ("EAC", "perf-hunid.log"),
+    ("EAC", "shitty.log"),
+    ("XLD", "htoa.log"),
+    ("XLD", "xld-cdp.log"),
+    ("whipper", "whipper-good.log"),
+]
+
+
+def assert_equivalent(log: cambia.ParsedLog, expected: cambia.ParsedLog) -> None:
+    """Assert that a reparsed log holds the same data as the original.
+
+    Args:
+        log: The log parsed from regenerated text.
+        expected: The original parsed log.
+    """
+    assert log.ripper == expected.ripper
+    assert log.drive == expected.drive
+    assert log.read_offset == expected.read_offset
+    assert log.read_mode == expected.read_mode
+    assert log.defeat_audio_cache == expected.defeat_audio_cache
+    assert log.use_c2 == expected.use_c2
+    assert log.gap_handling == expected.gap_handling
+    assert [
+        (e.track, e.start_sector, e.end_sector) for e in log.toc.raw.entries
+    ] == [(e.track, e.start_sector, e.end_sector) for e in expected.toc.raw.entries]
+    assert len(log.tracks) == len(expected.tracks)
+    for track, original in zip(log.tracks, expected.tracks, strict=True):
+        assert track.num == original.num
+        assert track.test_and_copy.copy_hash == original.test_and_copy.copy_hash
+        assert track.test_and_copy.test_hash == original.test_and_copy.test_hash
+        assert [u.status for u in track.ar_info] == [
+            u.status for u in original.ar_info
+        ]
+
+
+@pytest.mark.parametrize(("subdir", "filename"), ROUND_TRIP_CASES)
+def test_round_trip(test_logs_dir: Path, subdir: str, filename: str) -> None:
+    """Test that regenerated text parses to an equivalent log."""
+    result = cambia.parse_log_file(test_logs_dir / subdir / filename)
+    log = result.parsed.parsed_logs[0]
+
+    reparsed = cambia.parse_log_content(log.to_log_text())
+
+    assert_equivalent(reparsed.parsed.parsed_logs[0], log)
+    assert (
+        reparsed.evaluation_combined[0].combined_score
+        == result.evaluation_combined[0].combined_score
+    )
+
+
+@pytest.mark.parametrize(
+    "ripper", [cambia.Ripper.EAC, cambia.Ripper.XLD, cambia.Ripper.Whipper]
+)
+def test_marked_as_regenerated(eac_logs_dir: Path, ripper: cambia.Ripper) -> None:
+    """Test that the text has no checksum and says it was regenerated."""
+    log = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log").parsed.parsed_logs[0]
+
+    text = log.to_log_text(ripper)
+
+    assert "Regenerated by pycambia" in text.splitlines()[-1]
+    info = cambia.sniff(text)
+    assert info.ripper == ripper
+    assert not info.has_checksum
+
+
+def test_other_ripper(whipper_logs_dir: Path) -> None:
+    """Test that a log can be written in another ripper's format."""
+    log = cambia.parse_log_file(
+        whipper_logs_dir / "whipper-good.log"
+    ).parsed.parsed_logs[0]
+
+    reparsed = cambia.parse_log_content(log.to_log_text(cambia.Ripper.EAC))
+
+    converted = reparsed.parsed.parsed_logs[0]
+    assert converted.ripper == cambia.Ripper.EAC
+    assert converted.read_offset == log.read_offset
+    assert len(converted.tracks) == len(log.tracks)
+
+
+def test_built_log() -> None:
+    """Test that a hand-built log can be written out."""
+    log = cambia.ParsedLog.secure(
+        cambia.Ripper.XLD,
+        tracks=[cambia.TrackEntry(1, test_and_copy=cambia.TestAndCopy("AB", "AB"))],
+        toc=cambia.Toc(cambia.TocRaw([cambia.TocEntry(1, 0, 14999)])),
+        drive="PLEXTOR DVDR PX-716A",
+    )
+
+    text = log.to_log_text()
+
+    assert text.startswith("X Lossless Decoder version ")
+    assert "CRC32 hash (test run)    : AB" in text
+    assert "Used drive : PLEXTOR DVDR PX-716A" in text
+
+
+def test_unsupported(eac_logs_dir: Path) -> None:
+    """Test that unsupported rippers and languages raise ValueError."""
+    log = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log").parsed.parsed_logs[0]
+
+    with pytest.raises(ValueError, match="Cannot write"):
+        log.to_log_text(cambia.Ripper.CueRipper)
+    with pytest.raises(ValueError, match="Unsupported language"):
+        log.to_log_text(language="ru")
@@ -440,3 +440,81 @@ def test_parse_log(
         f"Expected: {expected_deductions}\n"
         f"Actual:   {actual_deductions}"
     )
+
+
+@pytest.mark.parametrize(
+    ("subdir", "filename", "ripper", "expected_score", "expected_deductions"),
+    RIPPER_TEST_CASES,
+)
+def test_numeric_scores(
+    subdir: str,
+    filename: str,
+    ripper: cambia.Ripper,
+    expected_score: str,
+    expected_deductions: dict[str, str],
+    test_logs_dir: Path,
+) -> None:
+    """Test that numeric scores and deductions() agree with the strings.
+
+    Args:
+        subdir: Subdirectory under tests/logs/ containing the log file.
+        filename: Name of the log file to test.
+        ripper: Expected ripper enum value.
+        expected_score: Expected total score as string.
+        expected_deductions: Dict mapping deduction message to unit score.
+        test_logs_dir: Path to test logs directory.
+    """
+    result = cambia.parse_log_file(test_logs_dir / subdir / filename)
+    evaluation = result.evaluation_combined[0]
+
+    assert evaluation.numeric_score == int(expected_score)
+    assert evaluation.max_score == 100
+
+    per_log = evaluation.deductions()
+    assert len(per_log) == len(evaluation.evaluations)
+    messages = {message for deductions in per_log for _, message, _, _, _ in deductions}
+    assert messages == set(expected_deductions)
+
+    for log_evaluation, deductions in zip(evaluation.evaluations, per_log):
+        assert deductions == log_evaluation.deductions()
+        points = [points for _, _, points, _, _ in deductions]
+        assert points == sorted(points, reverse=True)
+        for unit in log_evaluation.evaluation_units:
+            assert unit.points == int(unit.unit_score)
+            assert unit.impact == -unit.points
+
+
+@pytest.mark.parametrize(
+    ("filename", "deducted", "largest_unit"),
+    [
+        ("abort.log", 0, 100),
+        ("badcombo.log", 10, 100),
+        ("hella-aborted.log", 31, 100),
+    ],
+)
+def test_deducted_is_not_unit_sum(
+    filename: str, deducted: int, largest_unit: int, eac_logs_dir: Path
+) -> None:
+    """Test that deducted follows the score of files with aborted logs.
+
+    cambia-core scores these files from their logs' own scores, so the
+    points of an aborted log's units are not taken off the combined score.
+
+    Args:
+        filename: Name of the EAC log file to test.
+        deducted: Expected points below max_score.
+        largest_unit: Expected points of the costliest unit.
+        eac_logs_dir: Path to EAC logs directory.
+    """
+    evaluation = cambia.parse_log_file(eac_logs_dir / filename).evaluation_combined[0]
+
+    assert evaluation.deducted == deducted
+    assert evaluation.deducted == evaluation.max_score - evaluation.numeric_score
+
+    points = [
+        points
+        for deductions in evaluation.deductions()
+        for _, _, points, _, _ in deductions
+    ]
+    assert max(points) == largest_unit
+    assert sum(points) > evaluation.deducted
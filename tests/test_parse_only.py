@@ -0,0 +1,112 @@
+"""Test cases for parsing without evaluation and evaluating later."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+@pytest.mark.parametrize(
+    ("subdir", "filename"),
+    [
+        ("EAC", "perf-hunid.log"),
+        ("EAC", "russian1.log"),
+        ("EAC", "spanish-099.log"),
+        ("EAC95", "burst.log"),
+        ("XLD", "htoa.log"),
+        ("whipper", "whipper-good.log"),
+    ],
+)
+def test_parse_only_matches_full_parse(
+    test_logs_dir: Path, subdir: str, filename: str
+) -> None:
+    """Test that parse_only() returns the same data as a full parse."""
+    raw = (test_logs_dir / subdir / filename).read_bytes()
+    full = cambia.parse_log_content(raw).parsed
+    parsed = cambia.parse_only(raw)
+
+    assert isinstance(parsed, cambia.ParsedLogCombined)
+    assert parsed.encoding == full.encoding
+    assert len(parsed.parsed_logs) == len(full.parsed_logs)
+    for log, expected in zip(parsed.parsed_logs, full.parsed_logs, strict=True):
+        assert log.ripper == expected.ripper
+        assert log.drive == expected.drive
+        assert log.read_offset == expected.read_offset
+        assert log.toc.mbz.hash == expected.toc.mbz.hash
+        assert len(log.tracks) == len(expected.tracks)
+
+
+@pytest.mark.parametrize(
+    ("subdir", "filename"),
+    [
+        ("EAC", "perf-hunid.log"),
+        ("XLD", "htoa.log"),
+        ("whipper", "whipper-good.log"),
+    ],
+)
+def test_evaluate_later_matches_full_parse(
+    test_logs_dir: Path, subdir: str, filename: str
+) -> None:
+    """Test that evaluating a parse_only() result gives the same scores."""
+    raw = (test_logs_dir / subdir / filename).read_bytes()
+    full = cambia.parse_log_content(raw)
+    result = cambia.evaluate(cambia.parse_only(raw))
+
+    assert isinstance(result, cambia.CambiaResponse)
+    assert len(result.evaluation_combined) == len(full.evaluation_combined)
+    for evaluation, expected in zip(
+        result.evaluation_combined, full.evaluation_combined, strict=True
+    ):
+        assert evaluation.evaluator == expected.evaluator
+        assert evaluation.combined_score == expected.combined_score
+
+
+def test_evaluate_can_be_repeated(eac_logs_dir: Path) -> None:
+    """Test that a parsed log is not consumed by evaluation."""
+    parsed = cambia.parse_only((eac_logs_dir / "perf-hunid.log").read_bytes())
+
+    first = cambia.evaluate(parsed).evaluation_combined[0].combined_score
+    second = cambia.evaluate(parsed).evaluation_combined[0].combined_score
+
+    assert first == second
+    assert len(parsed.parsed_logs) == 1
+
+
+def test_parse_only_sniffed_ripper(test_logs_dir: Path) -> None:
+    """Test that forcing the sniffed ripper parses like detection does."""
+    for path in [
+        test_logs_dir / "EAC" / "hella-aborted.log",
+        test_logs_dir / "XLD" / "htoa.log",
+    ]:
+        raw = path.read_bytes()
+        detected = cambia.parse_only(raw)
+        forced = cambia.parse_only(raw, ripper=cambia.sniff(raw).ripper)
+
+        assert len(forced.parsed_logs) == len(detected.parsed_logs)
+        assert [log.ripper for log in forced.parsed_logs] == [
+            log.ripper for log in detected.parsed_logs
+        ]
+
+
+def test_parse_only_forced_ripper(xld_logs_dir: Path) -> None:
+    """Test that parse_only() honours the ripper keyword."""
+    raw = (xld_logs_dir / "htoa.log").read_bytes()
+
+    parsed = cambia.parse_only(raw, ripper=cambia.Ripper.XLD)
+
+    assert parsed.parsed_logs[0].ripper == cambia.Ripper.XLD
+
+
+def test_parse_only_forced_encoding(eac_logs_dir: Path) -> None:
+    """Test that parse_only() honours the encoding keyword."""
+    raw = (eac_logs_dir / "russian1.log").read_bytes()
+
+    parsed = cambia.parse_only(raw, encoding="utf-16le")
+
+    assert parsed.encoding == "UTF-16LE"
+
+
+def test_parse_only_not_a_log() -> None:
+    """Test that content without a ripper header raises ValueError."""
+    with pytest.raises(ValueError, match="Could not parse log"):
+        cambia.parse_only(b"Just some text\n")
@@ -0,0 +1,108 @@
+"""Test cases for exact sector, sample and MSF positions."""
+
+from collections.abc import Callable
+from datetime import timedelta
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+def test_msf_fields() -> None:
+    """Test that an MSF splits its sectors into minutes, seconds and frames."""
+    msf = cambia.MSF(4, 48, 33)
+
+    assert (msf.minutes, msf.seconds, msf.frames) == (4, 48, 33)
+    assert msf.sectors == 21633
+    assert msf.samples == 21633 * 588
+    assert str(msf) == "04:48:33"
+    assert cambia.MSF.from_sectors(21633) == msf
+    assert cambia.MSF.parse("04:48:33") == msf
+    assert cambia.MSF.parse("4:48.33") == msf
+    assert cambia.MSF(0, 1, 0).duration == timedelta(seconds=1)
+
+
+def test_msf_arithmetic() -> None:
+    """Test that MSF arithmetic and comparison are exact."""
+    msf = cambia.MSF(0, 59, 74)
+
+    assert str(msf + 1) == "01:00:00"
+    assert 1 + msf == msf + cambia.MSF(0, 0, 1)
+    assert msf - msf == cambia.MSF()
+    assert msf < msf + 1
+    assert int(msf) == 59 * 75 + 74
+    assert len({msf, cambia.MSF.from_sectors(msf.sectors)}) == 1
+    with pytest.raises(ValueError, match="before the start"):
+        msf - (msf + 1)
+
+
+@pytest.mark.parametrize(
+    ("build", "match"),
+    [
+        (lambda: cambia.MSF(0, 60, 0), "seconds must be below 60"),
+        (lambda: cambia.MSF(0, 0, 75), "frames must be below 75"),
+        (lambda: cambia.MSF.parse("1:2"), "not in mm:ss:ff form"),
+    ],
+)
+def test_msf_validation(build: Callable[[], object], match: str) -> None:
+    """Test that out-of-range MSF values raise ValueError."""
+    with pytest.raises(ValueError, match=match):
+        build()
+
+
+def test_toc_entry_positions(eac_logs_dir: Path) -> None:
+    """Test that TOC entries expose exact positions matching their times."""
+    log = cambia.parse_log_file(eac_logs_dir / "perf-hunid.log").parsed.parsed_logs[0]
+
+    for entry in log.toc.raw.entries:
+        assert entry.start_msf.sectors == entry.start_sector
+        assert entry.length_sectors == entry.end_sector - entry.start_sector + 1
+        assert entry.start_sample == entry.start_sector * 588
+        assert entry.length_msf.duration.total_seconds() == pytest.approx(
+            entry.length.total_seconds(), abs=1e-6
+        )
+
+
+def test_error_range_positions() -> None:
+    """Test that error ranges round to whole sectors and samples."""
+    error = cambia.TrackErrorRange(timedelta(seconds=2), timedelta(seconds=1 / 75))
+
+    assert error.start_sector == 150
+    assert error.length_sectors == 1
+    assert str(error.start_msf) == "00:02:00"
+    assert error.start_sample == 88200
+    assert error.length_samples == 588
+
+
+def test_error_ranges_on_disc() -> None:
+    """Test that error ranges are mapped to absolute sectors with the TOC."""
+    errors = cambia.TrackError(
+        read=cambia.TrackErrorData(
+            ranges=[cambia.TrackErrorRange(timedelta(seconds=1), timedelta())]
+        ),
+        skip=cambia.TrackErrorData(
+            ranges=[
+                cambia.TrackErrorRange(timedelta(seconds=2), timedelta(seconds=2))
+            ]
+        ),
+    )
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[
+            cambia.TrackEntry(2, errors=errors),
+            cambia.TrackEntry(3, errors=errors),
+        ],
+        toc=cambia.Toc(
+            cambia.TocRaw([cambia.TocEntry(1, 0, 999), cambia.TocEntry(2, 1000, 9999)])
+        ),
+    )
+
+    ranges = log.error_ranges()
+
+    assert [(r.track, r.kind, r.start_sector, r.end_sector) for r in ranges] == [
+        (2, cambia.ErrorKind.Read, 1075, 1075),
+        (2, cambia.ErrorKind.Skip, 1150, 1299),
+    ]
+    assert ranges[1].length_sectors == 150
+    assert str(ranges[1].start_msf) == "00:15:25"
+    assert ranges[1].range.length == timedelta(seconds=2)
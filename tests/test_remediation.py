@@ -0,0 +1,98 @@
+"""Test cases for per-ripper remediation hints on evaluation units."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+def find_unit(result: cambia.CambiaResponse, message: str) -> cambia.EvaluationUnit:
+    """Return the first evaluation unit with the given message.
+
+    Args:
+        result: The parsed cambia response.
+        message: The deduction message to look for.
+
+    Returns:
+        The matching evaluation unit.
+    """
+    for evaluation in result.evaluation_combined[0].evaluations:
+        for unit in evaluation.evaluation_units:
+            if unit.data.message == message:
+                return unit
+    raise AssertionError(f"no unit with message {message!r}")
+
+
+@pytest.mark.parametrize(
+    ("log_path", "message", "setting", "rerip"),
+    [
+        (
+            "EAC/bad-htoa.log",
+            "Test and copy was not used",
+            "Action > Test & Copy Selected Tracks",
+            True,
+        ),
+        (
+            "EAC/bad-russian-099.log",
+            '"Defeat audio cache" should be Yes/true',
+            "Drive Options > Drive > Drive caches audio data",
+            True,
+        ),
+        (
+            "EAC/bad-russian-099.log",
+            "Null samples should be used in CRC calculations",
+            "EAC Options > Extraction > Use null samples in CRC calculations",
+            False,
+        ),
+        (
+            "XLD/cdparanoia.log",
+            "C2 pointers were used",
+            "Preferences > CD Rip > Use C2 Error Pointers",
+            True,
+        ),
+        (
+            "whipper/whipper-with-errors.log",
+            "Logs must be produced by whipper 0.7.3+",
+            "whipper version",
+            True,
+        ),
+    ],
+)
+def test_setting_remediation(
+    test_logs_dir: Path, log_path: str, message: str, setting: str, rerip: bool
+) -> None:
+    """Test that setting deductions point at the ripper's own setting."""
+    result = cambia.parse_log_file(test_logs_dir / log_path)
+
+    remediation = find_unit(result, message).remediation
+
+    assert isinstance(remediation, cambia.Remediation)
+    assert remediation.setting == setting
+    assert remediation.value
+    assert remediation.advice
+    assert remediation.rerip is rerip
+
+
+def test_read_error_remediation(xld_logs_dir: Path) -> None:
+    """Test that read errors ask for a re-rip without naming a setting."""
+    result = cambia.parse_log_file(xld_logs_dir / "ripping-error.log")
+
+    remediation = find_unit(result, "Damaged sectors").remediation
+
+    assert remediation is not None
+    assert remediation.setting is None
+    assert remediation.rerip
+
+
+@pytest.mark.parametrize(
+    ("log_path", "message"),
+    [
+        ("EAC/perf-hunid.log", "The drive was not found in the database"),
+        ("XLD/htoa.log", "Not a pressed cd"),
+    ],
+)
+def test_no_remediation(test_logs_dir: Path, log_path: str, message: str) -> None:
+    """Test that deductions the user cannot fix have no remediation."""
+    result = cambia.parse_log_file(test_logs_dir / log_path)
+
+    assert find_unit(result, message).remediation is None
@@ -0,0 +1,107 @@
+"""Regression corpus checking that malformed logs never crash the process.
+
+Every log in ``tests/logs`` is truncated, corrupted and shuffled with fixed
+seeds, and each variant is fed to every entry point. Each log runs in a
+subprocess, so an abort is reported as a failing test rather than killing
+the test run.
+"""
+
+import contextlib
+import random
+import subprocess
+import sys
+from collections.abc import Callable
+from pathlib import Path
+from typing import Any
+
+import cambia
+import pytest
+
+LOGS_DIR = Path(__file__).parent / "logs"
+LOG_FILES = sorted(LOGS_DIR.rglob("*.log"))
+
+
+def mutations(data: bytes, seed: int) -> list[bytes]:
+    """Build deterministic malformed variants of a log.
+
+    Args:
+        data: The original log content.
+        seed: Seed for the random mutations.
+
+    Returns:
+        Truncated, byte-corrupted, line-dropped and random variants.
+    """
+    rng = random.Random(seed)
+    size = len(data)
+    cuts = (0, 1, 2, 3, size // 4, size // 2, size * 3 // 4, size - 1)
+    cases = [data[:n] for n in cuts]
+    cases += [data[: rng.randrange(size)] for _ in range(8)]
+
+    for _ in range(8):
+        corrupted = bytearray(data)
+        for _ in range(16):
+            corrupted[rng.randrange(size)] = rng.randrange(256)
+        cases.append(bytes(corrupted))
+
+    lines = data.splitlines(keepends=True)
+    for _ in range(4):
+        cases.append(b"".join(line for line in lines if rng.random() > 0.1))
+    shuffled = lines[:]
+    rng.shuffle(shuffled)
+    cases.append(b"".join(shuffled))
+
+    cases.append(rng.randbytes(size))
+    return cases
+
+
+def exercise(log_file: Path) -> None:
+    """Run every variant of a log through every entry point.
+
+    Errors are expected; only a crash of the interpreter is a failure.
+
+    Args:
+        log_file: The log to derive variants from.
+    """
+    data = log_file.read_bytes()
+    rippers = cambia.get_supported_rippers()
+    calls: list[tuple[Callable[..., object], dict[str, Any]]] = [
+        (cambia.parse_log_content, {}),
+        (cambia.parse_only, {}),
+        (cambia.sniff, {}),
+        (cambia.detect_encoding, {}),
+    ]
+    calls += [(cambia.parse_log_content, {"ripper": ripper}) for ripper in rippers]
+    for case in mutations(data, seed=len(data)):
+        for call, kwargs in calls:
+            with contextlib.suppress(ValueError, cambia.InternalError):
+                call(case, **kwargs)
+
+
+@pytest.mark.parametrize(
+    "log_file", LOG_FILES, ids=[str(p.relative_to(LOGS_DIR)) for p in LOG_FILES]
+)
+def test_malformed_variants_do_not_crash(log_file: Path) -> None:
+    """Test that no malformed variant of a log aborts the interpreter."""
+    script = (
+        "import sys; from pathlib import Path; sys.path.insert(0, sys.argv[1]); "
+        "import test_robustness; test_robustness.exercise(Path(sys.argv[2]))"
+    )
+    result = subprocess.run(
+        [sys.executable, "-c", script, str(Path(__file__).parent), str(log_file)],
+        capture_output=True,
+        text=True,
+        check=False,
+    )
+
+    assert result.returncode == 0, result.stderr[-2000:]
+
+
+@pytest.mark.skipif(
+    not hasattr(cambia._cambia, "_panic"), reason="built without test-hooks"
+)
+def test_panic_raises_internal_error() -> None:
+    """Test that a panic raises InternalError and leaves the module usable."""
+    with pytest.raises(cambia.InternalError, match="cambia panicked: boom"):
+        cambia._cambia._panic("boom")
+
+    assert cambia.sniff(b"Just some text\n").ripper is None
@@ -0,0 +1,108 @@
+"""Test cases for header-level log sniffing."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+@pytest.mark.parametrize(
+    ("subdir", "filename", "ripper", "version", "language"),
+    [
+        ("EAC", "perf-hunid.log", cambia.Ripper.EAC, "1.1", "English"),
+        ("EAC", "russian1.log", cambia.Ripper.EAC, "1.0 beta 3", "Russian"),
+        ("EAC", "bad-russian-099.log", cambia.Ripper.EAC, "0.99 prebeta 3", "Russian"),
+        ("EAC", "spanish-099.log", cambia.Ripper.EAC, "0.99 prebeta 5", "Spanish"),
+        ("EAC95", "burst.log", cambia.Ripper.EAC, None, "English"),
+        ("XLD", "htoa.log", cambia.Ripper.XLD, "20170729 (150.3)", "English"),
+        ("whipper", "whipper-good.log", cambia.Ripper.Whipper, "0.9.0", "English"),
+    ],
+)
+def test_sniff_header(
+    test_logs_dir: Path,
+    subdir: str,
+    filename: str,
+    ripper: cambia.Ripper,
+    version: str | None,
+    language: str,
+) -> None:
+    """Test that the ripper, version and language are read from the header."""
+    result = cambia.sniff((test_logs_dir / subdir / filename).read_bytes())
+
+    assert result.ripper == ripper
+    assert result.ripper_version == version
+    assert result.language == language
+    assert not result.combined
+    assert result.log_count == 1
+
+
+@pytest.mark.parametrize(
+    ("subdir", "filename", "expected"),
+    [
+        ("EAC", "1.3-good.log", True),
+        ("EAC", "russian2.log", True),
+        ("EAC", "burst.log", False),
+        ("XLD", "crc-mismatch.log", True),
+        ("XLD", "bad-chardet-no-checksum.log", False),
+        ("whipper", "whipper-with-errors.log", True),
+    ],
+)
+def test_sniff_checksum(
+    test_logs_dir: Path, subdir: str, filename: str, expected: bool
+) -> None:
+    """Test checksum block detection across rippers and languages."""
+    result = cambia.sniff((test_logs_dir / subdir / filename).read_bytes())
+
+    assert result.has_checksum is expected
+
+
+def test_sniff_combined(eac_logs_dir: Path) -> None:
+    """Test that combined logs report their sub-log count."""
+    result = cambia.sniff((eac_logs_dir / "hella-aborted.log").read_bytes())
+
+    assert result.combined
+    assert result.log_count == 8
+
+
+def test_sniff_unknown_language() -> None:
+    """Test that EAC logs in languages without a header string are split."""
+    log = (
+        "Exact Audio Copy V1.3 from 2. September 2016\n\n"
+        "EAC Auslesedatei vom 9. M\u00e4rz 2017, 9:59\n\n"
+        "Interpret / Album\n"
+    )
+    result = cambia.sniff((log + "\n" + log).encode())
+
+    assert result.ripper == cambia.Ripper.EAC
+    assert result.ripper_version == "1.3"
+    assert result.language is None
+    assert result.log_count == 2
+
+
+def test_sniff_german(eac_logs_dir: Path) -> None:
+    """Test that a German EAC header is recognized."""
+    text = (eac_logs_dir / "perf-hunid.log").read_text(encoding="utf-8")
+    german = text.replace(
+        "EAC extraction logfile from 15. March 2018",
+        "EAC Auslese-Logdatei vom 15. M\u00e4rz 2018",
+    )
+
+    result = cambia.sniff(german.encode())
+
+    assert result.ripper == cambia.Ripper.EAC
+    assert result.language == "German"
+
+
+def test_sniff_eac_095_by_drive_line(eac95_logs_dir: Path) -> None:
+    """Test that EAC 0.95 logs in unknown languages are found by the drive line."""
+    text = (eac95_logs_dir / "burst.log").read_text(encoding="utf-8")
+    first, rest = text.split("\n", 1)
+    assert first.startswith("EAC extraction logfile from")
+    localized = "EAC Auslesedatei vom 19. Januar 2008, 14:21 f\u00fcr CD\n" + rest
+
+    result = cambia.sniff((localized + "\n" + localized).encode())
+
+    assert result.ripper == cambia.Ripper.EAC
+    assert result.ripper_version is None
+    assert result.language is None
+    assert result.log_count == 2
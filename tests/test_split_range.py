@@ -0,0 +1,119 @@
+"""Test cases for splitting range rips into per-track entries."""
+
+from datetime import timedelta
+from pathlib import Path
+
+import cambia
+
+
+def build_range_log() -> cambia.ParsedLog:
+    """Build a three-track range rip with read errors and AR results.
+
+    Returns:
+        The log, with one read error crossing from track 1 into track 2 and
+        one past the end of the TOC.
+    """
+    read = cambia.TrackErrorData(
+        ranges=[
+            cambia.TrackErrorRange(timedelta(seconds=12), timedelta(seconds=2)),
+            cambia.TrackErrorRange(timedelta(seconds=500), timedelta(seconds=1)),
+        ]
+    )
+    pseudo_track = cambia.TrackEntry(
+        1,
+        is_range=True,
+        peak_level=97.7,
+        test_and_copy=cambia.TestAndCopy("4199FFCA", "4199FFCA"),
+        errors=cambia.TrackError(read=read, skip=cambia.TrackErrorData(3)),
+        ar_info=[
+            cambia.AccurateRipUnit(cambia.AccurateRipStatus.Match, sign=sign)
+            for sign in ("63DA46C4", "4334B581", "BC9D2EB3")
+        ],
+    )
+    return cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[pseudo_track],
+        toc=cambia.Toc(
+            cambia.TocRaw(
+                [
+                    cambia.TocEntry(1, 33, 999),
+                    cambia.TocEntry(2, 1000, 1999),
+                    cambia.TocEntry(3, 2000, 2999),
+                ]
+            )
+        ),
+    )
+
+
+def test_one_entry_per_toc_track() -> None:
+    """Test that the pseudo-track becomes one entry per TOC track."""
+    split = cambia.split_range(build_range_log())
+
+    assert [t.num for t in split.log.tracks] == [1, 2, 3]
+    assert not any(t.is_range for t in split.log.tracks)
+    assert [(t.track, t.start_sector, t.end_sector) for t in split.tracks] == [
+        (1, 33, 999),
+        (2, 1000, 1999),
+        (3, 2000, 2999),
+    ]
+    assert all(t.derived for t in split.tracks)
+    assert split.range.is_range
+    assert split.range.peak_level == 97.7
+
+
+def test_errors_follow_their_sectors() -> None:
+    """Test that an error range is split at the track boundary it crosses."""
+    split = cambia.split_range(build_range_log())
+
+    read = [
+        [(r.start_sector, r.length_sectors) for r in t.entry.errors.read.ranges]
+        for t in split.tracks
+    ]
+    assert read == [[(900, 67)], [(0, 83)], []]
+    assert split.unplaced_errors == {cambia.ErrorKind.Read: 1, cambia.ErrorKind.Skip: 3}
+
+
+def test_accuraterip_and_integrity() -> None:
+    """Test that AR results go to tracks in order and T&C carries over."""
+    split = cambia.split_range(build_range_log())
+
+    assert [[u.sign for u in t.entry.ar_info] for t in split.tracks] == [
+        ["63DA46C4"],
+        ["4334B581"],
+        ["BC9D2EB3"],
+    ]
+    for track in split.tracks:
+        assert track.entry.test_and_copy.integrity == cambia.Integrity.Match
+        assert track.entry.test_and_copy.copy_hash == ""
+        assert track.entry.peak_level is None
+
+
+def test_track_rip_unchanged() -> None:
+    """Test that logs without a range keep their tracks."""
+    log = cambia.ParsedLog(
+        cambia.Ripper.EAC,
+        tracks=[cambia.TrackEntry(1)],
+        toc=cambia.Toc(
+            cambia.TocRaw([cambia.TocEntry(1, 0, 9), cambia.TocEntry(2, 10, 19)])
+        ),
+    )
+
+    split = cambia.split_range(log)
+
+    assert split.range is None
+    assert [t.num for t in split.log.tracks] == [1]
+    assert [(t.track, t.derived) for t in split.tracks] == [(1, False)]
+    assert split.unplaced_errors == {}
+
+
+def test_range_rip_logs(eac_logs_dir: Path, xld_logs_dir: Path) -> None:
+    """Test that the bundled range rips split into every TOC track."""
+    for path in (eac_logs_dir / "range-rip.log", xld_logs_dir / "range-vbox.log"):
+        log = cambia.parse_log_file(path).parsed.parsed_logs[0]
+
+        split = cambia.split_range(log)
+
+        toc = [entry.track for entry in log.toc.raw.entries]
+        assert [t.track for t in split.tracks] == toc
+        assert [t.num for t in split.log.tracks] == toc
+        assert cambia.evaluate(split.log).evaluation_combined
@@ -0,0 +1,139 @@
+"""Test cases for translating localized EAC logs."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+LOCALIZED_CASES = [
+    ("russian1.log", "Russian"),
+    ("russian2.log", "Russian"),
+    ("russian3.log", "Russian"),
+    ("russian4.log", "Russian"),
+    ("bad-russian-099.log", "Russian"),
+    ("spanish-099.log", "Spanish"),
+    ("spanish-log-extra-colons.log", "Spanish"),
+    ("swedish-timing-problems.log", "Swedish"),
+]
+
+
+@pytest.mark.parametrize(("filename", "language"), LOCALIZED_CASES)
+def test_every_line_recognized(
+    eac_logs_dir: Path, filename: str, language: str
+) -> None:
+    """Test that the bundled localized logs translate without leftovers."""
+    translated = cambia.translate_log((eac_logs_dir / filename).read_bytes())
+
+    assert translated.source_language == language
+    assert translated.target == "en"
+    assert translated.untranslated == []
+    assert translated.complete
+    assert "EAC extraction logfile from" in translated.text
+    assert "No errors occurred" in translated.text or "Copy OK" in translated.text
+
+
+def test_russian_wording(eac_logs_dir: Path) -> None:
+    """Test that settings, values and track lines use EAC's English wording."""
+    text = cambia.translate_log((eac_logs_dir / "russian1.log").read_bytes()).text
+
+    assert "Read mode : Secure" in text
+    assert "Utilize accurate stream : Yes" in text
+    assert "Make use of C2 pointers : No" in text
+    assert "TOC of the extracted CD" in text
+    assert "End of status report" in text
+    assert "Трек" not in text
+
+
+def test_line_map(eac_logs_dir: Path) -> None:
+    """Test that each output line maps back to its line in the original."""
+    raw = (eac_logs_dir / "spanish-099.log").read_bytes()
+    original = raw.decode("cp1252").splitlines()
+
+    translated = cambia.translate_log(raw)
+
+    assert len(translated.lines) == len(original)
+    assert translated.text.splitlines() == [line.text for line in translated.lines]
+    for line in translated.lines:
+        assert line.original == original[line.number - 1]
+    tracks = [line for line in translated.lines if line.text == "Track  1"]
+    assert [line.original for line in tracks] == ["Pista  1"]
+
+
+def test_unrecognized_lines_marked(eac_logs_dir: Path) -> None:
+    """Test that unknown localized lines are kept with a marker."""
+    text = (eac_logs_dir / "russian1.log").read_text(encoding="utf-16")
+    lines = text.splitlines()
+    lines.insert(5, "Неизвестная настройка : Да")
+
+    translated = cambia.translate_log("\n".join(lines))
+
+    assert [line.number for line in translated.untranslated] == [6]
+    assert translated.lines[5].text == "[untranslated] Неизвестная настройка : Да"
+    assert not translated.lines[5].recognized
+    assert not translated.complete
+
+
+def test_strict_raises_on_untranslated_lines(eac_logs_dir: Path) -> None:
+    """Test that strict mode reports the lines it could not translate."""
+    raw = (eac_logs_dir / "russian1.log").read_bytes()
+    lines = raw.decode("utf-16").splitlines()
+    lines.insert(5, "Неизвестная настройка : Да")
+    lines.insert(9, "Ещё одна строка")
+
+    with pytest.raises(ValueError, match=r"Could not translate 2 line\(s\): 6, 10"):
+        cambia.translate_log("\n".join(lines), strict=True)
+    assert cambia.translate_log(raw, strict=True).complete
+
+
+def test_english_unchanged(eac_logs_dir: Path) -> None:
+    """Test that English logs come back as they are."""
+    raw = (eac_logs_dir / "perf-hunid.log").read_bytes()
+
+    translated = cambia.translate_log(raw)
+
+    assert translated.source_language == "English"
+    assert all(line.recognized for line in translated.lines)
+    assert [line.text for line in translated.lines] == [
+        line.original for line in translated.lines
+    ]
+
+
+def test_translated_log_parses(eac_logs_dir: Path) -> None:
+    """Test that the translated text parses as an English log."""
+    raw = (eac_logs_dir / "russian1.log").read_bytes()
+    original = cambia.parse_log_content(raw)
+
+    translated = cambia.parse_log_content(cambia.translate_log(raw).text)
+
+    log = translated.parsed.parsed_logs[0]
+    expected = original.parsed.parsed_logs[0]
+    assert log.language == "English"
+    assert log.read_offset == expected.read_offset
+    assert log.read_mode == expected.read_mode
+    assert log.accurate_stream == expected.accurate_stream
+    assert len(log.tracks) == len(expected.tracks)
+
+
+def test_unsupported_language(eac_logs_dir: Path) -> None:
+    """Test that an EAC log in a language without a table is rejected."""
+    text = (eac_logs_dir / "perf-hunid.log").read_text(encoding="utf-8")
+    # A header sniff knows as German, and one it does not know at all
+    for header in ("EAC Auslese-Logdatei vom", "EAC Auslesedatei vom"):
+        localized = text.replace(
+            "EAC extraction logfile from 15. March 2018",
+            f"{header} 15. März 2018",
+        )
+
+        with pytest.raises(
+            ValueError,
+            match="Unsupported language: only English, Russian, Spanish, Swedish",
+        ):
+            cambia.translate_log(localized)
+
+
+def test_errors() -> None:
+    """Test that unsupported targets and unrecognized content raise."""
+    with pytest.raises(ValueError, match="Unsupported target language"):
+        cambia.translate_log(b"", target="ru")
+    with pytest.raises(ValueError, match="Could not identify"):
+        cambia.translate_log(b"not a log\n")
@@ -0,0 +1,158 @@
+"""Test cases for what-if evaluation of modified logs."""
+
+from pathlib import Path
+
+import cambia
+import pytest
+
+
+@pytest.fixture
+def bad_htoa(eac_logs_dir: Path) -> cambia.ParsedLog:
+    """Return the single log of an EAC rip without test and copy.
+
+    Args:
+        eac_logs_dir: The EAC logs directory.
+
+    Returns:
+        The parsed log.
+    """
+    return cambia.parse_log_file(eac_logs_dir / "bad-htoa.log").parsed.parsed_logs[0]
+
+
+def test_replace_returns_modified_copy(bad_htoa: cambia.ParsedLog) -> None:
+    """Test that replace() changes the copy and leaves the original alone."""
+    modified = bad_htoa.replace(
+        test_and_copy=cambia.Quartet.TRUE,
+        gap_handling=cambia.Gap.Append,
+        read_offset=667,
+        drive="Some Drive",
+    )
+
+    assert modified.test_and_copy == cambia.Quartet.TRUE
+    assert modified.gap_handling == cambia.Gap.Append
+    assert modified.read_offset == 667
+    assert modified.drive == "Some Drive"
+    assert bad_htoa.test_and_copy != cambia.Quartet.TRUE
+    assert bad_htoa.drive != "Some Drive"
+    assert modified.tracks[0].num == bad_htoa.tracks[0].num
+
+
+def test_replace_accepts_bool_for_quartet(bad_htoa: cambia.ParsedLog) -> None:
+    """Test that Quartet settings can be given as plain bools."""
+    assert bad_htoa.replace(use_c2=False).use_c2 == cambia.Quartet.FALSE
+    assert bad_htoa.replace(use_c2=True).use_c2 == cambia.Quartet.TRUE
+
+
+def test_replace_track_changes(bad_htoa: cambia.ParsedLog) -> None:
+    """Test per-track AccurateRip and error count changes."""
+    num = bad_htoa.tracks[0].num
+    modified = bad_htoa.replace(
+        tracks={
+            num: {
+                "ar_status": cambia.AccurateRipStatus.Mismatch,
+                "errors": {cambia.ErrorKind.Read: 3, "skip": 0},
+                "aborted": True,
+            }
+        }
+    )
+    track = modified.tracks[0]
+
+    assert track.ar_info
+    assert all(
+        unit.status == cambia.AccurateRipStatus.Mismatch for unit in track.ar_info
+    )
+    assert track.errors.read.count == 3
+    assert track.errors.skip.count == 0
+    assert track.errors.skip.ranges == []
+    assert track.aborted
+
+
+def test_error_kind_values() -> None:
+    """Test that each ErrorKind's value names a TrackError attribute."""
+    kinds = [
+        cambia.ErrorKind.Read,
+        cambia.ErrorKind.JitterGeneric,
+        cambia.ErrorKind.InconsistentErrorSectors,
+        cambia.ErrorKind.MissingSamples,
+    ]
+    errors = cambia.TrackError()
+
+    assert [kind.value for kind in kinds] == [
+        "read",
+        "jitter_generic",
+        "inconsistent_err_sectors",
+        "missing_samples",
+    ]
+    for kind in kinds:
+        assert isinstance(getattr(errors, kind.value), cambia.TrackErrorData)
+
+
+@pytest.mark.parametrize(
+    ("changes", "error"),
+    [
+        ({"bogus": 1}, TypeError),
+        ({"test_and_copy": "yes"}, TypeError),
+        ({"read_offset": "six"}, TypeError),
+        ({"tracks": {99: {"aborted": True}}}, ValueError),
+    ],
+)
+def test_replace_rejects_bad_changes(
+    bad_htoa: cambia.ParsedLog, changes: dict, error: type[Exception]
+) -> None:
+    """Test that unknown attributes and bad values raise."""
+    with pytest.raises(error):
+        bad_htoa.replace(**changes)
+
+
+@pytest.mark.parametrize(
+    ("track_changes", "error"),
+    [
+        ({"errors": {"nope": 1}}, ValueError),
+        ({"bogus": 1}, TypeError),
+        ({"aborted": "yes"}, TypeError),
+    ],
+)
+def test_replace_rejects_bad_track_changes(
+    bad_htoa: cambia.ParsedLog, track_changes: dict, error: type[Exception]
+) -> None:
+    """Test that unknown track attributes and bad values raise."""
+    with pytest.raises(error):
+        bad_htoa.replace(tracks={bad_htoa.tracks[0].num: track_changes})
+
+
+def test_evaluate_single_log(eac_logs_dir: Path) -> None:
+    """Test that an unchanged ParsedLog scores like the original file."""
+    result = cambia.parse_log_file(eac_logs_dir / "bad-htoa.log")
+
+    rescored = cambia.evaluate(result.parsed.parsed_logs[0])
+
+    assert (
+        rescored.evaluation_combined[0].combined_score
+        == result.evaluation_combined[0].combined_score
+    )
+
+
+def test_compare_score_delta(eac_logs_dir: Path) -> None:
+    """Test that defeating the audio cache recovers its deduction."""
+    result = cambia.parse_log_file(eac_logs_dir / "bad-russian-099.log")
+    log = result.parsed.parsed_logs[0]
+
+    unchanged = cambia.compare(log, log)
+    fixed = cambia.compare(log, log.replace(defeat_audio_cache=True))
+
+    assert unchanged.delta == 0
+    assert isinstance(fixed, cambia.ScoreComparison)
+    assert fixed.delta == 10
+    assert fixed.original.evaluation_combined[0].combined_score == "70"
+    assert fixed.modified.evaluation_combined[0].combined_score == "80"
+    [deductions] = fixed.modified.evaluation_combined[0].deductions()
+    assert not any(
+        message == '"Defeat audio cache" should be Yes/true'
+        for _, message, _, _, _ in deductions
+    )
+
+
+def test_evaluate_rejects_other_types() -> None:
+    """Test that evaluate() only takes parsed logs."""
+    with pytest.raises(TypeError):
+        cambia.evaluate("not a log")